@@ -0,0 +1,215 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rocket::serde::Serialize;
+use rocket::serde::json::to_string;
+use rocket::time::OffsetDateTime;
+use rocket::time::format_description::well_known::Rfc3339;
+use starknet_crypto::Felt;
+
+/// Append-only log of the messages signed by the attestator, as JSON lines,
+/// kept as evidence of what the attestator key signed and for whom.
+pub struct AuditLog {
+    file: Option<(PathBuf, Mutex<File>)>,
+    // error of the last append, cleared by the next successful one
+    last_error: Mutex<Option<String>>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+struct AuditRecord<'a> {
+    timestamp: &'a str,
+    caller: &'a str,
+    public_key: Felt,
+    message: Felt,
+    signature: (Felt, Felt),
+    cached: bool,
+}
+
+impl AuditLog {
+    /// Nothing is logged without `path`.
+    pub fn open(path: Option<&Path>) -> std::io::Result<Self> {
+        let file = path
+            .map(|path| OpenOptions::new().create(true).append(true).open(path))
+            .transpose()?;
+
+        Ok(Self {
+            file: path
+                .zip(file)
+                .map(|(path, file)| (path.to_path_buf(), Mutex::new(file))),
+            last_error: Mutex::new(None),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// Appends a record per signed message, and syncs them to disk before the
+    /// attestations are returned.
+    pub fn append(
+        &self,
+        caller: &str,
+        public_key: Felt,
+        attestations: &[(Felt, (Felt, Felt), bool)],
+    ) -> Result<(), String> {
+        let Some((_, file)) = &self.file else {
+            return Ok(());
+        };
+
+        let timestamp = OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .map_err(|e| e.to_string())?;
+
+        let mut lines = String::new();
+
+        for &(message, signature, cached) in attestations {
+            let record = AuditRecord {
+                timestamp: &timestamp,
+                caller,
+                public_key,
+                message,
+                signature,
+                cached,
+            };
+
+            lines.push_str(&to_string(&record).map_err(|e| e.to_string())?);
+            lines.push('\n');
+        }
+
+        let mut file = file.lock().unwrap();
+
+        let result = file
+            .write_all(lines.as_bytes())
+            .and_then(|()| file.sync_data())
+            .map_err(|e| format!("failed to write the audit log: {e}"));
+
+        *self.last_error.lock().unwrap() = result.as_ref().err().cloned();
+
+        result
+    }
+
+    /// Checks that the audit log can still be appended to: the last append succeeded,
+    /// and the file at the configured path is still writable.
+    pub fn check_writable(&self) -> Result<(), String> {
+        let Some((path, _)) = &self.file else {
+            return Ok(());
+        };
+
+        if let Some(error) = self.last_error.lock().unwrap().clone() {
+            return Err(error);
+        }
+
+        OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map(|_| ())
+            .map_err(|e| format!("audit log {} is not writable: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::serde::json::{Value, from_str};
+
+    use super::*;
+
+    fn audit_log_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "attestator-audit-{}-{name}.log",
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_file(&path);
+
+        path
+    }
+
+    #[test]
+    fn test_records_are_appended() {
+        let path = audit_log_path("append");
+
+        let audit_log = AuditLog::open(Some(&path)).unwrap();
+
+        assert!(audit_log.is_enabled());
+
+        audit_log
+            .append(
+                "relayer-1",
+                Felt::ONE,
+                &[(Felt::TWO, (Felt::THREE, Felt::ZERO), false)],
+            )
+            .unwrap();
+        audit_log
+            .append(
+                "relayer-2",
+                Felt::ONE,
+                &[(Felt::TWO, (Felt::THREE, Felt::ZERO), true)],
+            )
+            .unwrap();
+
+        // the records of a previous run are kept when the log is reopened
+        drop(audit_log);
+        let audit_log = AuditLog::open(Some(&path)).unwrap();
+        audit_log.append("relayer-3", Felt::ONE, &[]).unwrap();
+
+        let records = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["caller"], "relayer-1");
+        assert_eq!(records[0]["message"], "0x2");
+        assert_eq!(records[0]["signature"][0], "0x3");
+        assert_eq!(records[0]["cached"], false);
+        assert_eq!(records[1]["caller"], "relayer-2");
+        assert_eq!(records[1]["cached"], true);
+        assert!(OffsetDateTime::parse(records[0]["timestamp"].as_str().unwrap(), &Rfc3339).is_ok());
+
+        assert!(audit_log.check_writable().is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(audit_log.check_writable().is_err());
+    }
+
+    #[test]
+    fn test_disabled_audit_log() {
+        let audit_log = AuditLog::open(None).unwrap();
+
+        assert!(!audit_log.is_enabled());
+        assert!(
+            audit_log
+                .append(
+                    "relayer",
+                    Felt::ONE,
+                    &[(Felt::TWO, (Felt::THREE, Felt::ZERO), false)]
+                )
+                .is_ok()
+        );
+        assert!(audit_log.check_writable().is_ok());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_failed_append_is_reported() {
+        // writes to `/dev/full` always fail with `ENOSPC`
+        let audit_log = AuditLog::open(Some(Path::new("/dev/full"))).unwrap();
+
+        assert!(audit_log.check_writable().is_ok());
+
+        let error = audit_log
+            .append(
+                "relayer",
+                Felt::ONE,
+                &[(Felt::TWO, (Felt::THREE, Felt::ZERO), false)],
+            )
+            .unwrap_err();
+
+        assert_eq!(audit_log.check_writable(), Err(error));
+    }
+}
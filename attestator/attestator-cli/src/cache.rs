@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rocket::serde::Serialize;
+use starknet_crypto::Felt;
+
+/// Least recently used cache of the attestations, keyed by the signed message.
+///
+/// The message is the Poseidon hash of the challenge, so a cached entry can only
+/// be returned for the very same Ed25519 signature. Invalid signatures are not
+/// cached, so that a client sending them cannot evict the valid attestations.
+pub struct AttestationCache {
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Default)]
+struct LruEntries {
+    tick: u64,
+    entries: HashMap<Felt, (u64, (Felt, Felt))>,
+    // the messages by last access, from the least recently used
+    order: BTreeMap<u64, Felt>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CacheStats {
+    pub capacity: usize,
+    pub size: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl AttestationCache {
+    /// The cache is disabled when `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(LruEntries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, message: &Felt) -> Option<(Felt, Felt)> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let mut lru = self.entries.lock().unwrap();
+
+        let tick = lru.next_tick();
+
+        let result = match lru.entries.get_mut(message) {
+            Some((last_access, attestation)) => {
+                let previous_access = core::mem::replace(last_access, tick);
+                let attestation = *attestation;
+
+                lru.order.remove(&previous_access);
+                lru.order.insert(tick, *message);
+
+                Some(attestation)
+            }
+            None => None,
+        };
+
+        let counter = if result.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        result
+    }
+
+    pub fn insert(&self, message: Felt, attestation: (Felt, Felt)) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut lru = self.entries.lock().unwrap();
+
+        let tick = lru.next_tick();
+
+        if let Some((previous_access, _)) = lru.entries.insert(message, (tick, attestation)) {
+            lru.order.remove(&previous_access);
+        }
+
+        lru.order.insert(tick, message);
+
+        while lru.entries.len() > self.capacity {
+            let Some((_, evicted)) = lru.order.pop_first() else {
+                break;
+            };
+
+            lru.entries.remove(&evicted);
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            capacity: self.capacity,
+            size: self.entries.lock().unwrap().entries.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl LruEntries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attestation(value: u64) -> (Felt, Felt) {
+        (Felt::from(value), Felt::from(value + 1))
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let cache = AttestationCache::new(2);
+
+        cache.insert(Felt::ONE, attestation(1));
+        cache.insert(Felt::TWO, attestation(2));
+
+        // accessing the oldest entry makes the second one the least recently used
+        assert_eq!(cache.get(&Felt::ONE), Some(attestation(1)));
+
+        cache.insert(Felt::THREE, attestation(3));
+
+        assert_eq!(cache.get(&Felt::TWO), None);
+        assert_eq!(cache.get(&Felt::ONE), Some(attestation(1)));
+        assert_eq!(cache.get(&Felt::THREE), Some(attestation(3)));
+
+        // inserting an entry again refreshes it without growing the cache
+        cache.insert(Felt::ONE, attestation(1));
+        cache.insert(Felt::TWO, attestation(2));
+
+        assert_eq!(cache.get(&Felt::THREE), None);
+        assert_eq!(cache.get(&Felt::ONE), Some(attestation(1)));
+        assert_eq!(cache.stats().size, 2);
+    }
+
+    #[test]
+    fn test_hits_and_misses_are_counted() {
+        let cache = AttestationCache::new(16);
+
+        assert_eq!(cache.get(&Felt::ONE), None);
+
+        cache.insert(Felt::ONE, attestation(1));
+
+        assert_eq!(cache.get(&Felt::ONE), Some(attestation(1)));
+        assert_eq!(cache.get(&Felt::ONE), Some(attestation(1)));
+        assert_eq!(cache.get(&Felt::TWO), None);
+
+        let stats = cache.stats();
+
+        assert_eq!(stats.capacity, 16);
+        assert_eq!(stats.size, 1);
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 2);
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_the_cache() {
+        let cache = AttestationCache::new(0);
+
+        cache.insert(Felt::ONE, attestation(1));
+
+        assert_eq!(cache.get(&Felt::ONE), None);
+
+        let stats = cache.stats();
+
+        assert_eq!(stats.size, 0);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 0);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use attestator::AttestationError;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use subtle::ConstantTimeEq;
+
+use crate::config::{AttestatorConfig, RateLimitConfig};
+
+/// Identity of the client of an attestation request: the common name of its TLS
+/// certificate, the name of its bearer token, or else its IP address.
+pub struct Caller(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Caller {
+    type Error = AttestationError;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let caller = match authenticate(request).await {
+            Ok(caller) => caller,
+            Err(e) => return fail(request, Status::Unauthorized, e),
+        };
+
+        let rate_limiter = request
+            .rocket()
+            .state::<RateLimiter>()
+            .expect("RateLimiter is managed");
+
+        if !rate_limiter.check(&caller.0) {
+            return fail(
+                request,
+                Status::TooManyRequests,
+                AttestationError::RateLimited,
+            );
+        }
+
+        Outcome::Success(caller)
+    }
+}
+
+async fn authenticate(request: &Request<'_>) -> Result<Caller, AttestationError> {
+    #[cfg(feature = "mtls")]
+    if let Outcome::Success(certificate) = request.guard::<rocket::mtls::Certificate<'_>>().await {
+        if let Some(name) = certificate.subject().common_name() {
+            return Ok(Caller(name.to_string()));
+        }
+    }
+
+    let config = request
+        .rocket()
+        .state::<AttestatorConfig>()
+        .expect("AttestatorConfig is managed");
+
+    if config.auth_tokens.is_empty() {
+        let client_ip = request
+            .client_ip()
+            .map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+
+        return Ok(Caller(client_ip));
+    }
+
+    let token = request
+        .headers()
+        .get_one("Authorization")
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(AttestationError::Unauthorized)?;
+
+    config
+        .auth_tokens
+        .iter()
+        .find(|(_, expected)| bool::from(expected.as_bytes().ct_eq(token.as_bytes())))
+        .map(|(name, _)| Caller(name.clone()))
+        .ok_or(AttestationError::Unauthorized)
+}
+
+/// Fails the request, keeping the error for the JSON error catcher.
+fn fail(
+    request: &Request<'_>,
+    status: Status,
+    error: AttestationError,
+) -> Outcome<Caller, AttestationError> {
+    request.local_cache(|| Some(error.clone()));
+
+    Outcome::Error((status, error))
+}
+
+/// Fixed window rate limiter, counting the requests of each client.
+pub struct RateLimiter {
+    config: Option<RateLimitConfig>,
+    windows: Mutex<HashMap<String, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: Option<RateLimitConfig>) -> Self {
+        Self {
+            config,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn check(&self, caller: &str) -> bool {
+        let Some(config) = &self.config else {
+            return true;
+        };
+
+        let period = Duration::from_secs(config.period_secs);
+        let now = Instant::now();
+
+        let mut windows = self.windows.lock().unwrap();
+
+        // drop the expired windows, so that the map does not grow with every client IP
+        windows.retain(|_, (start, _)| now.duration_since(*start) < period);
+
+        let (_, count) = windows.entry(caller.to_string()).or_insert((now, 0));
+
+        if *count >= config.max_requests {
+            return false;
+        }
+
+        *count += 1;
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter_counts_requests_per_caller() {
+        let rate_limiter = RateLimiter::new(Some(RateLimitConfig {
+            max_requests: 2,
+            period_secs: 3600,
+        }));
+
+        assert!(rate_limiter.check("relayer-1"));
+        assert!(rate_limiter.check("relayer-1"));
+        assert!(!rate_limiter.check("relayer-1"));
+
+        assert!(rate_limiter.check("relayer-2"));
+    }
+
+    #[test]
+    fn test_rate_limiter_resets_after_period() {
+        let rate_limiter = RateLimiter::new(Some(RateLimitConfig {
+            max_requests: 1,
+            period_secs: 0,
+        }));
+
+        assert!(rate_limiter.check("relayer"));
+        assert!(rate_limiter.check("relayer"));
+    }
+
+    #[test]
+    fn test_rate_limiter_is_disabled_without_config() {
+        let rate_limiter = RateLimiter::new(None);
+
+        assert!((0..100).all(|_| rate_limiter.check("relayer")));
+    }
+}
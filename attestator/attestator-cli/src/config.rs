@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use rocket::serde::Deserialize;
+use starknet_crypto::Felt;
+
+/// Configuration of the attestator node, read by Rocket from `Rocket.toml` and the
+/// `ROCKET_*` environment variables together with the Rocket configuration.
+#[derive(Debug, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AttestatorConfig {
+    /// Source of the signing key of the attestator.
+    #[serde(default)]
+    pub key: KeyConfig,
+    /// Public key the attestator rotates to next. It is published on `/keys`,
+    /// so that it can be trusted by the Comet clients ahead of the rotation.
+    #[serde(default)]
+    pub next_public_key: Option<Felt>,
+    /// Epoch of the current key, incremented with each rotation. Domain-bound
+    /// attestations are only signed for this epoch.
+    #[serde(default)]
+    pub key_epoch: u64,
+    /// Maximum number of challenges attested per request.
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+    /// Bearer tokens of the clients allowed to request attestations, by client name.
+    /// The clients are not authenticated when empty, except by their TLS certificate.
+    #[serde(default)]
+    pub auth_tokens: BTreeMap<String, String>,
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Maximum number of attestations kept in the cache. The cache is disabled with `0`.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: usize,
+    /// Append-only log of the messages signed, with the caller of each request.
+    #[serde(default)]
+    pub audit_log: Option<PathBuf>,
+}
+
+/// Where the attestator loads its signing key from.
+#[derive(Debug, Default, Deserialize)]
+#[serde(crate = "rocket::serde", tag = "type", rename_all = "snake_case")]
+pub enum KeyConfig {
+    /// Hex private key from the `PRIVATE_KEY` environment variable.
+    #[default]
+    Env,
+    /// Encrypted keystore file, such as written by `new-keystore`.
+    Keystore {
+        path: PathBuf,
+        #[serde(default = "default_password_env")]
+        password_env: String,
+    },
+    /// Remote signer holding the private key of `public_key`.
+    Remote {
+        url: String,
+        public_key: Felt,
+        #[serde(default)]
+        auth_token_env: Option<String>,
+        /// Timeout of the requests to the remote signer.
+        #[serde(default = "default_remote_timeout_secs")]
+        timeout_secs: u64,
+    },
+}
+
+/// Maximum number of attestation requests of each client per period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RateLimitConfig {
+    pub max_requests: u32,
+    pub period_secs: u64,
+}
+
+fn default_max_batch_size() -> usize {
+    512
+}
+
+fn default_cache_size() -> usize {
+    16384
+}
+
+fn default_remote_timeout_secs() -> u64 {
+    10
+}
+
+fn default_password_env() -> String {
+    "ATTESTATOR_KEYSTORE_PASSWORD".to_string()
+}
@@ -0,0 +1,173 @@
+use std::time::Instant;
+
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+
+use crate::cache::CacheStats;
+
+const BATCH_SIZE_BUCKETS: [f64; 8] = [1.0, 10.0, 50.0, 100.0, 150.0, 200.0, 500.0, 1000.0];
+
+const LATENCY_BUCKETS: [f64; 9] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 5.0];
+
+/// Metrics of the attestation requests, served on `/metrics` in the Prometheus text format.
+pub struct AttestatorMetrics {
+    registry: Registry,
+    // attestation requests, by response status
+    requests: IntCounterVec,
+    batch_size: Histogram,
+    request_duration: Histogram,
+    verification_failures: IntCounter,
+    signatures: IntCounter,
+}
+
+impl AttestatorMetrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let metrics = Self {
+            registry: Registry::new(),
+            requests: IntCounterVec::new(
+                Opts::new(
+                    "attestator_requests_total",
+                    "Attestation requests by response status.",
+                ),
+                &["status"],
+            )?,
+            batch_size: Histogram::with_opts(
+                HistogramOpts::new(
+                    "attestator_batch_size",
+                    "Number of challenges per attestation request.",
+                )
+                .buckets(BATCH_SIZE_BUCKETS.to_vec()),
+            )?,
+            request_duration: Histogram::with_opts(
+                HistogramOpts::new(
+                    "attestator_request_duration_seconds",
+                    "Latency of the attestation requests.",
+                )
+                .buckets(LATENCY_BUCKETS.to_vec()),
+            )?,
+            verification_failures: IntCounter::new(
+                "attestator_verification_failures_total",
+                "Challenges with an invalid Ed25519 signature.",
+            )?,
+            signatures: IntCounter::new(
+                "attestator_signatures_total",
+                "Messages signed by the attestator key.",
+            )?,
+        };
+
+        metrics
+            .registry
+            .register(Box::new(metrics.requests.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.batch_size.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.request_duration.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.verification_failures.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.signatures.clone()))?;
+
+        Ok(metrics)
+    }
+
+    pub fn record_batch(&self, batch_size: usize) {
+        self.batch_size.observe(batch_size as f64);
+    }
+
+    pub fn record_verification_failures(&self, count: usize) {
+        self.verification_failures.inc_by(count as u64);
+    }
+
+    pub fn record_signatures(&self, count: usize) {
+        self.signatures.inc_by(count as u64);
+    }
+
+    fn record_request(&self, status: u16, duration: f64) {
+        self.requests
+            .with_label_values(&[&status.to_string()])
+            .inc();
+
+        self.request_duration.observe(duration);
+    }
+
+    /// Encodes the metrics together with the statistics of the attestation cache.
+    pub fn render(&self, cache: &CacheStats) -> Result<String, prometheus::Error> {
+        let mut families = self.registry.gather();
+
+        families.extend(cache_metrics(cache)?.gather());
+
+        let mut output = Vec::new();
+
+        TextEncoder::new().encode(&families, &mut output)?;
+
+        String::from_utf8(output).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+}
+
+/// The cache keeps its own counters, which are exported as of the time of the scrape.
+fn cache_metrics(cache: &CacheStats) -> Result<Registry, prometheus::Error> {
+    let registry = Registry::new();
+
+    let hits = IntCounter::new(
+        "attestator_cache_hits_total",
+        "Attestations returned from the cache.",
+    )?;
+    hits.inc_by(cache.hits);
+
+    let misses = IntCounter::new(
+        "attestator_cache_misses_total",
+        "Attestations missing from the cache.",
+    )?;
+    misses.inc_by(cache.misses);
+
+    let size = IntGauge::new("attestator_cache_size", "Attestations in the cache.")?;
+    size.set(cache.size as i64);
+
+    registry.register(Box::new(hits))?;
+    registry.register(Box::new(misses))?;
+    registry.register(Box::new(size))?;
+
+    Ok(registry)
+}
+
+/// Records the status and the latency of the attestation requests, including the
+/// ones rejected by the request guards.
+pub struct MetricsFairing;
+
+struct RequestStart(Instant);
+
+#[rocket::async_trait]
+impl Fairing for MetricsFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Attestator metrics",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        request.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        if request.uri().path() != "/attest" {
+            return;
+        }
+
+        let Some(metrics) = request.rocket().state::<AttestatorMetrics>() else {
+            return;
+        };
+
+        let RequestStart(start) = request.local_cache(|| RequestStart(Instant::now()));
+
+        metrics.record_request(response.status().code, start.elapsed().as_secs_f64());
+    }
+}
@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use starknet_signers::SigningKey;
+
+/// Generates a random attestator key and writes it to an encrypted keystore,
+/// with the password from `ATTESTATOR_KEYSTORE_PASSWORD`.
+fn main() {
+    let path: PathBuf = std::env::args_os()
+        .nth(1)
+        .expect("Usage: new-keystore <PATH>")
+        .into();
+
+    assert!(!path.exists(), "{} already exists", path.display());
+
+    let password = std::env::var("ATTESTATOR_KEYSTORE_PASSWORD")
+        .expect("ATTESTATOR_KEYSTORE_PASSWORD environment variable not set");
+
+    let signing_key = SigningKey::from_random();
+
+    signing_key
+        .save_as_keystore(&path, &password)
+        .expect("Failed to write keystore");
+
+    println!("{}", signing_key.verifying_key().scalar().to_hex_string());
+}
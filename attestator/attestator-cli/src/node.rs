@@ -1,48 +1,568 @@
-use std::sync::OnceLock;
-
-use attestator::Ed25519;
+use attestator::{Attestation, AttestationError, AttestationRequest, AttestatorKeys};
+use rocket::http::{ContentType, Status};
+use rocket::serde::Serialize;
 use rocket::serde::json::Json as Codec;
-use rocket::{get, launch, post, routes};
-use starknet_crypto::{Felt, get_public_key};
+use rocket::{Build, Request, Rocket, State, catch, catchers, get, launch, post, routes};
+use starknet_crypto::Felt;
 
-static KEY: OnceLock<(Felt, Felt)> = OnceLock::new();
+mod audit;
+mod cache;
+mod caller;
+mod config;
+mod metrics;
+mod signer;
 
-fn key() -> &'static (Felt, Felt) {
-    KEY.get_or_init(|| {
-        let private_key = Felt::from_hex(
-            &std::env::var("PRIVATE_KEY").expect("PRIVATE_KEY environment variable not set"),
-        )
-        .expect("Invalid PRIVATE_KEY format");
-        let public_key = get_public_key(&private_key);
-        (private_key, public_key)
-    })
-}
+use audit::AuditLog;
+use cache::{AttestationCache, CacheStats};
+use caller::{Caller, RateLimiter};
+use config::AttestatorConfig;
+use metrics::{AttestatorMetrics, MetricsFairing};
+use signer::{AttestationSigner, load_signer};
+
+type ErrorResponse = (Status, Codec<AttestationError>);
 
 #[post("/attest", data = "<data>")]
-fn attest_api(data: Codec<Vec<Ed25519>>) -> Option<Codec<(Felt, Vec<(Felt, Felt)>)>> {
-    let (private_key, public_key) = key();
+async fn attest_api(
+    caller: Caller,
+    config: &State<AttestatorConfig>,
+    signer: &State<Box<dyn AttestationSigner>>,
+    cache: &State<AttestationCache>,
+    metrics: &State<AttestatorMetrics>,
+    audit_log: &State<AuditLog>,
+    data: Codec<AttestationRequest>,
+) -> Result<Codec<Attestation>, ErrorResponse> {
+    let request = data.into_inner();
+
+    let challenges = request.challenges();
+
+    metrics.record_batch(challenges.len());
+
+    if challenges.len() > config.max_batch_size {
+        return Err((
+            Status::PayloadTooLarge,
+            Codec(AttestationError::BatchTooLarge {
+                max_batch_size: config.max_batch_size,
+            }),
+        ));
+    }
+
+    if let Some(domain) = request.domain() {
+        if domain.key_epoch != config.key_epoch {
+            return Err((
+                Status::UnprocessableEntity,
+                Codec(AttestationError::KeyEpochMismatch {
+                    key_epoch: config.key_epoch,
+                }),
+            ));
+        }
+    }
+
+    let mut messages = Vec::with_capacity(challenges.len());
+    let mut indices = Vec::new();
+
+    for (index, challenge) in challenges.iter().enumerate() {
+        let message = challenge.attestation_message(request.domain());
+
+        match cache.get(&message) {
+            Some(attestation) => messages.push((message, Some(attestation))),
+            None if challenge.verify().is_some() => messages.push((message, None)),
+            None => indices.push(index),
+        }
+    }
+
+    if !indices.is_empty() {
+        metrics.record_verification_failures(indices.len());
 
-    let challenges = data.into_inner();
+        return Err((
+            Status::UnprocessableEntity,
+            Codec(AttestationError::InvalidSignatures { indices }),
+        ));
+    }
+
+    // the messages missing from the cache are signed in a single batch
+    let unsigned_messages = messages
+        .iter()
+        .filter(|(_, cached_attestation)| cached_attestation.is_none())
+        .map(|(message, _)| *message)
+        .collect::<Vec<_>>();
+
+    let mut new_attestations = signer
+        .sign_batch(&unsigned_messages)
+        .await
+        .map_err(|message| {
+            (
+                Status::ServiceUnavailable,
+                Codec(AttestationError::SignerUnavailable { message }),
+            )
+        })?
+        .into_iter();
+
+    metrics.record_signatures(unsigned_messages.len());
+
+    let mut signed_messages = Vec::with_capacity(messages.len());
+
+    for (message, cached_attestation) in messages {
+        let attestation = match cached_attestation {
+            Some(attestation) => attestation,
+            None => {
+                let attestation = new_attestations
+                    .next()
+                    .expect("the signer returns one signature per message");
+
+                cache.insert(message, attestation);
+
+                attestation
+            }
+        };
 
-    challenges
+        signed_messages.push((message, attestation, cached_attestation.is_some()));
+    }
+
+    // the attestations are only returned once they are recorded in the audit log
+    audit_log
+        .append(&caller.0, signer.public_key(), &signed_messages)
+        .map_err(|message| {
+            (
+                Status::ServiceUnavailable,
+                Codec(AttestationError::AuditLogUnavailable { message }),
+            )
+        })?;
+
+    let attestations = signed_messages
         .into_iter()
-        .map(|challenge| challenge.attest(private_key))
-        .collect::<Option<Vec<_>>>()
-        .map(|attestations| (*public_key, attestations))
-        .map(Codec)
+        .map(|(_, attestation, _)| attestation)
+        .collect();
+
+    Ok(Codec((signer.public_key(), attestations)))
 }
 
 #[get("/public_key")]
-fn public_key_api() -> Codec<Felt> {
-    let (_, public_key) = key();
+fn public_key_api(signer: &State<Box<dyn AttestationSigner>>) -> Codec<Felt> {
+    Codec(signer.public_key())
+}
+
+#[get("/keys")]
+fn keys_api(
+    config: &State<AttestatorConfig>,
+    signer: &State<Box<dyn AttestationSigner>>,
+) -> Codec<AttestatorKeys> {
+    Codec(AttestatorKeys {
+        public_key: signer.public_key(),
+        next_public_key: config.next_public_key,
+    })
+}
 
-    Codec(*public_key)
+#[get("/cache")]
+fn cache_api(cache: &State<AttestationCache>) -> Codec<CacheStats> {
+    Codec(cache.stats())
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+struct Health {
+    status: &'static str,
+    public_key: Felt,
+    audit_log: bool,
+    errors: Vec<String>,
+}
+
+/// Responds with `503` unless the signer is reachable and the audit log is writable.
+#[get("/health")]
+async fn health_api(
+    signer: &State<Box<dyn AttestationSigner>>,
+    audit_log: &State<AuditLog>,
+) -> (Status, Codec<Health>) {
+    let errors = [signer.check_health().await, audit_log.check_writable()]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect::<Vec<_>>();
+
+    let (status, health_status) = if errors.is_empty() {
+        (Status::Ok, "ok")
+    } else {
+        (Status::ServiceUnavailable, "degraded")
+    };
+
+    (
+        status,
+        Codec(Health {
+            status: health_status,
+            public_key: signer.public_key(),
+            audit_log: audit_log.is_enabled(),
+            errors,
+        }),
+    )
+}
+
+#[get("/metrics")]
+fn metrics_api(
+    metrics: &State<AttestatorMetrics>,
+    cache: &State<AttestationCache>,
+) -> Result<(ContentType, String), Status> {
+    let output = metrics
+        .render(&cache.stats())
+        .map_err(|_| Status::InternalServerError)?;
+
+    Ok((ContentType::Plain, output))
+}
+
+#[catch(default)]
+fn error_catcher(status: Status, request: &Request<'_>) -> ErrorResponse {
+    let error = request
+        .local_cache(|| None::<AttestationError>)
+        .clone()
+        .unwrap_or_else(|| {
+            if status == Status::PayloadTooLarge {
+                AttestationError::BodyTooLarge
+            } else {
+                AttestationError::InvalidRequest {
+                    message: status.to_string(),
+                }
+            }
+        });
+
+    (status, Codec(error))
 }
 
 #[launch]
 fn rocket() -> _ {
-    {
-        let (_, _) = key(); // Ensure the private key is initialized at startup
+    let rocket = rocket::build();
+
+    let config: AttestatorConfig = rocket
+        .figment()
+        .extract()
+        .expect("Invalid attestator configuration");
+
+    let signer = load_signer(&config.key).expect("Failed to load the attestator key");
+
+    attestator(rocket, config, signer)
+}
+
+/// Mounts the attestator API on `rocket`, signing with `signer`.
+fn attestator(
+    rocket: Rocket<Build>,
+    config: AttestatorConfig,
+    signer: Box<dyn AttestationSigner>,
+) -> Rocket<Build> {
+    let audit_log =
+        AuditLog::open(config.audit_log.as_deref()).expect("Failed to open the audit log");
+
+    rocket
+        .manage(RateLimiter::new(config.rate_limit.clone()))
+        .manage(AttestationCache::new(config.cache_size))
+        .manage(AttestatorMetrics::new().expect("Failed to register the metrics"))
+        .manage(audit_log)
+        .manage(signer)
+        .manage(config)
+        .attach(MetricsFairing)
+        .mount(
+            "/",
+            routes![
+                attest_api,
+                public_key_api,
+                keys_api,
+                cache_api,
+                health_api,
+                metrics_api
+            ],
+        )
+        .register("/", catchers![error_catcher])
+}
+
+#[cfg(test)]
+mod tests {
+    use attestator::{AsyncAttestatorClient, AttestationDomain, Ed25519};
+    use rocket::figment::Figment;
+    use rocket::figment::providers::Serialized;
+    use rocket::http::Header;
+    use rocket::local::blocking::Client;
+    use rocket::serde::json::{Value, json};
+    use signer::LocalSigner;
+    use starknet_crypto::{get_public_key, verify};
+    use starknet_signers::SigningKey;
+
+    use super::*;
+
+    fn private_key() -> Felt {
+        Felt::from_hex("0x1234").unwrap()
+    }
+
+    fn test_rocket(config: Value) -> Rocket<Build> {
+        let figment = Figment::from(rocket::Config::debug_default())
+            .merge(Serialized::defaults(json!({ "log_level": "off" })))
+            .merge(Serialized::defaults(config));
+
+        let rocket = rocket::custom(figment);
+
+        let config = rocket.figment().extract().unwrap();
+
+        let signer = Box::new(LocalSigner(SigningKey::from_secret_scalar(private_key())));
+
+        attestator(rocket, config, signer)
+    }
+
+    fn test_client(config: Value) -> Client {
+        Client::tracked(test_rocket(config)).unwrap()
+    }
+
+    fn challenge() -> Ed25519 {
+        // test 3 from https://datatracker.ietf.org/doc/html/rfc8032
+        rocket::serde::json::from_value(json!({
+            "message": "af82",
+            "signature": "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            "public_key": "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        }))
+        .unwrap()
+    }
+
+    fn invalid_challenge() -> Ed25519 {
+        Ed25519 {
+            message: vec![0xaf, 0x83],
+            ..challenge()
+        }
+    }
+
+    fn attest(client: &Client, auth_token: Option<&str>) -> (Status, Value) {
+        let mut request = client.post("/attest").json(&vec![challenge()]);
+
+        if let Some(auth_token) = auth_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {auth_token}")));
+        }
+
+        let response = request.dispatch();
+
+        (response.status(), response.into_json().unwrap())
+    }
+
+    fn error(error: AttestationError) -> Value {
+        rocket::serde::json::to_value(error).unwrap()
+    }
+
+    #[test]
+    fn test_caller_is_authenticated_by_bearer_token() {
+        let client = test_client(json!({ "auth_tokens": { "relayer": "secret" } }));
+
+        assert_eq!(
+            attest(&client, None),
+            (Status::Unauthorized, error(AttestationError::Unauthorized))
+        );
+        assert_eq!(
+            attest(&client, Some("other")),
+            (Status::Unauthorized, error(AttestationError::Unauthorized))
+        );
+        assert_eq!(attest(&client, Some("secret")).0, Status::Ok);
+    }
+
+    #[test]
+    fn test_caller_is_rate_limited() {
+        let client = test_client(json!({
+            "auth_tokens": { "relayer-1": "secret-1", "relayer-2": "secret-2" },
+            "rate_limit": { "max_requests": 1, "period_secs": 3600 },
+        }));
+
+        assert_eq!(attest(&client, Some("secret-1")).0, Status::Ok);
+        assert_eq!(
+            attest(&client, Some("secret-1")),
+            (
+                Status::TooManyRequests,
+                error(AttestationError::RateLimited)
+            )
+        );
+
+        // the requests are counted per caller
+        assert_eq!(attest(&client, Some("secret-2")).0, Status::Ok);
+    }
+
+    #[test]
+    fn test_errors_are_json() {
+        let client = test_client(json!({ "limits": { "json": 64 } }));
+
+        let response = client.get("/unknown").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(
+            response.into_json::<Value>().unwrap(),
+            error(AttestationError::InvalidRequest {
+                message: Status::NotFound.to_string(),
+            })
+        );
+
+        let response = client
+            .post("/attest")
+            .header(ContentType::JSON)
+            .body("[{}]")
+            .dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            response.into_json::<Value>().unwrap(),
+            error(AttestationError::InvalidRequest {
+                message: Status::UnprocessableEntity.to_string(),
+            })
+        );
+
+        let response = client.post("/attest").json(&vec![challenge()]).dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+        assert_eq!(
+            response.into_json::<Value>().unwrap(),
+            error(AttestationError::BodyTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_invalid_signatures_are_reported() {
+        let client = test_client(json!({}));
+
+        let response = client
+            .post("/attest")
+            .json(&vec![challenge(), invalid_challenge()])
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            response.into_json::<Value>().unwrap(),
+            error(AttestationError::InvalidSignatures { indices: vec![1] })
+        );
+    }
+
+    #[test]
+    fn test_domain_key_epoch_is_checked() {
+        let client = test_client(json!({ "key_epoch": 1 }));
+
+        let attest_in_epoch = |key_epoch| {
+            let domain = AttestationDomain {
+                counterparty_chain_id: "cosmoshub-4".to_string(),
+                client_id: "07-tendermint-0".to_string(),
+                key_epoch,
+                expiry_height: 100,
+            };
+
+            let response = client
+                .post("/attest")
+                .json(&AttestationRequest::new(Some(&domain), &[challenge()]))
+                .dispatch();
+
+            (response.status(), response.into_json::<Value>().unwrap())
+        };
+
+        assert_eq!(
+            attest_in_epoch(0),
+            (
+                Status::UnprocessableEntity,
+                error(AttestationError::KeyEpochMismatch { key_epoch: 1 })
+            )
+        );
+        assert_eq!(attest_in_epoch(1).0, Status::Ok);
+    }
+
+    #[test]
+    fn test_public_key_and_keys() {
+        let next_public_key = Felt::from_hex("0x5678").unwrap();
+
+        let client = test_client(json!({ "next_public_key": next_public_key }));
+
+        let public_key = get_public_key(&private_key());
+
+        // `/public_key` is kept as the bare public key for the existing clients
+        let response = client.get("/public_key").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_json::<Felt>().unwrap(), public_key);
+
+        let response = client.get("/keys").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.into_json::<AttestatorKeys>().unwrap(),
+            AttestatorKeys {
+                public_key,
+                next_public_key: Some(next_public_key),
+            }
+        );
+    }
+
+    #[test]
+    fn test_health_and_metrics() {
+        let client = test_client(json!({}));
+
+        let response = client.get("/health").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let health = response.into_json::<Value>().unwrap();
+        assert_eq!(health["status"], "ok");
+        assert_eq!(health["audit_log"], false);
+
+        assert_eq!(attest(&client, None).0, Status::Ok);
+        assert_eq!(attest(&client, None).0, Status::Ok);
+
+        let metrics = client.get("/metrics").dispatch().into_string().unwrap();
+
+        assert!(metrics.contains("attestator_requests_total{status=\"200\"} 2\n"));
+        assert!(metrics.contains("attestator_batch_size_count 2\n"));
+        assert!(metrics.contains("attestator_signatures_total 1\n"));
+        assert!(metrics.contains("attestator_cache_hits_total 1\n"));
+        assert!(metrics.contains("attestator_cache_size 1\n"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_audit_log_failure_is_reported() {
+        // writes to `/dev/full` always fail with `ENOSPC`
+        let client = test_client(json!({ "audit_log": "/dev/full" }));
+
+        let (status, error) = attest(&client, None);
+        assert_eq!(status, Status::ServiceUnavailable);
+        assert_eq!(error["error"], "audit_log_unavailable");
+
+        let response = client.get("/health").dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+
+        let health = response.into_json::<Value>().unwrap();
+        assert_eq!(health["status"], "degraded");
+        assert_eq!(health["errors"].as_array().unwrap().len(), 1);
+    }
+
+    #[rocket::async_test]
+    async fn test_valid_attestation_retries_valid_challenges() {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let (liftoff_sender, liftoff_receiver) = rocket::tokio::sync::oneshot::channel();
+
+        let rocket = test_rocket(json!({ "address": "127.0.0.1", "port": port }))
+            .attach(rocket::fairing::AdHoc::on_liftoff("Liftoff", |_| {
+                Box::pin(async move {
+                    liftoff_sender.send(()).unwrap();
+                })
+            }))
+            .ignite()
+            .await
+            .unwrap();
+
+        let shutdown = rocket.shutdown();
+
+        let server = rocket::tokio::spawn(rocket.launch());
+
+        liftoff_receiver.await.unwrap();
+
+        let address = format!("http://127.0.0.1:{port}");
+        let http_client = reqwest::Client::new();
+
+        let challenges = vec![invalid_challenge(), challenge(), invalid_challenge()];
+
+        let (public_key, attestations) = AsyncAttestatorClient::new(&http_client, &address)
+            .get_valid_attestation(None, &challenges)
+            .await
+            .unwrap();
+
+        assert_eq!(public_key, get_public_key(&private_key()));
+        assert_eq!(attestations.len(), challenges.len());
+        assert!(attestations[0].is_none());
+        assert!(attestations[2].is_none());
+
+        let (r, s) = attestations[1].unwrap();
+        assert!(verify(&public_key, &challenge().signed_message(), &r, &s).unwrap());
+
+        shutdown.notify();
+        server.await.unwrap().unwrap();
     }
-    rocket::build().mount("/", routes![attest_api, public_key_api])
 }
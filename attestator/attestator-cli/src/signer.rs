@@ -0,0 +1,346 @@
+use std::time::Duration;
+
+use rocket::serde::{Deserialize, Serialize};
+use rocket::tokio::task::spawn_blocking;
+use starknet_crypto::Felt;
+use starknet_signers::SigningKey;
+
+use crate::config::KeyConfig;
+
+/// Signs the attestation messages with the key of the attestator.
+#[rocket::async_trait]
+pub trait AttestationSigner: Send + Sync {
+    fn public_key(&self) -> Felt;
+
+    async fn sign(&self, message: &Felt) -> Result<(Felt, Felt), String>;
+
+    /// Signs the messages, returning one signature per message in the same order.
+    async fn sign_batch(&self, messages: &[Felt]) -> Result<Vec<(Felt, Felt)>, String> {
+        let mut signatures = Vec::with_capacity(messages.len());
+
+        for message in messages {
+            signatures.push(self.sign(message).await?);
+        }
+
+        Ok(signatures)
+    }
+
+    /// Checks that the signer is able to sign, without signing anything.
+    async fn check_health(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+pub fn load_signer(config: &KeyConfig) -> Result<Box<dyn AttestationSigner>, String> {
+    match config {
+        KeyConfig::Env => {
+            let private_key = std::env::var("PRIVATE_KEY")
+                .map_err(|_| "PRIVATE_KEY environment variable not set".to_string())?;
+
+            let private_key = Felt::from_hex(&private_key)
+                .map_err(|e| format!("Invalid PRIVATE_KEY format: {e}"))?;
+
+            Ok(Box::new(LocalSigner(SigningKey::from_secret_scalar(
+                private_key,
+            ))))
+        }
+        KeyConfig::Keystore { path, password_env } => {
+            let password = std::env::var(password_env)
+                .map_err(|_| format!("{password_env} environment variable not set"))?;
+
+            let signing_key = SigningKey::from_keystore(path, &password)
+                .map_err(|e| format!("failed to load keystore {}: {e}", path.display()))?;
+
+            Ok(Box::new(LocalSigner(signing_key)))
+        }
+        KeyConfig::Remote {
+            url,
+            public_key,
+            auth_token_env,
+            timeout_secs,
+        } => {
+            let auth_token = auth_token_env
+                .as_ref()
+                .map(|auth_token_env| {
+                    std::env::var(auth_token_env)
+                        .map_err(|_| format!("{auth_token_env} environment variable not set"))
+                })
+                .transpose()?;
+
+            Ok(Box::new(RemoteSigner::new(
+                url.clone(),
+                *public_key,
+                auth_token,
+                Duration::from_secs(*timeout_secs),
+            )))
+        }
+    }
+}
+
+/// Signer holding the private key in memory.
+pub struct LocalSigner(pub SigningKey);
+
+#[rocket::async_trait]
+impl AttestationSigner for LocalSigner {
+    fn public_key(&self) -> Felt {
+        self.0.verifying_key().scalar()
+    }
+
+    async fn sign(&self, message: &Felt) -> Result<(Felt, Felt), String> {
+        let signature = self.0.sign(message).map_err(|e| e.to_string())?;
+
+        Ok((signature.r, signature.s))
+    }
+}
+
+/// Signer delegating to a remote signing service, which is sent the public key and
+/// the message hashes of a batch, and answers with their signatures. The returned
+/// signatures are verified against the configured public key.
+pub struct RemoteSigner {
+    agent: ureq::Agent,
+    url: String,
+    public_key: Felt,
+    auth_token: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+struct RemoteSignRequest {
+    public_key: Felt,
+    hashes: Vec<Felt>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct RemoteSignResponse {
+    signatures: Vec<(Felt, Felt)>,
+}
+
+impl RemoteSigner {
+    pub fn new(
+        url: String,
+        public_key: Felt,
+        auth_token: Option<String>,
+        timeout: Duration,
+    ) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(timeout))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            url,
+            public_key,
+            auth_token,
+        }
+    }
+
+    async fn request_signatures(&self, hashes: Vec<Felt>) -> Result<Vec<(Felt, Felt)>, String> {
+        let agent = self.agent.clone();
+        let url = self.url.clone();
+        let auth_token = self.auth_token.clone();
+
+        let request = RemoteSignRequest {
+            public_key: self.public_key,
+            hashes,
+        };
+
+        let response = spawn_blocking(move || {
+            let mut request_builder = agent.post(&url);
+
+            if let Some(auth_token) = auth_token {
+                request_builder =
+                    request_builder.header("Authorization", &format!("Bearer {auth_token}"));
+            }
+
+            request_builder
+                .send_json(request)?
+                .body_mut()
+                .read_json::<RemoteSignResponse>()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("remote signer error: {e}"))?;
+
+        Ok(response.signatures)
+    }
+}
+
+#[rocket::async_trait]
+impl AttestationSigner for RemoteSigner {
+    fn public_key(&self) -> Felt {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &Felt) -> Result<(Felt, Felt), String> {
+        let signatures = self.sign_batch(core::slice::from_ref(message)).await?;
+
+        Ok(signatures[0])
+    }
+
+    async fn sign_batch(&self, messages: &[Felt]) -> Result<Vec<(Felt, Felt)>, String> {
+        if messages.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let signatures = self.request_signatures(messages.to_vec()).await?;
+
+        if signatures.len() != messages.len() {
+            return Err(format!(
+                "remote signer returned {} signatures for {} messages",
+                signatures.len(),
+                messages.len()
+            ));
+        }
+
+        for (message, (r, s)) in messages.iter().zip(&signatures) {
+            if !starknet_crypto::verify(&self.public_key, message, r, s).unwrap_or(false) {
+                return Err("remote signer returned an invalid signature".to_string());
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    /// Sends an empty batch, which the remote signer answers without signing.
+    async fn check_health(&self) -> Result<(), String> {
+        let signatures = self.request_signatures(Vec::new()).await?;
+
+        if !signatures.is_empty() {
+            return Err(format!(
+                "remote signer returned {} signatures for an empty batch",
+                signatures.len()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::fairing::AdHoc;
+    use rocket::serde::json::{Json, json};
+    use rocket::tokio::sync::oneshot;
+    use rocket::{Shutdown, post, routes};
+
+    use super::*;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_secret_scalar(Felt::from_hex("0x1234").unwrap())
+    }
+
+    #[derive(Deserialize)]
+    #[serde(crate = "rocket::serde")]
+    struct MockSignRequest {
+        hashes: Vec<Felt>,
+    }
+
+    /// Remote signer signing the first hash with another key.
+    #[post("/sign", data = "<request>")]
+    fn sign_api(request: Json<MockSignRequest>) -> Json<rocket::serde::json::Value> {
+        let signatures = request
+            .hashes
+            .iter()
+            .enumerate()
+            .map(|(index, hash)| {
+                let signing_key = if index == 0 && request.hashes.len() == 3 {
+                    SigningKey::from_secret_scalar(Felt::TWO)
+                } else {
+                    signing_key()
+                };
+
+                let signature = signing_key.sign(hash).unwrap();
+
+                (signature.r, signature.s)
+            })
+            .collect::<Vec<_>>();
+
+        Json(json!({ "signatures": signatures }))
+    }
+
+    async fn launch_remote_signer() -> (String, Shutdown) {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let (liftoff_sender, liftoff_receiver) = oneshot::channel();
+
+        let figment = rocket::figment::Figment::from(rocket::Config::debug_default())
+            .merge(("address", "127.0.0.1"))
+            .merge(("port", port))
+            .merge(("log_level", "off"));
+
+        let rocket = rocket::custom(figment)
+            .mount("/", routes![sign_api])
+            .attach(AdHoc::on_liftoff("Liftoff", |_| {
+                Box::pin(async move {
+                    liftoff_sender.send(()).unwrap();
+                })
+            }))
+            .ignite()
+            .await
+            .unwrap();
+
+        let shutdown = rocket.shutdown();
+
+        rocket::tokio::spawn(rocket.launch());
+
+        liftoff_receiver.await.unwrap();
+
+        (format!("http://127.0.0.1:{port}/sign"), shutdown)
+    }
+
+    #[rocket::async_test]
+    async fn test_remote_signer_signs_batch() {
+        let (url, shutdown) = launch_remote_signer().await;
+
+        let public_key = signing_key().verifying_key().scalar();
+
+        let signer = RemoteSigner::new(url, public_key, None, Duration::from_secs(10));
+
+        let messages = [Felt::ONE, Felt::TWO];
+
+        let signatures = signer.sign_batch(&messages).await.unwrap();
+
+        assert_eq!(signatures.len(), messages.len());
+
+        for (message, (r, s)) in messages.iter().zip(signatures) {
+            assert!(starknet_crypto::verify(&public_key, message, &r, &s).unwrap());
+        }
+
+        assert!(signer.sign_batch(&[]).await.unwrap().is_empty());
+
+        assert!(signer.check_health().await.is_ok());
+
+        // a batch with a signature from another key is rejected
+        assert!(
+            signer
+                .sign_batch(&[Felt::ONE, Felt::TWO, Felt::THREE])
+                .await
+                .is_err()
+        );
+
+        shutdown.notify();
+    }
+
+    #[rocket::async_test]
+    async fn test_remote_signer_times_out() {
+        // a listener that accepts the connection but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/sign", listener.local_addr().unwrap());
+
+        let signer = RemoteSigner::new(url, Felt::ONE, None, Duration::from_millis(200));
+
+        let error = signer.sign(&Felt::ONE).await.unwrap_err();
+
+        assert!(error.starts_with("remote signer error"), "{error}");
+
+        assert!(signer.check_health().await.is_err());
+
+        drop(listener);
+    }
+}
@@ -0,0 +1,177 @@
+use starknet_crypto::Felt;
+
+use crate::{
+    Attestation, AttestationDomain, AttestationError, AttestationRequest, AttestatorClientError,
+    AttestatorKeys, Ed25519,
+};
+
+/// Non-blocking counterpart of `AttestatorClient`,
+/// for use from async contexts such as the relayer.
+#[derive(Clone, Debug)]
+pub struct AsyncAttestatorClient<'a> {
+    pub client: &'a reqwest::Client,
+    pub address: &'a str,
+    pub auth_token: Option<&'a str>,
+}
+
+impl<'a> AsyncAttestatorClient<'a> {
+    pub fn new(client: &'a reqwest::Client, address: &'a str) -> Self {
+        Self {
+            client,
+            address,
+            auth_token: None,
+        }
+    }
+
+    /// Sends `auth_token` as bearer token with the attestation requests.
+    pub fn with_auth_token(self, auth_token: Option<&'a str>) -> Self {
+        Self { auth_token, ..self }
+    }
+
+    /// Requests the attestation of the challenges, bound to `domain` if any,
+    /// or as legacy attestations otherwise.
+    pub async fn get_attestation(
+        &self,
+        domain: Option<&AttestationDomain>,
+        challenges: &[Ed25519],
+    ) -> Result<Attestation, AttestatorClientError<reqwest::Error>> {
+        let mut request = self
+            .client
+            .post(format!("{}/attest", self.address))
+            .json(&AttestationRequest::new(domain, challenges));
+
+        if let Some(auth_token) = self.auth_token {
+            request = request.bearer_auth(auth_token);
+        }
+
+        let response = request.send().await?;
+
+        if let Err(e) = response.error_for_status_ref() {
+            return Err(match response.json::<AttestationError>().await {
+                Ok(error) => AttestatorClientError::Attestation(error),
+                Err(_) => AttestatorClientError::Transport(e),
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Same as `get_attestation`, except that when the node rejects some of the
+    /// Ed25519 signatures, the attestation of the other challenges is requested
+    /// again. The challenges with a rejected signature are not attested.
+    pub async fn get_valid_attestation(
+        &self,
+        domain: Option<&AttestationDomain>,
+        challenges: &[Ed25519],
+    ) -> Result<(Felt, Vec<Option<(Felt, Felt)>>), AttestatorClientError<reqwest::Error>> {
+        let indices = match self.get_attestation(domain, challenges).await {
+            Ok((public_key, attestations)) => {
+                return Ok((public_key, attestations.into_iter().map(Some).collect()));
+            }
+            Err(AttestatorClientError::Attestation(AttestationError::InvalidSignatures {
+                indices,
+            })) if indices.len() < challenges.len() => indices,
+            Err(e) => return Err(e),
+        };
+
+        let (valid_indices, valid_challenges): (Vec<_>, Vec<_>) = challenges
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(index, _)| !indices.contains(index))
+            .unzip();
+
+        let (public_key, attestations) = self.get_attestation(domain, &valid_challenges).await?;
+
+        let mut valid_attestations = vec![None; challenges.len()];
+
+        for (index, attestation) in valid_indices.into_iter().zip(attestations) {
+            valid_attestations[index] = Some(attestation);
+        }
+
+        Ok((public_key, valid_attestations))
+    }
+
+    /// Current public key of the attestator, and the next one if any.
+    pub async fn get_keys(&self) -> Result<AttestatorKeys, reqwest::Error> {
+        self.client
+            .get(format!("{}/keys", self.address))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+
+    /// Current public key of the attestator.
+    pub async fn get_public_key(&self) -> Result<Felt, reqwest::Error> {
+        self.client
+            .get(format!("{}/public_key", self.address))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_crypto::{Felt, get_public_key, verify};
+
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "manual testing"]
+    async fn test_get_attestation_batch() {
+        let addr = "http://localhost:1234";
+
+        // tests 1 and 3 from https://datatracker.ietf.org/doc/html/rfc8032
+        let challenges: Vec<Ed25519> = serde_json::from_value(serde_json::json!([
+            {
+                "message": "",
+                "signature": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+                "public_key": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            },
+            {
+                "message": "af82",
+                "signature": "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+                "public_key": "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            },
+        ]))
+        .unwrap();
+
+        let domain = AttestationDomain {
+            counterparty_chain_id: "cosmoshub-4".to_string(),
+            client_id: "07-tendermint-0".to_string(),
+            key_epoch: 0,
+            expiry_height: 100,
+        };
+
+        let client = reqwest::Client::new();
+
+        let (attestator_key, signatures) = AsyncAttestatorClient::new(&client, addr)
+            .get_attestation(Some(&domain), &challenges)
+            .await
+            .unwrap();
+
+        let private_key = Felt::from_hex("0x1234").unwrap();
+        let public_key = get_public_key(&private_key);
+
+        assert_eq!(attestator_key, public_key);
+        assert_eq!(signatures.len(), challenges.len());
+
+        for (challenge, (r, s)) in challenges.iter().zip(signatures) {
+            assert!(
+                verify(
+                    &public_key,
+                    &challenge.domain_signed_message(&domain),
+                    &r,
+                    &s
+                )
+                .unwrap(),
+                "Signature verification failed"
+            );
+        }
+    }
+}
@@ -6,6 +6,80 @@ use starknet_core::crypto::ecdsa_sign;
 use starknet_core::types::Felt;
 use starknet_crypto::poseidon_hash_many;
 
+/// Short string `ibc-attestation`, prefixing the payload of versioned attestations.
+pub const ATTESTATION_DOMAIN: Felt = Felt::from_hex_unchecked("0x6962632d6174746573746174696f6e");
+
+pub const ATTESTATION_VERSION: Felt = Felt::ONE;
+
+/// Public key of the attestator, and the Stark ECDSA signature `(r, s)` of each challenge.
+pub type Attestation = (Felt, Vec<(Felt, Felt)>);
+
+/// Context that a versioned attestation is bound to, so that it is only accepted by
+/// the Starknet client `client_id` of the Cosmos chain `counterparty_chain_id`, while
+/// the client trusts the attestator keys of `key_epoch`, until the Starknet block
+/// `expiry_height`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttestationDomain {
+    pub counterparty_chain_id: String,
+    pub client_id: String,
+    pub key_epoch: u64,
+    pub expiry_height: u64,
+}
+
+impl AttestationDomain {
+    pub fn cairo_serialize(&self) -> Vec<Felt> {
+        let mut serialized = vec![ATTESTATION_DOMAIN, ATTESTATION_VERSION];
+
+        for field in [&self.counterparty_chain_id, &self.client_id] {
+            serialized.push(Felt::from(field.len()));
+            serialized.extend(field.bytes().map(Felt::from));
+        }
+
+        serialized.push(Felt::from(self.key_epoch));
+        serialized.push(Felt::from(self.expiry_height));
+
+        serialized
+    }
+}
+
+/// Body of the `/attest` endpoint. Requests without a domain are legacy attestations,
+/// which are bound to nothing but the Ed25519 signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AttestationRequest {
+    Domain {
+        domain: AttestationDomain,
+        challenges: Vec<Ed25519>,
+    },
+    Legacy(Vec<Ed25519>),
+}
+
+impl AttestationRequest {
+    pub fn new(domain: Option<&AttestationDomain>, challenges: &[Ed25519]) -> Self {
+        match domain {
+            Some(domain) => Self::Domain {
+                domain: domain.clone(),
+                challenges: challenges.to_vec(),
+            },
+            None => Self::Legacy(challenges.to_vec()),
+        }
+    }
+
+    pub fn domain(&self) -> Option<&AttestationDomain> {
+        match self {
+            Self::Domain { domain, .. } => Some(domain),
+            Self::Legacy(_) => None,
+        }
+    }
+
+    pub fn challenges(&self) -> &[Ed25519] {
+        match self {
+            Self::Domain { challenges, .. } => challenges,
+            Self::Legacy(challenges) => challenges,
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ed25519 {
@@ -35,15 +109,167 @@ impl Ed25519 {
         serialized
     }
 
+    /// Message signed by legacy attestations.
     pub fn signed_message(&self) -> Felt {
         poseidon_hash_many(&self.cairo_serialize())
     }
 
-    pub fn attest(&self, private_key: &Felt) -> Option<(Felt, Felt)> {
+    /// Message signed by versioned attestations, with the domain serialized
+    /// in front of the challenge.
+    pub fn domain_signed_message(&self, domain: &AttestationDomain) -> Felt {
+        let mut serialized = domain.cairo_serialize();
+        serialized.extend(self.cairo_serialize());
+
+        poseidon_hash_many(&serialized)
+    }
+
+    /// Message signed to attest the challenge, bound to `domain` if any.
+    /// The Ed25519 signature is not verified.
+    pub fn attestation_message(&self, domain: Option<&AttestationDomain>) -> Felt {
+        match domain {
+            Some(domain) => self.domain_signed_message(domain),
+            None => self.signed_message(),
+        }
+    }
+
+    pub fn attest(
+        &self,
+        private_key: &Felt,
+        domain: Option<&AttestationDomain>,
+    ) -> Option<(Felt, Felt)> {
         self.verify()?;
 
-        ecdsa_sign(private_key, &self.signed_message())
+        let message = self.attestation_message(domain);
+
+        ecdsa_sign(private_key, &message)
             .map(|signature| (signature.r, signature.s))
             .ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use starknet_crypto::{get_public_key, verify};
+
+    use super::*;
+
+    fn challenge() -> Ed25519 {
+        // test 3 from https://datatracker.ietf.org/doc/html/rfc8032
+        serde_json::from_value(serde_json::json!({
+            "message": "af82",
+            "signature": "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            "public_key": "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        }))
+        .unwrap()
+    }
+
+    fn domain() -> AttestationDomain {
+        AttestationDomain {
+            counterparty_chain_id: "cosmoshub-4".to_string(),
+            client_id: "07-tendermint-0".to_string(),
+            key_epoch: 0,
+            expiry_height: 100,
+        }
+    }
+
+    #[test]
+    fn test_domain_attestation_is_bound_to_domain() {
+        let challenge = challenge();
+        let domain = domain();
+
+        let private_key = Felt::from_hex("0x1234").unwrap();
+        let public_key = get_public_key(&private_key);
+
+        let (r, s) = challenge.attest(&private_key, Some(&domain)).unwrap();
+
+        assert!(
+            verify(
+                &public_key,
+                &challenge.domain_signed_message(&domain),
+                &r,
+                &s
+            )
+            .unwrap()
+        );
+        assert!(!verify(&public_key, &challenge.signed_message(), &r, &s).unwrap());
+
+        let other_domain = AttestationDomain {
+            counterparty_chain_id: "osmosis-1".to_string(),
+            ..domain
+        };
+
+        assert!(
+            !verify(
+                &public_key,
+                &challenge.domain_signed_message(&other_domain),
+                &r,
+                &s
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_attestation_request_accepts_legacy_body() {
+        let legacy: AttestationRequest =
+            serde_json::from_value(serde_json::json!([challenge()])).unwrap();
+
+        assert!(legacy.domain().is_none());
+        assert_eq!(legacy.challenges().len(), 1);
+
+        let request: AttestationRequest = serde_json::from_value(serde_json::json!({
+            "domain": domain(),
+            "challenges": [challenge()],
+        }))
+        .unwrap();
+
+        assert_eq!(request.domain(), Some(&domain()));
+        assert_eq!(request.challenges().len(), 1);
+    }
+
+    /// Vectors shared with `cairo-libs/packages/cometbft/tests/attestation.cairo`, which
+    /// checks that the Comet client verifies the same messages and signatures.
+    #[test]
+    fn test_attestation_message_vectors() {
+        let challenge = challenge();
+        let domain = domain();
+
+        let private_key = Felt::from_hex("0x1234").unwrap();
+
+        assert_eq!(
+            get_public_key(&private_key),
+            Felt::from_hex("0x26da8d11938b76025862be14fdb8b28438827f73e75e86f7bfa38b196951fa7")
+                .unwrap()
+        );
+
+        assert_eq!(
+            challenge.signed_message(),
+            Felt::from_hex("0x2c9b664053d8438c486fc3c116a973463fcc7284fb0e1b51beea2646465976a")
+                .unwrap()
+        );
+        assert_eq!(
+            challenge.domain_signed_message(&domain),
+            Felt::from_hex("0x6d996ed23dd45f953b8b84816be0927e2c79262f844e0835e342c2758c5b5a2")
+                .unwrap()
+        );
+
+        assert_eq!(
+            challenge.attest(&private_key, None).unwrap(),
+            (
+                Felt::from_hex("0x37063480d38eccdc3f7e606a3afdaa56c7ea9a66199650189968f3d3634f82a")
+                    .unwrap(),
+                Felt::from_hex("0x66d8f1eabf0b29a1b80cb00f8f48bd4c5111c096fd42a239fe3fb87e7a597de")
+                    .unwrap(),
+            )
+        );
+        assert_eq!(
+            challenge.attest(&private_key, Some(&domain)).unwrap(),
+            (
+                Felt::from_hex("0x69fc1f8eb9dd38188a023967a132861f24c9512aa6c0d588d5aaca948bfffae")
+                    .unwrap(),
+                Felt::from_hex("0x2224382dc5979808de454ed2a041e87572fb147ae37181ddefc0749cc9bb9dd")
+                    .unwrap(),
+            )
+        );
+    }
+}
@@ -1,25 +1,54 @@
 use starknet_crypto::Felt;
 
-use crate::Ed25519;
+use crate::{
+    Attestation, AttestationDomain, AttestationError, AttestationRequest, AttestatorClientError,
+    AttestatorKeys, Ed25519,
+};
 
 pub struct AttestatorClient<'a>(pub &'a str);
 
 impl AttestatorClient<'_> {
+    /// Requests the attestation of the challenges, bound to `domain` if any,
+    /// or as legacy attestations otherwise.
     pub fn get_attestation(
         &self,
+        domain: Option<&AttestationDomain>,
         challenges: &[Ed25519],
-    ) -> Result<(Felt, Vec<(Felt, Felt)>), ureq::Error> {
-        Ok(ureq::post(&format!("{}/attest", self.0))
-            .send_json(challenges)?
+    ) -> Result<Attestation, AttestatorClientError<ureq::Error>> {
+        let mut response = ureq::post(&format!("{}/attest", self.0))
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .send_json(AttestationRequest::new(domain, challenges))?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(match response.body_mut().read_json::<AttestationError>() {
+                Ok(error) => AttestatorClientError::Attestation(error),
+                Err(_) => {
+                    AttestatorClientError::Transport(ureq::Error::StatusCode(status.as_u16()))
+                }
+            });
+        }
+
+        Ok(response.body_mut().read_json()?)
+    }
+
+    /// Current public key of the attestator, and the next one if any.
+    pub fn get_keys(&self) -> Result<AttestatorKeys, ureq::Error> {
+        ureq::get(&format!("{}/keys", self.0))
+            .call()?
             .body_mut()
-            .read_json()?)
+            .read_json()
     }
 
+    /// Current public key of the attestator.
     pub fn get_public_key(&self) -> Result<Felt, ureq::Error> {
-        Ok(ureq::get(&format!("{}/public_key", self.0))
+        ureq::get(&format!("{}/public_key", self.0))
             .call()?
             .body_mut()
-            .read_json()?)
+            .read_json()
     }
 }
 
@@ -44,7 +73,7 @@ mod tests {
         let client = AttestatorClient(addr);
 
         let [(r, s)] = client
-            .get_attestation(std::slice::from_ref(&challenge))
+            .get_attestation(None, std::slice::from_ref(&challenge))
             .unwrap()
             .1
             .try_into()
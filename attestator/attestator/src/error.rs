@@ -0,0 +1,86 @@
+use core::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// JSON body of the error responses of the attestator node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub enum AttestationError {
+    /// The Ed25519 signatures of the challenges at these indices are invalid.
+    /// None of the challenges of the batch are attested.
+    InvalidSignatures {
+        indices: Vec<usize>,
+    },
+    BatchTooLarge {
+        max_batch_size: usize,
+    },
+    BodyTooLarge,
+    /// The domain is bound to another key epoch than the current one of the attestator.
+    KeyEpochMismatch {
+        key_epoch: u64,
+    },
+    Unauthorized,
+    RateLimited,
+    InvalidRequest {
+        message: String,
+    },
+    SignerUnavailable {
+        message: String,
+    },
+    /// The attestations were signed but could not be recorded in the audit log,
+    /// so they are not returned.
+    AuditLogUnavailable {
+        message: String,
+    },
+}
+
+impl Display for AttestationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSignatures { indices } => {
+                write!(f, "invalid Ed25519 signatures at indices {indices:?}")
+            }
+            Self::BatchTooLarge { max_batch_size } => {
+                write!(f, "batch larger than {max_batch_size} challenges")
+            }
+            Self::BodyTooLarge => write!(f, "request body too large"),
+            Self::KeyEpochMismatch { key_epoch } => {
+                write!(
+                    f,
+                    "attestation domain is not bound to key epoch {key_epoch}"
+                )
+            }
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::RateLimited => write!(f, "rate limited"),
+            Self::InvalidRequest { message } => write!(f, "invalid request: {message}"),
+            Self::SignerUnavailable { message } => write!(f, "signer unavailable: {message}"),
+            Self::AuditLogUnavailable { message } => write!(f, "audit log unavailable: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for AttestationError {}
+
+/// Error of the attestator clients, either from the transport or returned by the node.
+#[derive(Debug)]
+pub enum AttestatorClientError<E> {
+    Transport(E),
+    Attestation(AttestationError),
+}
+
+impl<E: Display> Display for AttestatorClientError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "{e}"),
+            Self::Attestation(e) => write!(f, "attestator error: {e}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + Display> std::error::Error for AttestatorClientError<E> {}
+
+impl<E> From<E> for AttestatorClientError<E> {
+    fn from(e: E) -> Self {
+        Self::Transport(e)
+    }
+}
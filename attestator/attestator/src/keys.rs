@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use starknet_crypto::Felt;
+
+/// Keys published by an attestator on `/keys`. During a planned key rotation, the
+/// next public key is published ahead of time, so that it can be added to the trusted
+/// attestator keys of the Comet clients before the attestator switches to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttestatorKeys {
+    pub public_key: Felt,
+    pub next_public_key: Option<Felt>,
+}
+
+impl AttestatorKeys {
+    pub fn keys(&self) -> impl Iterator<Item = Felt> {
+        core::iter::once(self.public_key).chain(self.next_public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attestator_keys() {
+        let public_key =
+            Felt::from_hex("0x26da8d11938b76025862be14fdb8b28438827f73e75e86f7bfa38b196951fa7")
+                .unwrap();
+
+        let keys = AttestatorKeys {
+            public_key,
+            next_public_key: Some(Felt::ONE),
+        };
+
+        let decoded: AttestatorKeys =
+            serde_json::from_value(serde_json::to_value(&keys).unwrap()).unwrap();
+
+        assert_eq!(decoded, keys);
+        assert_eq!(
+            decoded.keys().collect::<Vec<_>>(),
+            vec![public_key, Felt::ONE]
+        );
+    }
+}
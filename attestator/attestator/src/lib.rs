@@ -1,7 +1,18 @@
 mod challenge;
 pub use challenge::*;
 
+mod error;
+pub use error::*;
+
+mod keys;
+pub use keys::*;
+
 #[cfg(feature = "client")]
 mod client;
 #[cfg(feature = "client")]
 pub use client::*;
+
+#[cfg(feature = "async-client")]
+mod async_client;
+#[cfg(feature = "async-client")]
+pub use async_client::*;
@@ -1,3 +1,4 @@
+use cometbft::ed25519::AttestationMode;
 use cometbft::light_client::ClientState as ProtoCometClientState;
 use cometbft::utils::Fraction;
 use core::num::traits::Zero;
@@ -23,6 +24,8 @@ pub struct CometClientState {
     pub upgrade_path: Array<ByteArray>,
     pub attestator_keys: Array<felt252>,
     pub attestator_quorum_percentage: usize,
+    pub attestation_mode: AttestationMode,
+    pub attestator_key_epoch: u64,
 }
 
 #[generate_trait]
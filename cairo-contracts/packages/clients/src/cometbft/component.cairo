@@ -867,6 +867,8 @@ pub mod CometClientComponent {
 
             let client_state = self.read_client_state(client_sequence);
 
+            let hints_context = self._attestator_hints_context(client_sequence, @client_state);
+
             let trusted_consensus_state = self
                 .read_consensus_state(client_sequence, ibc_trusted_height);
 
@@ -891,12 +893,6 @@ pub mod CometClientComponent {
 
             let options = Options { trust_threshold, trusting_period, clock_drift };
 
-            let mut hints_context = array![];
-            Serde::serialize(
-                @(client_state.attestator_quorum_percentage, client_state.attestator_keys),
-                ref hints_context,
-            );
-
             ICometLibraryDispatcher { class_hash: read_raw_key::<'comet-library'>() }
                 .verify_update_header(
                     untrusted_block_state,
@@ -909,6 +905,31 @@ pub mod CometClientComponent {
         }
 
 
+        /// Serializes the context of the attestator signature hints: the attestator
+        /// quorum, keys, accepted attestation mode and key epoch, followed by the domain
+        /// that versioned attestations are bound to, i.e. the counterparty chain ID, the
+        /// client ID and the current block number, which must not be past the expiry
+        /// height of the attestations.
+        fn _attestator_hints_context(
+            self: @ComponentState<TContractState>,
+            client_sequence: u64,
+            client_state: @CometClientState,
+        ) -> Array<felt252> {
+            let client_id = ClientIdImpl::new(self.client_type(), client_sequence);
+
+            let mut hints_context = array![];
+
+            client_state.attestator_quorum_percentage.serialize(ref hints_context);
+            client_state.attestator_keys.serialize(ref hints_context);
+            client_state.attestation_mode.serialize(ref hints_context);
+            client_state.attestator_key_epoch.serialize(ref hints_context);
+            client_state.chain_id.serialize(ref hints_context);
+            ClientIdImpl::to_byte_array(@client_id).serialize(ref hints_context);
+            get_block_number().serialize(ref hints_context);
+
+            hints_context
+        }
+
         fn _verify_misbehaviour_header(
             self: @ComponentState<TContractState>,
             client_sequence: u64,
@@ -923,6 +944,8 @@ pub mod CometClientComponent {
 
             let client_state = self.read_client_state(client_sequence);
 
+            let hints_context = self._attestator_hints_context(client_sequence, @client_state);
+
             let trusted_consensus_state = self
                 .read_consensus_state(client_sequence, ibc_trusted_height);
 
@@ -947,12 +970,6 @@ pub mod CometClientComponent {
 
             let options = Options { trust_threshold, trusting_period, clock_drift };
 
-            let mut hints_context = array![];
-            Serde::serialize(
-                @(client_state.attestator_quorum_percentage, client_state.attestator_keys),
-                ref hints_context,
-            );
-
             ICometLibraryDispatcher { class_hash: read_raw_key::<'comet-library'>() }
                 .verify_misbehaviour_header(
                     untrusted_block_state,
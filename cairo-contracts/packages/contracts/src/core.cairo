@@ -47,6 +47,9 @@ pub mod IBCCore {
     impl CoreScheduleUpgradeImpl =
         ClientHandlerComponent::CoreScheduleUpgrade<ContractState>;
     #[abi(embed_v0)]
+    impl CoreSequencerKeyRegistryImpl =
+        ClientHandlerComponent::CoreSequencerKeyRegistry<ContractState>;
+    #[abi(embed_v0)]
     impl CoreRegisterClientImpl =
         ClientHandlerComponent::CoreRegisterClient<ContractState>;
     #[abi(embed_v0)]
@@ -1,5 +1,6 @@
 use openzeppelin_testing::spy_events;
 use snforge_std::{start_cheat_block_number_global, start_cheat_block_timestamp_global};
+use starknet_ibc_core::client::ClientHandlerComponent::SEQUENCER_KEY_ROTATION_DELAY;
 use starknet_ibc_core::client::{
     ClientContractTrait, StatusTrait, TimestampTrait, U64IntoTimestamp, UpdateResponse,
 };
@@ -528,3 +529,31 @@ fn test_prune_after_client_recover() {
     // Should panic as the first consensus state has been pruned
     comet.consensus_state_root(0, third_updating_height);
 }
+
+#[test]
+fn test_sequencer_key_rotation_ok() {
+    let (mut core, _) = SetupImpl::setup_core_with_client("IBCCore", "MockClient");
+
+    core.propose_sequencer_public_key(0x1234);
+
+    // The proposed key is not proven to the counterparty until it is activated.
+    assert_eq!(core.sequencer_public_key(), 0);
+
+    start_cheat_block_timestamp_global(20 + SEQUENCER_KEY_ROTATION_DELAY);
+
+    core.activate_sequencer_public_key();
+
+    assert_eq!(core.sequencer_public_key(), 0x1234);
+}
+
+#[test]
+#[should_panic(expected: 'ICS02: sequencer key pending')]
+fn test_sequencer_key_rotation_before_delay() {
+    let (mut core, _) = SetupImpl::setup_core_with_client("IBCCore", "MockClient");
+
+    core.propose_sequencer_public_key(0x1234);
+
+    start_cheat_block_timestamp_global(20 + SEQUENCER_KEY_ROTATION_DELAY - 1);
+
+    core.activate_sequencer_public_key();
+}
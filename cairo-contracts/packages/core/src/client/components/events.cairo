@@ -15,6 +15,9 @@ pub mod ClientEventEmitterComponent {
         RecoverClientEvent: RecoverClientEvent,
         UpgradeClientEvent: UpgradeClientEvent,
         ScheduleUpgradeEvent: ScheduleUpgradeEvent,
+        ProposeSequencerKeyEvent: ProposeSequencerKeyEvent,
+        CancelSequencerKeyEvent: CancelSequencerKeyEvent,
+        ActivateSequencerKeyEvent: ActivateSequencerKeyEvent,
     }
 
     #[derive(Debug, Drop, starknet::Event)]
@@ -51,6 +54,25 @@ pub mod ClientEventEmitterComponent {
         pub upgraded_height: Height,
     }
 
+    #[derive(Debug, Drop, starknet::Event)]
+    pub struct ProposeSequencerKeyEvent {
+        #[key]
+        pub sequencer_public_key: felt252,
+        pub activation_timestamp: u64,
+    }
+
+    #[derive(Debug, Drop, starknet::Event)]
+    pub struct CancelSequencerKeyEvent {
+        #[key]
+        pub sequencer_public_key: felt252,
+    }
+
+    #[derive(Debug, Drop, starknet::Event)]
+    pub struct ActivateSequencerKeyEvent {
+        #[key]
+        pub sequencer_public_key: felt252,
+    }
+
     #[generate_trait]
     pub impl ClientEventEmitterImpl<
         TContractState, +HasComponent<TContractState>, +Drop<TContractState>,
@@ -97,6 +119,26 @@ pub mod ClientEventEmitterComponent {
         ) {
             self.emit(ScheduleUpgradeEvent { upgraded_height });
         }
+
+        fn emit_propose_sequencer_key_event(
+            ref self: ComponentState<TContractState>,
+            sequencer_public_key: felt252,
+            activation_timestamp: u64,
+        ) {
+            self.emit(ProposeSequencerKeyEvent { sequencer_public_key, activation_timestamp });
+        }
+
+        fn emit_cancel_sequencer_key_event(
+            ref self: ComponentState<TContractState>, sequencer_public_key: felt252,
+        ) {
+            self.emit(CancelSequencerKeyEvent { sequencer_public_key });
+        }
+
+        fn emit_activate_sequencer_key_event(
+            ref self: ComponentState<TContractState>, sequencer_public_key: felt252,
+        ) {
+            self.emit(ActivateSequencerKeyEvent { sequencer_public_key });
+        }
     }
 }
 
@@ -7,10 +7,10 @@ pub mod ClientHandlerComponent {
         IntoIterRange, Map, MutableVecTrait, StorageMapReadAccess, StorageMapWriteAccess,
         StoragePointerReadAccess, StoragePointerWriteAccess, Vec,
     };
-    use starknet::{ContractAddress, get_tx_info};
+    use starknet::{ContractAddress, get_block_timestamp, get_tx_info};
     use starknet_ibc_core::client::ClientEventEmitterComponent::ClientEventEmitterTrait;
     use starknet_ibc_core::client::interface::{
-        IClientHandler, IRegisterClient, IRegisterRelayer, IScheduleUpgrade,
+        IClientHandler, IRegisterClient, IRegisterRelayer, IScheduleUpgrade, ISequencerKeyRegistry,
     };
     use starknet_ibc_core::client::{
         ClientContract, ClientContractHandlerTrait, ClientErrors, ClientEventEmitterComponent,
@@ -33,8 +33,17 @@ pub mod ClientHandlerComponent {
         // note: this is a map on height to make sure final height is part of the path/key
         pub(crate) upgraded_client_state_commitments: Map<u64, felt252>,
         pub(crate) upgraded_consensus_state_commitments: Map<u64, felt252>,
+        // the sequencer public key proven to the counterparty light clients on a
+        // sequencer key rotation, only activated once the rotation delay has elapsed
+        pub(crate) sequencer_public_key: felt252,
+        // the sequencer public key proposed by the admin, with its activation timestamp
+        pending_sequencer_public_key: (felt252, u64),
     }
 
+    /// Delay between the proposal of a new sequencer public key by the admin and its
+    /// activation, leaving time to the counterparty chains to react, i.e. seven days.
+    pub const SEQUENCER_KEY_ROTATION_DELAY: u64 = 604800;
+
     #[event]
     #[derive(Debug, Drop, starknet::Event)]
     pub enum Event {}
@@ -183,6 +192,84 @@ pub mod ClientHandlerComponent {
         }
     }
 
+    // -----------------------------------------------------------
+    // Sequencer Key Registry
+    // -----------------------------------------------------------
+
+    #[embeddable_as(CoreSequencerKeyRegistry)]
+    pub impl CoreSequencerKeyRegistryImpl<
+        TContractState,
+        +HasComponent<TContractState>,
+        +Drop<TContractState>,
+        impl EventEmitter: ClientEventEmitterComponent::HasComponent<TContractState>,
+        impl Ownable: OwnableComponent::HasComponent<TContractState>,
+    > of ISequencerKeyRegistry<ComponentState<TContractState>> {
+        fn propose_sequencer_public_key(
+            ref self: ComponentState<TContractState>, sequencer_public_key: felt252,
+        ) {
+            {
+                // only admin can propose a sequencer public key
+                let ownable = get_dep_component!(@self, Ownable);
+                ownable.assert_only_owner();
+            }
+
+            assert(sequencer_public_key.is_non_zero(), ClientErrors::ZERO_SEQUENCER_PUBLIC_KEY);
+
+            let activation_timestamp = get_block_timestamp() + SEQUENCER_KEY_ROTATION_DELAY;
+
+            self.pending_sequencer_public_key.write((sequencer_public_key, activation_timestamp));
+
+            self.emit_propose_sequencer_key_event(sequencer_public_key, activation_timestamp);
+        }
+
+        fn cancel_sequencer_public_key(ref self: ComponentState<TContractState>) {
+            {
+                // only admin can cancel a proposed sequencer public key
+                let ownable = get_dep_component!(@self, Ownable);
+                ownable.assert_only_owner();
+            }
+
+            let (sequencer_public_key, _) = self.pending_sequencer_public_key.read();
+
+            assert(
+                sequencer_public_key.is_non_zero(), ClientErrors::MISSING_SEQUENCER_PUBLIC_KEY,
+            );
+
+            self.pending_sequencer_public_key.write((0, 0));
+
+            self.emit_cancel_sequencer_key_event(sequencer_public_key);
+        }
+
+        fn activate_sequencer_public_key(ref self: ComponentState<TContractState>) {
+            let (sequencer_public_key, activation_timestamp) = self
+                .pending_sequencer_public_key
+                .read();
+
+            assert(
+                sequencer_public_key.is_non_zero(), ClientErrors::MISSING_SEQUENCER_PUBLIC_KEY,
+            );
+            assert(
+                get_block_timestamp() >= activation_timestamp,
+                ClientErrors::PENDING_SEQUENCER_PUBLIC_KEY,
+            );
+
+            self.pending_sequencer_public_key.write((0, 0));
+            self.sequencer_public_key.write(sequencer_public_key);
+
+            self.emit_activate_sequencer_key_event(sequencer_public_key);
+        }
+
+        fn get_sequencer_public_key(self: @ComponentState<TContractState>) -> felt252 {
+            self.sequencer_public_key.read()
+        }
+
+        fn get_pending_sequencer_public_key(
+            self: @ComponentState<TContractState>,
+        ) -> (felt252, u64) {
+            self.pending_sequencer_public_key.read()
+        }
+    }
+
     // -----------------------------------------------------------
     // IRegisterClient
     // -----------------------------------------------------------
@@ -354,5 +441,32 @@ pub mod ClientHandlerComponent {
 
             event_emitter.emit_schedule_upgrade_event(upgraded_height);
         }
+
+        fn emit_propose_sequencer_key_event(
+            ref self: ComponentState<TContractState>,
+            sequencer_public_key: felt252,
+            activation_timestamp: u64,
+        ) {
+            let mut event_emitter = get_dep_component_mut!(ref self, EventEmitter);
+
+            event_emitter
+                .emit_propose_sequencer_key_event(sequencer_public_key, activation_timestamp);
+        }
+
+        fn emit_cancel_sequencer_key_event(
+            ref self: ComponentState<TContractState>, sequencer_public_key: felt252,
+        ) {
+            let mut event_emitter = get_dep_component_mut!(ref self, EventEmitter);
+
+            event_emitter.emit_cancel_sequencer_key_event(sequencer_public_key);
+        }
+
+        fn emit_activate_sequencer_key_event(
+            ref self: ComponentState<TContractState>, sequencer_public_key: felt252,
+        ) {
+            let mut event_emitter = get_dep_component_mut!(ref self, EventEmitter);
+
+            event_emitter.emit_activate_sequencer_key_event(sequencer_public_key);
+        }
     }
 }
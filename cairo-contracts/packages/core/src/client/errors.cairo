@@ -2,6 +2,9 @@ pub mod ClientErrors {
     pub const ZERO_CLIENT_TYPE: felt252 = 'ICS02: client type is 0';
     pub const ZERO_CLIENT_ADDRESS: felt252 = 'ICS02: client address is 0';
     pub const ZERO_RELAYER_ADDRESS: felt252 = 'ICS02: relayer address is 0';
+    pub const ZERO_SEQUENCER_PUBLIC_KEY: felt252 = 'ICS02: sequencer key is 0';
+    pub const MISSING_SEQUENCER_PUBLIC_KEY: felt252 = 'ICS02: no proposed sequencer key';
+    pub const PENDING_SEQUENCER_PUBLIC_KEY: felt252 = 'ICS02: sequencer key pending';
     pub const EMPTY_CLIENT_STATE: felt252 = 'ICS02: empty client state';
     pub const EMPTY_CONSENSUS_STATE: felt252 = 'ICS02: empty consensus state';
     pub const EMPTY_CLIENT_MESSAGE: felt252 = 'ICS02: empty client message';
@@ -27,6 +27,18 @@ pub trait IScheduleUpgrade<TContractState> {
     fn unschedule_upgrade(ref self: TContractState);
 }
 
+/// Registry of the sequencer public key proven to the counterparty light clients
+/// on a sequencer key rotation. A key proposed by the admin can only be activated
+/// after `SEQUENCER_KEY_ROTATION_DELAY`.
+#[starknet::interface]
+pub trait ISequencerKeyRegistry<TContractState> {
+    fn propose_sequencer_public_key(ref self: TContractState, sequencer_public_key: felt252);
+    fn cancel_sequencer_public_key(ref self: TContractState);
+    fn activate_sequencer_public_key(ref self: TContractState);
+    fn get_sequencer_public_key(self: @TContractState) -> felt252;
+    fn get_pending_sequencer_public_key(self: @TContractState) -> (felt252, u64);
+}
+
 #[starknet::interface]
 pub trait IRegisterClient<TContractState> {
     fn register_client(
@@ -107,7 +107,8 @@ pub mod client {
         IClientStateValidationDispatcherTrait, IRegisterClient, IRegisterClientDispatcher,
         IRegisterClientDispatcherTrait, IRegisterRelayer, IRegisterRelayerDispatcher,
         IRegisterRelayerDispatcherTrait, IScheduleUpgrade, IScheduleUpgradeDispatcher,
-        IScheduleUpgradeDispatcherTrait,
+        IScheduleUpgradeDispatcherTrait, ISequencerKeyRegistry, ISequencerKeyRegistryDispatcher,
+        ISequencerKeyRegistryDispatcherTrait,
     };
     pub use msgs::{
         MsgCreateClient, MsgRecoverClient, MsgScheduleUpgrade, MsgUpdateClient, MsgUpgradeClient,
@@ -111,3 +111,18 @@ fn test_schedule_upgrade_keys() {
         0xef005e48e802e8403a09622b8ffd8299020c511293a5ed773b0f5d80ab81b9,
     )
 }
+
+#[test]
+fn test_sequencer_public_key_registry_key() {
+    let state = COMPONENT_STATE();
+
+    assert_eq!(
+        state.sequencer_public_key.__storage_pointer_address__.into(),
+        selector!("sequencer_public_key"),
+    );
+
+    assert_eq!(
+        state.sequencer_public_key.__storage_pointer_address__.into(),
+        0x2d6ac710dba016bbbdda3b20f2bfede7260727c1576801631bf0faa1b3dbeb,
+    );
+}
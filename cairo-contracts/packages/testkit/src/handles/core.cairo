@@ -10,7 +10,8 @@ use starknet_ibc_core::client::{
     CreateResponse, IClientHandlerDispatcher, IClientHandlerDispatcherTrait,
     IRegisterClientDispatcher, IRegisterClientDispatcherTrait, IRegisterRelayerDispatcher,
     IRegisterRelayerDispatcherTrait, IScheduleUpgradeDispatcher, IScheduleUpgradeDispatcherTrait,
-    MsgCreateClient, MsgRecoverClient, MsgScheduleUpgrade, MsgUpdateClient, UpdateResponse,
+    ISequencerKeyRegistryDispatcher, ISequencerKeyRegistryDispatcherTrait, MsgCreateClient,
+    MsgRecoverClient, MsgScheduleUpgrade, MsgUpdateClient, UpdateResponse,
 };
 use starknet_ibc_core::commitment::Commitment;
 use starknet_ibc_core::connection::{
@@ -68,6 +69,10 @@ pub impl CoreHandleImpl of CoreHandle {
         IScheduleUpgradeDispatcher { contract_address: *self.address }
     }
 
+    fn sequencer_key_registry_dispatcher(self: @CoreContract) -> ISequencerKeyRegistryDispatcher {
+        ISequencerKeyRegistryDispatcher { contract_address: *self.address }
+    }
+
     fn register_client_dispatcher(self: @CoreContract) -> IRegisterClientDispatcher {
         IRegisterClientDispatcher { contract_address: *self.address }
     }
@@ -92,6 +97,18 @@ pub impl CoreHandleImpl of CoreHandle {
         self.schedule_upgrade_dispatcher().schedule_upgrade(msg)
     }
 
+    fn propose_sequencer_public_key(self: @CoreContract, sequencer_public_key: felt252) {
+        self.sequencer_key_registry_dispatcher().propose_sequencer_public_key(sequencer_public_key)
+    }
+
+    fn activate_sequencer_public_key(self: @CoreContract) {
+        self.sequencer_key_registry_dispatcher().activate_sequencer_public_key()
+    }
+
+    fn sequencer_public_key(self: @CoreContract) -> felt252 {
+        self.sequencer_key_registry_dispatcher().get_sequencer_public_key()
+    }
+
     fn register_relayer(self: @CoreContract, relayer_address: ContractAddress) {
         self.register_relayer_dispatcher().register_relayer(relayer_address)
     }
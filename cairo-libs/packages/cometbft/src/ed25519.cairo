@@ -68,48 +68,132 @@ pub impl GaragaEd25519VerifierImpl of Ed25519Verifier<GaragaEd25519Verifier> {
     }
 }
 
+/// Short string `ibc-attestation` prefixing the signature hints and the signed payload
+/// of versioned attestations, which are bound to an attestation domain.
+pub const ATTESTATION_DOMAIN: felt252 = 'ibc-attestation';
+
+pub const ATTESTATION_VERSION: felt252 = 1;
+
+/// Attestations accepted by a Comet client.
+#[derive(Copy, Drop, Debug, PartialEq, Serde, starknet::Store)]
+pub enum AttestationMode {
+    /// Accepts legacy attestations, which only cover the Ed25519 signature, as well as
+    /// versioned ones. Clients created before versioned attestations default to it.
+    #[default]
+    Legacy,
+    /// Only accepts versioned attestations bound to the attestation domain.
+    Domain,
+}
+
+/// Domain a versioned attestation is bound to: the Cosmos chain and the Comet client
+/// it was produced for, the epoch of the attestator keys trusted by the client, and the
+/// Starknet block height after which it expires.
+#[derive(Clone, Debug, Drop, PartialEq, Serde)]
+pub struct AttestationDomain {
+    pub chain_id: ByteArray,
+    pub client_id: ByteArray,
+    pub key_epoch: u64,
+    pub expiry_height: u64,
+}
+
+/// Returns the Poseidon hash signed by the attestators for an Ed25519 signature.
+///
+/// Must match `Ed25519::signed_message` and `Ed25519::domain_signed_message` of the
+/// attestator crate, which share the test vectors of `tests/attestation.cairo`.
+pub fn attestation_hash(
+    domain: @Option<AttestationDomain>,
+    mut msg: Span<u8>,
+    mut signature: Span<u8>,
+    mut public_key: Span<u8>,
+) -> felt252 {
+    let mut attestation_msg: Array<felt252> = array![];
+
+    match domain {
+        Some(domain) => {
+            attestation_msg.append(ATTESTATION_DOMAIN);
+            attestation_msg.append(ATTESTATION_VERSION);
+            append_byte_array(ref attestation_msg, domain.chain_id);
+            append_byte_array(ref attestation_msg, domain.client_id);
+            attestation_msg.append((*domain.key_epoch).into());
+            attestation_msg.append((*domain.expiry_height).into());
+        },
+        None => {},
+    }
+
+    attestation_msg.append(msg.len().into());
+
+    while let Some(byte) = msg.pop_front() {
+        attestation_msg.append((*byte).into());
+    }
+
+    while let Some(byte) = signature.pop_front() {
+        attestation_msg.append((*byte).into());
+    }
+
+    while let Some(byte) = public_key.pop_front() {
+        attestation_msg.append((*byte).into());
+    }
+
+    core::poseidon::poseidon_hash_span(attestation_msg.span())
+}
+
 pub struct AttestatorEd25519Verifier {}
 
 pub impl AttestatorEd25519VerifierImpl of Ed25519Verifier<AttestatorEd25519Verifier> {
     fn assert_signature(
-        mut msg: Span<u8>,
-        mut signature: Span<u8>,
-        mut public_key: Span<u8>,
+        msg: Span<u8>,
+        signature: Span<u8>,
+        public_key: Span<u8>,
         mut hints_context: Span<felt252>,
         mut hints: Span<felt252>,
     ) {
         assert(signature.len() == 64, CometErrors::INVALID_SIGNATURE_LENGTH);
         assert(public_key.len() == 32, CometErrors::INVALID_PUBKEY_LENGTH);
 
-        let (attestator_quorum_percentage, mut attestator_keys): (usize, Array<felt252>) =
+        let (
+            attestator_quorum_percentage, attestator_keys, attestation_mode, attestator_key_epoch,
+        ): (usize, Array<felt252>, AttestationMode, u64) =
             Serde::deserialize(
             ref hints_context,
         )
             .unwrap();
 
-        // Array((pub_key, r, s))
-        let mut attestator_signatures: Array<(felt252, felt252, felt252)> = Serde::deserialize(
-            ref hints,
-        )
-            .unwrap();
+        // Versioned attestations are prefixed with the attestation domain. Hints without
+        // the prefix are legacy attestations, which only cover the Ed25519 signature.
+        let domain = if !hints.is_empty() && *hints[0] == ATTESTATION_DOMAIN {
+            hints.pop_front().unwrap();
 
-        let mut attestation_msg: Array<felt252> = array![msg.len().into()];
+            let (version, key_epoch, expiry_height): (felt252, u64, u64) = Serde::deserialize(
+                ref hints,
+            )
+                .unwrap();
 
-        while let Some(byte) = msg.pop_front() {
-            attestation_msg.append((*byte).into());
-        }
+            assert(version == ATTESTATION_VERSION, 'unsupported attestation version');
 
-        while let Some(byte) = signature.pop_front() {
-            attestation_msg.append((*byte).into());
-        }
+            assert(key_epoch == attestator_key_epoch, 'attestator key epoch mismatch');
 
-        while let Some(byte) = public_key.pop_front() {
-            attestation_msg.append((*byte).into());
-        }
+            let (chain_id, client_id, block_number): (ByteArray, ByteArray, u64) =
+                Serde::deserialize(
+                ref hints_context,
+            )
+                .unwrap();
+
+            assert(block_number <= expiry_height, 'expired attestation');
 
-        let mut attestation_msg = attestation_msg.span();
+            Some(AttestationDomain { chain_id, client_id, key_epoch, expiry_height })
+        } else {
+            assert(attestation_mode == AttestationMode::Legacy, 'legacy attestation rejected');
 
-        let attestation_hash = core::poseidon::poseidon_hash_span(attestation_msg);
+            None
+        };
+
+        // Array((pub_key, r, s))
+        let mut attestator_signatures: Array<(felt252, felt252, felt252)> = Serde::deserialize(
+            ref hints,
+        )
+            .unwrap();
+
+        let attestation_hash = attestation_hash(@domain, msg, signature, public_key);
 
         let mut signature_dict: Felt252Dict<Nullable<(felt252, felt252)>> = Default::default();
 
@@ -119,13 +203,17 @@ pub impl AttestatorEd25519VerifierImpl of Ed25519Verifier<AttestatorEd25519Verif
 
         let mut attestation_count = 0;
 
-        while let Some(trusted_pub_key) = attestator_keys.pop_front() {
-            let (r, s) = match match_nullable(signature_dict.get(trusted_pub_key)) {
+        // the keys are iterated through a span, so that the quorum below is computed
+        // against all the trusted keys
+        let mut trusted_pub_keys = attestator_keys.span();
+
+        while let Some(trusted_pub_key) = trusted_pub_keys.pop_front() {
+            let (r, s) = match match_nullable(signature_dict.get(*trusted_pub_key)) {
                 FromNullableResult::Null => { continue; },
                 FromNullableResult::NotNull(value) => value.unbox(),
             };
 
-            if core::ecdsa::check_ecdsa_signature(attestation_hash, trusted_pub_key, r, s) {
+            if core::ecdsa::check_ecdsa_signature(attestation_hash, *trusted_pub_key, r, s) {
                 attestation_count += 1;
             }
         }
@@ -137,3 +225,12 @@ pub impl AttestatorEd25519VerifierImpl of Ed25519Verifier<AttestatorEd25519Verif
     }
 }
 
+fn append_byte_array(ref output: Array<felt252>, bytes: @ByteArray) {
+    output.append(bytes.len().into());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        output.append(bytes.at(i).unwrap().into());
+        i += 1;
+    }
+}
@@ -0,0 +1,214 @@
+use cometbft::ed25519::{
+    ATTESTATION_DOMAIN, ATTESTATION_VERSION, AttestationDomain, AttestationMode,
+    AttestatorEd25519Verifier, Ed25519Verifier, attestation_hash,
+};
+
+// NOTE: the vectors are shared with `test_attestation_message_vectors` of the Rust
+// attestator crate, for the Ed25519 test 3 of RFC 8032 attested with the private key
+// 0x1234 for the client `07-tendermint-0` of `cosmoshub-4` in the key epoch 0, expiring
+// at height 100.
+
+const ATTESTATOR_KEY: felt252 = 0x26da8d11938b76025862be14fdb8b28438827f73e75e86f7bfa38b196951fa7;
+
+// trusted attestator key without any attestation in the hints
+const OTHER_ATTESTATOR_KEY: felt252 = 0x1234;
+
+const LEGACY_HASH: felt252 = 0x2c9b664053d8438c486fc3c116a973463fcc7284fb0e1b51beea2646465976a;
+const LEGACY_R: felt252 = 0x37063480d38eccdc3f7e606a3afdaa56c7ea9a66199650189968f3d3634f82a;
+const LEGACY_S: felt252 = 0x66d8f1eabf0b29a1b80cb00f8f48bd4c5111c096fd42a239fe3fb87e7a597de;
+
+const DOMAIN_HASH: felt252 = 0x6d996ed23dd45f953b8b84816be0927e2c79262f844e0835e342c2758c5b5a2;
+const DOMAIN_R: felt252 = 0x69fc1f8eb9dd38188a023967a132861f24c9512aa6c0d588d5aaca948bfffae;
+const DOMAIN_S: felt252 = 0x2224382dc5979808de454ed2a041e87572fb147ae37181ddefc0749cc9bb9dd;
+
+const KEY_EPOCH: u64 = 0;
+
+const EXPIRY_HEIGHT: u64 = 100;
+
+fn public_key() -> Array<u8> {
+    array![
+        0xfc, 0x51, 0xcd, 0x8e, 0x62, 0x18, 0xa1, 0xa3, 0x8d, 0xa4, 0x7e, 0xd0, 0x02, 0x30, 0xf0,
+        0x58, 0x08, 0x16, 0xed, 0x13, 0xba, 0x33, 0x03, 0xac, 0x5d, 0xeb, 0x91, 0x15, 0x48, 0x90,
+        0x80, 0x25,
+    ]
+}
+
+fn msg() -> Array<u8> {
+    array![0xaf, 0x82]
+}
+
+fn signature() -> Array<u8> {
+    array![
+        0x62, 0x91, 0xd6, 0x57, 0xde, 0xec, 0x24, 0x02, 0x48, 0x27, 0xe6, 0x9c, 0x3a, 0xbe, 0x01,
+        0xa3, 0x0c, 0xe5, 0x48, 0xa2, 0x84, 0x74, 0x3a, 0x44, 0x5e, 0x36, 0x80, 0xd7, 0xdb, 0x5a,
+        0xc3, 0xac, 0x18, 0xff, 0x9b, 0x53, 0x8d, 0x16, 0xf2, 0x90, 0xae, 0x67, 0xf7, 0x60, 0x98,
+        0x4d, 0xc6, 0x59, 0x4a, 0x7c, 0x15, 0xe9, 0x71, 0x6e, 0xd2, 0x8d, 0xc0, 0x27, 0xbe, 0xce,
+        0xea, 0x1e, 0xc4, 0x0a,
+    ]
+}
+
+fn domain() -> AttestationDomain {
+    AttestationDomain {
+        chain_id: "cosmoshub-4",
+        client_id: "07-tendermint-0",
+        key_epoch: KEY_EPOCH,
+        expiry_height: EXPIRY_HEIGHT,
+    }
+}
+
+fn hints_context(
+    mode: AttestationMode, client_id: ByteArray, block_number: u64,
+) -> Array<felt252> {
+    quorum_hints_context(100, array![ATTESTATOR_KEY], mode, client_id, block_number)
+}
+
+fn quorum_hints_context(
+    quorum_percentage: usize,
+    attestator_keys: Array<felt252>,
+    mode: AttestationMode,
+    client_id: ByteArray,
+    block_number: u64,
+) -> Array<felt252> {
+    let mut hints_context = array![];
+
+    quorum_percentage.serialize(ref hints_context);
+    attestator_keys.serialize(ref hints_context);
+    mode.serialize(ref hints_context);
+    KEY_EPOCH.serialize(ref hints_context);
+    domain().chain_id.serialize(ref hints_context);
+    client_id.serialize(ref hints_context);
+    block_number.serialize(ref hints_context);
+
+    hints_context
+}
+
+fn legacy_hints() -> Array<felt252> {
+    let mut hints = array![];
+
+    array![(ATTESTATOR_KEY, LEGACY_R, LEGACY_S)].serialize(ref hints);
+
+    hints
+}
+
+fn domain_hints() -> Array<felt252> {
+    epoch_domain_hints(KEY_EPOCH)
+}
+
+fn epoch_domain_hints(key_epoch: u64) -> Array<felt252> {
+    let mut hints = array![ATTESTATION_DOMAIN, ATTESTATION_VERSION];
+
+    key_epoch.serialize(ref hints);
+    EXPIRY_HEIGHT.serialize(ref hints);
+    array![(ATTESTATOR_KEY, DOMAIN_R, DOMAIN_S)].serialize(ref hints);
+
+    hints
+}
+
+fn assert_attestation(hints_context: Array<felt252>, hints: Array<felt252>) {
+    Ed25519Verifier::<
+        AttestatorEd25519Verifier,
+    >::assert_signature(
+        msg().span(), signature().span(), public_key().span(), hints_context.span(), hints.span(),
+    );
+}
+
+#[test]
+fn test_attestation_hash_vectors() {
+    let legacy_hash = attestation_hash(
+        @Option::None, msg().span(), signature().span(), public_key().span(),
+    );
+    assert_eq!(legacy_hash, LEGACY_HASH);
+
+    let domain_hash = attestation_hash(
+        @Option::Some(domain()), msg().span(), signature().span(), public_key().span(),
+    );
+    assert_eq!(domain_hash, DOMAIN_HASH);
+}
+
+#[test]
+fn test_versioned_attestation() {
+    assert_attestation(
+        hints_context(AttestationMode::Domain, "07-tendermint-0", EXPIRY_HEIGHT), domain_hints(),
+    );
+}
+
+#[test]
+fn test_legacy_attestation_in_legacy_mode() {
+    assert_attestation(
+        hints_context(AttestationMode::Legacy, "07-tendermint-0", EXPIRY_HEIGHT), legacy_hints(),
+    );
+}
+
+#[test]
+#[should_panic(expected: 'legacy attestation rejected')]
+fn test_legacy_attestation_in_domain_mode() {
+    assert_attestation(
+        hints_context(AttestationMode::Domain, "07-tendermint-0", EXPIRY_HEIGHT), legacy_hints(),
+    );
+}
+
+#[test]
+#[should_panic(expected: 'expired attestation')]
+fn test_expired_attestation() {
+    assert_attestation(
+        hints_context(AttestationMode::Domain, "07-tendermint-0", EXPIRY_HEIGHT + 1),
+        domain_hints(),
+    );
+}
+
+#[test]
+#[should_panic(expected: 'attestator key epoch mismatch')]
+fn test_attestation_for_other_key_epoch() {
+    assert_attestation(
+        hints_context(AttestationMode::Domain, "07-tendermint-0", EXPIRY_HEIGHT),
+        epoch_domain_hints(KEY_EPOCH + 1),
+    );
+}
+
+#[test]
+#[should_panic(expected: 'not enough ed25519 attestations')]
+fn test_attestation_for_other_client() {
+    assert_attestation(
+        hints_context(AttestationMode::Domain, "07-tendermint-1", EXPIRY_HEIGHT), domain_hints(),
+    );
+}
+
+#[test]
+#[should_panic(expected: 'not enough ed25519 attestations')]
+fn test_attestation_without_quorum() {
+    let mut hints = array![];
+    ArrayTrait::<(felt252, felt252, felt252)>::new().serialize(ref hints);
+
+    assert_attestation(hints_context(AttestationMode::Legacy, "07-tendermint-0", 0), hints);
+}
+
+#[test]
+#[should_panic(expected: 'not enough ed25519 attestations')]
+fn test_attestation_below_quorum_of_several_keys() {
+    // a single attestation out of two trusted keys is only half of the attestators,
+    // which must not reach a quorum of 100%
+    assert_attestation(
+        quorum_hints_context(
+            100,
+            array![ATTESTATOR_KEY, OTHER_ATTESTATOR_KEY],
+            AttestationMode::Domain,
+            "07-tendermint-0",
+            EXPIRY_HEIGHT,
+        ),
+        domain_hints(),
+    );
+}
+
+#[test]
+fn test_attestation_at_quorum_of_several_keys() {
+    assert_attestation(
+        quorum_hints_context(
+            50,
+            array![ATTESTATOR_KEY, OTHER_ATTESTATOR_KEY],
+            AttestationMode::Domain,
+            "07-tendermint-0",
+            EXPIRY_HEIGHT,
+        ),
+        domain_hints(),
+    );
+}
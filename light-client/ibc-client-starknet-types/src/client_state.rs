@@ -15,4 +15,11 @@ pub struct StarknetClientState {
     pub sequencer_public_key: Vec<u8>,
     pub ibc_contract_address: Vec<u8>,
     pub is_frozen: u8,
+    /// Trusting period of the latest consensus state, in seconds. `0` disables expiry.
+    pub trusting_period: u64,
+    /// Maximum allowed drift of a header timestamp ahead of the host time, in seconds.
+    /// `0` disables the check.
+    pub max_clock_drift: u64,
+    /// Maximum number of consensus states kept by the client. `0` keeps all non-expired states.
+    pub max_consensus_states: u64,
 }
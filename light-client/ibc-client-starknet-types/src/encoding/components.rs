@@ -22,8 +22,12 @@ mod preset {
     use crate::encoding::impls::client_state::EncodeStarknetClientState;
     use crate::encoding::impls::consensus_state::EncodeStarknetConsensusState;
     use crate::encoding::impls::header::EncodeStarknetHeader;
+    use crate::encoding::impls::key_rotation::EncodeStarknetSequencerKeyRotation;
     use crate::encoding::impls::misbehaviour::EncodeStarknetMisbehaviour;
     use crate::header::{StarknetHeader, STARKNET_HEADER_TYPE_URL};
+    use crate::key_rotation::{
+        StarknetSequencerKeyRotation, STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL,
+    };
     use crate::misbehaviour::{StarknetMisbehaviour, STARKNET_MISBEHAVIOUR_TYPE_URL};
     use crate::{
         StarknetClientState, StarknetConsensusState, STARKNET_CLIENT_STATE_TYPE_URL,
@@ -74,6 +78,7 @@ mod preset {
                 (ViaProtobuf, StarknetConsensusState),
                 (ViaProtobuf, StarknetHeader),
                 (ViaProtobuf, StarknetMisbehaviour),
+                (ViaProtobuf, StarknetSequencerKeyRotation),
             ]: EncodeProtoWithMutBuffer,
 
             [
@@ -81,6 +86,7 @@ mod preset {
                 (ViaAny, StarknetConsensusState),
                 (ViaAny, StarknetHeader),
                 (ViaAny, StarknetMisbehaviour),
+                (ViaAny, StarknetSequencerKeyRotation),
             ]: EncodeViaAny<ViaProtobuf>,
         }
     }
@@ -105,6 +111,9 @@ mod preset {
 
             (ViaProtobuf, StarknetMisbehaviour):
                 EncodeStarknetMisbehaviour,
+
+            (ViaProtobuf, StarknetSequencerKeyRotation):
+                EncodeStarknetSequencerKeyRotation,
         }
     }
 
@@ -115,6 +124,7 @@ mod preset {
                 (StarknetConsensusState, Any),
                 (StarknetHeader, Any),
                 (StarknetMisbehaviour, Any),
+                (StarknetSequencerKeyRotation, Any),
             ]: EncodeAsAnyProtobuf<ViaProtobuf, UseContext>,
 
             [
@@ -122,6 +132,7 @@ mod preset {
                 (Any, StarknetConsensusState),
                 (Any, StarknetHeader),
                 (Any, StarknetMisbehaviour),
+                (Any, StarknetSequencerKeyRotation),
             ]: DecodeAsAnyProtobuf<ViaProtobuf, UseContext>,
         }
     }
@@ -149,4 +160,10 @@ mod preset {
         StarknetMisbehaviour,
         STARKNET_MISBEHAVIOUR_TYPE_URL,
     );
+
+    impl_type_url!(
+        StarknetLightClientTypeUrlSchemas,
+        StarknetSequencerKeyRotation,
+        STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL,
+    );
 }
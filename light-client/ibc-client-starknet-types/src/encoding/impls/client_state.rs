@@ -43,6 +43,18 @@ delegate_components! {
                     symbol!("is_frozen"),
                     EncodeU64ProtoField<6>,
                 >,
+                EncodeField<
+                    symbol!("trusting_period"),
+                    EncodeU64ProtoField<7>,
+                >,
+                EncodeField<
+                    symbol!("max_clock_drift"),
+                    EncodeU64ProtoField<8>,
+                >,
+                EncodeField<
+                    symbol!("max_consensus_states"),
+                    EncodeU64ProtoField<9>,
+                >,
             ]>,
         MutDecoderComponent: DecodeFrom<
             Self,
@@ -53,13 +65,16 @@ delegate_components! {
                 EncodeByteField<4>,
                 EncodeByteField<5>,
                 EncodeU64ProtoField<6>,
+                EncodeU64ProtoField<7>,
+                EncodeU64ProtoField<8>,
+                EncodeU64ProtoField<9>,
             ]>
         >,
     }
 }
 
 impl Transformer for EncodeStarknetClientState {
-    type From = Product![Height, u64, ChainId, Vec<u8>, Vec<u8>, u8];
+    type From = Product![Height, u64, ChainId, Vec<u8>, Vec<u8>, u8, u64, u64, u64];
 
     type To = StarknetClientState;
 
@@ -71,6 +86,9 @@ impl Transformer for EncodeStarknetClientState {
             sequencer_public_key,
             ibc_contract_address,
             is_frozen,
+            trusting_period,
+            max_clock_drift,
+            max_consensus_states,
         ]: Self::From,
     ) -> Self::To {
         StarknetClientState {
@@ -80,6 +98,9 @@ impl Transformer for EncodeStarknetClientState {
             sequencer_public_key,
             ibc_contract_address,
             is_frozen,
+            trusting_period,
+            max_clock_drift,
+            max_consensus_states,
         }
     }
 }
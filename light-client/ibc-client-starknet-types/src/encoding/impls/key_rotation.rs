@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+
+use cgp::core::component::UseContext;
+use hermes_encoding_components::impls::{CombineEncoders, DecodeFrom, EncodeField};
+use hermes_encoding_components::traits::{MutDecoderComponent, MutEncoderComponent, Transformer};
+use hermes_prelude::*;
+use hermes_protobuf_encoding_components::impls::{
+    DecodeRequiredProtoField, EncodeByteField, EncodeLengthDelimitedProtoField,
+};
+
+use crate::header::StarknetHeader;
+use crate::key_rotation::StarknetSequencerKeyRotation;
+
+pub struct EncodeStarknetSequencerKeyRotation;
+
+delegate_components! {
+    EncodeStarknetSequencerKeyRotation {
+        MutEncoderComponent:
+            CombineEncoders<Product![
+                EncodeField<
+                    symbol!("header"),
+                    EncodeLengthDelimitedProtoField<1, UseContext>,
+                >,
+                EncodeField<
+                    symbol!("new_sequencer_public_key"),
+                    EncodeByteField<2>,
+                >,
+            ]>,
+        MutDecoderComponent: DecodeFrom<
+            Self,
+            CombineEncoders<Product![
+                DecodeRequiredProtoField<1, UseContext>,
+                EncodeByteField<2>,
+            ]>
+        >,
+    }
+}
+
+impl Transformer for EncodeStarknetSequencerKeyRotation {
+    type From = Product![StarknetHeader, Vec<u8>];
+
+    type To = StarknetSequencerKeyRotation;
+
+    fn transform(product![header, new_sequencer_public_key,]: Self::From) -> Self::To {
+        StarknetSequencerKeyRotation {
+            header,
+            new_sequencer_public_key,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
+pub mod key_rotation;
 pub mod misbehaviour;
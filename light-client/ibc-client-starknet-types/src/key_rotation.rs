@@ -0,0 +1,19 @@
+use alloc::vec::Vec;
+
+use hermes_prelude::*;
+
+use crate::header::StarknetHeader;
+
+pub const STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL: &str = "/StarknetSequencerKeyRotation";
+
+/// Client message rotating the sequencer public key trusted by the client.
+///
+/// The `header` must be above the latest height of the client and signed by the currently
+/// trusted sequencer key. The new key is then proven by the storage proof of `header`,
+/// opening the timelocked sequencer key registry of the IBC contract to the new key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, HasField)]
+pub struct StarknetSequencerKeyRotation {
+    pub header: StarknetHeader,
+    pub new_sequencer_public_key: Vec<u8>,
+}
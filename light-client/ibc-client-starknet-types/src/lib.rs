@@ -6,6 +6,7 @@ pub mod client_state;
 pub mod consensus_state;
 pub mod encoding;
 pub mod header;
+pub mod key_rotation;
 pub mod misbehaviour;
 
 pub use client_state::*;
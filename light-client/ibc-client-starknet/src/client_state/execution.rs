@@ -6,8 +6,11 @@ use hermes_cosmos_encoding_components::impls::ConvertIbcAny;
 use hermes_encoding_components::impls::ConvertVia;
 use hermes_encoding_components::traits::Converter;
 use ibc_client_cw::context::CwClientExecution;
-use ibc_client_starknet_types::header::StarknetHeader;
-use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
+use ibc_client_starknet_types::header::{StarknetHeader, STARKNET_HEADER_TYPE_URL};
+use ibc_client_starknet_types::key_rotation::{
+    StarknetSequencerKeyRotation, STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL,
+};
+use ibc_client_starknet_types::misbehaviour::STARKNET_MISBEHAVIOUR_TYPE_URL;
 use ibc_client_starknet_types::{
     StarknetClientState as ClientStateType, StarknetConsensusState as StarknetConsensusStateType,
 };
@@ -19,8 +22,10 @@ use ibc_core::client::types::Height;
 use ibc_core::host::types::identifiers::ClientId;
 use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
 use prost_types::Any as ProstAny;
 
+use super::validation::unknown_client_message;
 use super::ClientState;
 use crate::encoding::context::StarknetLightClientEncoding;
 use crate::ConsensusState as StarknetConsensusState;
@@ -53,55 +58,47 @@ where
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
-        if let Ok(header) = <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
-            &StarknetLightClientEncoding,
-            &header,
-        ) {
-            // Specify the type to which `Any` should be decoded
-            let header: StarknetHeader = header;
-            let current_height = header.height();
-            let final_height = header.final_height;
-
-            let new_consensus_state = StarknetConsensusStateType::from(header);
-
-            let new_client_state = if self.latest_height() < current_height {
-                ClientStateType {
-                    latest_height: current_height,
-                    final_height,
-                    chain_id: self.0.chain_id.clone(),
-                    sequencer_public_key: self.0.sequencer_public_key.clone(),
-                    ibc_contract_address: self.0.ibc_contract_address.clone(),
-                    is_frozen: self.0.is_frozen,
-                }
-                .into()
-            } else {
-                self.clone()
-            };
-
-            update_client_and_consensus_state(
-                ctx,
-                current_height,
-                client_id,
-                new_client_state,
-                new_consensus_state.into(),
-            )?;
-
-            Ok(vec![current_height])
-        } else {
-            let client_state = ctx
-                .client_state(client_id)
-                .expect("Failed to retrieve client state");
-            let misbehaviour: StarknetMisbehaviour =
-                <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
-                    &StarknetLightClientEncoding,
-                    &header,
-                )?;
-            if client_state.check_for_misbehaviour(ctx, client_id, header.clone())? {
-                client_state.update_state_on_misbehaviour(ctx, client_id, header)?;
+        match header.type_url.as_str() {
+            STARKNET_HEADER_TYPE_URL => {
+                let header: StarknetHeader =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &header,
+                    )?;
+
+                let sequencer_public_key = self.0.sequencer_public_key.clone();
+
+                self.update_state_with_header(ctx, client_id, header, sequencer_public_key)
             }
+            STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL => {
+                let rotation: StarknetSequencerKeyRotation =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &header,
+                    )?;
 
-            // TODO: Should we return latest height as well?
-            Ok(vec![])
+                self.update_state_with_header(
+                    ctx,
+                    client_id,
+                    rotation.header,
+                    rotation.new_sequencer_public_key,
+                )
+            }
+            STARKNET_MISBEHAVIOUR_TYPE_URL => {
+                if !self.check_for_misbehaviour(ctx, client_id, header.clone())? {
+                    return Err(ClientError::ClientSpecific {
+                        description:
+                            "Starknet misbehaviour evidence does not contain conflicting headers"
+                                .into(),
+                    });
+                }
+
+                self.update_state_on_misbehaviour(ctx, client_id, header)?;
+
+                // TODO: Should we return latest height as well?
+                Ok(vec![])
+            }
+            type_url => Err(unknown_client_message(type_url)),
         }
     }
 
@@ -111,14 +108,6 @@ where
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
-        let evidence: StarknetMisbehaviour =
-            <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
-                &StarknetLightClientEncoding,
-                &client_message,
-            )?;
-
-        let new_consensus_state = StarknetConsensusStateType::from(evidence.header_1);
-
         let new_client_state = ClientStateType {
             latest_height: self.0.latest_height,
             final_height: self.0.final_height,
@@ -126,16 +115,15 @@ where
             sequencer_public_key: self.0.sequencer_public_key.clone(),
             ibc_contract_address: self.0.ibc_contract_address.clone(),
             is_frozen: 1,
+            trusting_period: self.0.trusting_period,
+            max_clock_drift: self.0.max_clock_drift,
+            max_consensus_states: self.0.max_consensus_states,
         }
         .into();
 
-        update_client_and_consensus_state(
-            ctx,
-            self.latest_height(),
-            client_id,
-            new_client_state,
-            new_consensus_state.into(),
-        )?;
+        // only the client state is frozen; the trusted consensus states are left untouched.
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+
         Ok(())
     }
 
@@ -146,7 +134,17 @@ where
         upgraded_client_state: Any,
         upgraded_consensus_state: Any,
     ) -> Result<Height, ClientError> {
-        let client_state = E::ClientStateRef::try_from(upgraded_client_state)?;
+        let upgraded_client_state = Self::try_from(upgraded_client_state)?;
+
+        // the upgraded client state only carries the chain-specified fields;
+        // the client-chosen parameters are kept from the current client state.
+        let client_state: Self = ClientStateType {
+            trusting_period: self.0.trusting_period,
+            max_clock_drift: self.0.max_clock_drift,
+            max_consensus_states: self.0.max_consensus_states,
+            ..upgraded_client_state.0
+        }
+        .into();
 
         let latest_height = client_state.latest_height();
 
@@ -168,7 +166,19 @@ where
         substitute_client_state: Any,
         substitute_consensus_state: Any,
     ) -> Result<(), ClientError> {
-        let client_state = E::ClientStateRef::try_from(substitute_client_state)?;
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        self.check_substitute_parameters(&substitute_client_state)?;
+
+        // the chain parameters are always kept from the subject client state, except for
+        // the sequencer public key, which the substitute may have verified after a rotation.
+        let client_state: Self = ClientStateType {
+            chain_id: self.0.chain_id.clone(),
+            ibc_contract_address: self.0.ibc_contract_address.clone(),
+            is_frozen: 0,
+            ..substitute_client_state.0
+        }
+        .into();
 
         update_client_and_consensus_state(
             ctx,
@@ -182,6 +192,59 @@ where
     }
 }
 
+impl ClientState {
+    /// Stores the consensus state of a verified header, signed by `sequencer_public_key`,
+    /// which becomes the sequencer key trusted by the client.
+    fn update_state_with_header<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: StarknetHeader,
+        sequencer_public_key: Vec<u8>,
+    ) -> Result<Vec<Height>, ClientError>
+    where
+        E: ExtClientExecutionContext<
+            ClientStateRef = Self,
+            ConsensusStateRef = StarknetConsensusState,
+        >,
+    {
+        let current_height = header.height();
+        let final_height = header.final_height;
+
+        let new_consensus_state = StarknetConsensusStateType::from(header);
+
+        let new_client_state: Self = if self.latest_height() < current_height {
+            ClientStateType {
+                latest_height: current_height,
+                final_height,
+                chain_id: self.0.chain_id.clone(),
+                sequencer_public_key,
+                ibc_contract_address: self.0.ibc_contract_address.clone(),
+                is_frozen: self.0.is_frozen,
+                trusting_period: self.0.trusting_period,
+                max_clock_drift: self.0.max_clock_drift,
+                max_consensus_states: self.0.max_consensus_states,
+            }
+            .into()
+        } else {
+            // the sequencer key only changes together with the height progress of the client.
+            self.clone()
+        };
+
+        update_client_and_consensus_state(
+            ctx,
+            current_height,
+            client_id,
+            new_client_state.clone(),
+            new_consensus_state.into(),
+        )?;
+
+        prune_consensus_states(ctx, client_id, &new_client_state.0)?;
+
+        Ok(vec![current_height])
+    }
+}
+
 fn update_client_and_consensus_state<E: ExtClientExecutionContext>(
     ctx: &mut E,
     client_height: Height,
@@ -207,3 +270,180 @@ fn update_client_and_consensus_state<E: ExtClientExecutionContext>(
 
     Ok(())
 }
+
+/// Deletes the expired consensus states, and the oldest consensus states exceeding
+/// `max_consensus_states`, together with their update metadata.
+///
+/// The consensus state at the latest height of the client is never deleted.
+fn prune_consensus_states<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_state: &ClientStateType,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext<ConsensusStateRef = StarknetConsensusState>,
+{
+    let heights = ctx.consensus_state_heights(client_id)?;
+
+    let host_timestamp = ctx.host_timestamp()?;
+
+    let pruned_heights =
+        consensus_states_to_prune(heights, client_state, &host_timestamp, |height| {
+            let consensus_state = ctx.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))?;
+
+            Ok(consensus_state.0.time)
+        })?;
+
+    for height in pruned_heights {
+        ctx.delete_consensus_state(ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))?;
+        ctx.delete_update_meta(client_id.clone(), height)?;
+    }
+
+    Ok(())
+}
+
+/// Selects the heights of the consensus states to prune, given the timestamp of
+/// each consensus state.
+fn consensus_states_to_prune(
+    mut heights: Vec<Height>,
+    client_state: &ClientStateType,
+    host_timestamp: &Timestamp,
+    mut consensus_state_time: impl FnMut(Height) -> Result<Timestamp, ClientError>,
+) -> Result<Vec<Height>, ClientError> {
+    heights.sort();
+
+    let excess = match client_state.max_consensus_states {
+        0 => 0,
+        max_consensus_states => heights
+            .len()
+            .saturating_sub(usize::try_from(max_consensus_states).unwrap_or(usize::MAX)),
+    };
+
+    let mut pruned_heights = Vec::new();
+
+    for (index, height) in heights.into_iter().enumerate() {
+        if height >= client_state.latest_height {
+            break;
+        }
+
+        // heights are sorted, so the remaining consensus states are newer and still within limits
+        if index >= excess {
+            let expired = client_state.trusting_period != 0
+                && host_timestamp
+                    .duration_since(&consensus_state_time(height)?)
+                    .is_some_and(|elapsed| elapsed.as_secs() >= client_state.trusting_period);
+
+            if !expired {
+                break;
+            }
+        }
+
+        pruned_heights.push(height);
+    }
+
+    Ok(pruned_heights)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+
+    const HOST_TIME: u64 = 1_000_000;
+
+    fn client_state(max_consensus_states: u64, trusting_period: u64) -> ClientStateType {
+        ClientStateType {
+            latest_height: Height::new(0, 10).unwrap(),
+            final_height: 0,
+            chain_id: "SN_SEPOLIA".parse().unwrap(),
+            sequencer_public_key: vec![],
+            ibc_contract_address: vec![],
+            is_frozen: 0,
+            trusting_period,
+            max_clock_drift: 0,
+            max_consensus_states,
+        }
+    }
+
+    /// Consensus states at heights 1 to 10, the one at height `h` being
+    /// `(10 - h) * 100` seconds old.
+    fn consensus_state_times() -> BTreeMap<Height, Timestamp> {
+        (1..=10)
+            .map(|height| {
+                let time = HOST_TIME - (10 - height) * 100;
+
+                (
+                    Height::new(0, height).unwrap(),
+                    Timestamp::from_unix_timestamp(time, 0).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn pruned_heights(client_state: &ClientStateType) -> Vec<u64> {
+        let times = consensus_state_times();
+
+        // the heights are not sorted by the host
+        let heights = times.keys().rev().copied().collect();
+
+        consensus_states_to_prune(
+            heights,
+            client_state,
+            &Timestamp::from_unix_timestamp(HOST_TIME, 0).unwrap(),
+            |height| Ok(times[&height]),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|height| height.revision_height())
+        .collect()
+    }
+
+    #[test]
+    fn test_prune_excess_consensus_states() {
+        assert_eq!(pruned_heights(&client_state(4, 0)), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(pruned_heights(&client_state(10, 0)), Vec::<u64>::new());
+        assert_eq!(pruned_heights(&client_state(0, 0)), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_prune_expired_consensus_states() {
+        // the consensus states at heights 1 to 3 are at least 700 seconds old
+        assert_eq!(pruned_heights(&client_state(0, 700)), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_prune_excess_and_expired_consensus_states() {
+        assert_eq!(pruned_heights(&client_state(8, 500)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            pruned_heights(&client_state(4, 500)),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_prune_keeps_latest_consensus_state() {
+        // even expired, or above the maximum number, the latest consensus state is kept
+        assert_eq!(
+            pruned_heights(&client_state(0, 1)),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+        assert_eq!(
+            pruned_heights(&client_state(1, 0)),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+
+        let mut stale_client_state = client_state(0, 1);
+        stale_client_state.latest_height = Height::new(0, 5).unwrap();
+
+        assert_eq!(pruned_heights(&stale_client_state), vec![1, 2, 3, 4]);
+    }
+}
@@ -10,12 +10,18 @@ use hermes_encoding_components::impls::ConvertVia;
 use hermes_encoding_components::traits::{CanDecode, Converter};
 use hermes_protobuf_encoding_components::types::strategy::ViaProtobuf;
 use ibc_client_cw::context::CwClientValidation;
-use ibc_client_starknet_types::header::StarknetHeader;
-use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
+use ibc_client_starknet_types::header::{StarknetHeader, STARKNET_HEADER_TYPE_URL};
+use ibc_client_starknet_types::key_rotation::{
+    StarknetSequencerKeyRotation, STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL,
+};
+use ibc_client_starknet_types::misbehaviour::{
+    StarknetMisbehaviour, STARKNET_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_client_starknet_types::{StarknetClientState, StarknetConsensusState};
 use ibc_core::channel::types::proto::v1::Channel;
 use ibc_core::client::context::client_state::ClientStateValidation;
 use ibc_core::client::context::prelude::ClientStateCommon;
+use ibc_core::client::context::ExtClientValidationContext;
 use ibc_core::client::types::error::ClientError;
 use ibc_core::client::types::Status;
 use ibc_core::commitment_types::commitment::{
@@ -26,18 +32,21 @@ use ibc_core::connection::types::proto::v1::ConnectionEnd;
 use ibc_core::host::types::error::DecodingError;
 use ibc_core::host::types::identifiers::ClientId;
 use ibc_core::host::types::path::{
-    Path, PathBytes, UpgradeClientStatePath, UpgradeConsensusStatePath,
+    ClientConsensusStatePath, Path, PathBytes, UpgradeClientStatePath, UpgradeConsensusStatePath,
 };
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
 use prost::Message;
 use prost_types::Any as ProstAny;
+use starknet_block_verifier::{Block, Signature};
 use starknet_core::types::{Felt, StorageProof};
 use starknet_crypto_lib::{StarknetCryptoFunctions, StarknetCryptoLib};
 use starknet_storage_verifier::ibc::ibc_path_to_storage_key;
 use starknet_storage_verifier::validate::validate_storage_proof;
 use starknet_storage_verifier::verifier::{
-    verify_starknet_contract_proof, verify_starknet_storage_proof,
+    verify_starknet_contract_proof, verify_starknet_global_contract_root,
+    verify_starknet_storage_proof, verify_starknet_storage_proofs,
 };
 
 use super::ClientState;
@@ -48,7 +57,8 @@ use crate::ConsensusState;
 
 impl<'a, V> ClientStateValidation<V> for ClientState
 where
-    V: CwClientValidation<'a, ClientStateRef = Self, ConsensusStateRef = ConsensusState>,
+    V: CwClientValidation<'a, ClientStateRef = Self, ConsensusStateRef = ConsensusState>
+        + ExtClientValidationContext,
 {
     fn verify_client_message(
         &self,
@@ -56,103 +66,38 @@ where
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
-        let starknet_crypto_cw = StarknetCryptoLib;
-
-        let header: StarknetHeader = if let Ok(decoded_header) =
-            <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
-                &StarknetLightClientEncoding,
-                &client_message,
-            ) {
-            decoded_header
-        } else {
-            // TODO: Correctly handle the case when the message is not a StarknetHeader
-            return Ok(());
-        };
-
-        let StarknetHeader {
-            block_header,
-            final_height,
-            block_signature,
-            storage_proof,
-        } = header;
-
-        // this is to make sure after a schedule upgrade, the client can't be updated after final_height.
-        // this way, any packet after the final_height will be rejected.
-        // only way to resume the client is to remove the scheduled upgrade on starknet after the upgrade is finished.
-        if final_height != 0 && final_height < block_header.block_number {
-            return Err(ClientError::ClientSpecific {
-                description: format!(
-                    "Updating client at height {} after upgrade final height ({final_height}); \
-                    upgrade the Starknet Client or unschedule upgrade at Starknet",
-                    block_header.block_number
-                ),
-            });
-        }
+        match client_message.type_url.as_str() {
+            STARKNET_HEADER_TYPE_URL => {
+                let header: StarknetHeader =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
 
-        let sequencer_public_key = Felt::from_bytes_be_slice(&self.0.sequencer_public_key);
-        let ibc_contract_address = Felt::from_bytes_be_slice(&self.0.ibc_contract_address);
+                self.verify_header(&ctx.host_timestamp()?, &header)?;
 
-        // 1. verify the block header
-        if !block_header
-            .verify_signature(&starknet_crypto_cw, &block_signature, &sequencer_public_key)
-            .map_err(|e| ClientError::FailedToVerifyHeader {
-                description: e.to_string(),
-            })?
-        {
-            return Err(ClientError::FailedToVerifyHeader {
-                description: "Invalid Starknet block header signature".to_string(),
-            });
-        }
-
-        // 2. validate the storage proof with correct merkle nodes
-        validate_storage_proof(&starknet_crypto_cw, &storage_proof).map_err(|e| {
-            ClientError::FailedToVerifyHeader {
-                description: e.to_string(),
+                Ok(())
             }
-        })?;
-
-        // FIXME: uncomment when #468 is resolved
-        // // 3. verify the global contract storage root is correct
-        // let global_contract_trie_root = verify_starknet_global_contract_root(
-        //     &starknet_crypto_cw,
-        //     &storage_proof,
-        //     block_header.state_root,
-        // )
-        // .map_err(|e| ClientError::FailedToVerifyHeader {
-        //     description: e.to_string(),
-        // })?;
-
-        let global_contract_trie_root = storage_proof.global_roots.contracts_tree_root;
-
-        // 4. verify the contract storage root is correct
-        let contract_root = verify_starknet_contract_proof(
-            &starknet_crypto_cw,
-            &storage_proof,
-            global_contract_trie_root,
-            ibc_contract_address,
-        )
-        .map_err(|e| ClientError::FailedToVerifyHeader {
-            description: e.to_string(),
-        })?;
-
-        verify_starknet_storage_proof(
-            &storage_proof,
-            contract_root,
-            // expansion of: selector!("final_height")
-            // to avoid import of: starknet_macros
-            Felt::from_raw([
-                282283167788747436,
-                16778837309615584552,
-                17246355766618278593,
-                8468359089124617139,
-            ]),
-            final_height.into(),
-        )
-        .map_err(|e| ClientError::FailedToVerifyHeader {
-            description: e.to_string(),
-        })?;
-
-        Ok(())
+            STARKNET_MISBEHAVIOUR_TYPE_URL => {
+                let evidence: StarknetMisbehaviour =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
+
+                self.verify_misbehaviour(&ctx.host_timestamp()?, client_id, &evidence)
+            }
+            STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL => {
+                let rotation: StarknetSequencerKeyRotation =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
+
+                self.verify_sequencer_key_rotation(&ctx.host_timestamp()?, &rotation)
+            }
+            type_url => Err(unknown_client_message(type_url)),
+        }
     }
 
     fn check_for_misbehaviour(
@@ -161,22 +106,38 @@ where
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<bool, ClientError> {
-        let starknet_crypto_cw = StarknetCryptoLib;
+        match client_message.type_url.as_str() {
+            STARKNET_HEADER_TYPE_URL => {
+                let header: StarknetHeader =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
+
+                header_conflicts_with_consensus_states(ctx, client_id, &header)
+            }
+            STARKNET_MISBEHAVIOUR_TYPE_URL => {
+                let evidence: StarknetMisbehaviour =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
 
-        let evidence: StarknetMisbehaviour =
-            <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
-                &StarknetLightClientEncoding,
-                &client_message,
-            )?;
+                self.verify_misbehaviour(&ctx.host_timestamp()?, client_id, &evidence)?;
 
-        // Different block headers at the same height is a misbehaviour case
-        if evidence.header_1.block_signature != evidence.header_2.block_signature {
-            return Ok(true);
+                evidence_is_misbehaviour(ctx, client_id, &evidence)
+            }
+            STARKNET_SEQUENCER_KEY_ROTATION_TYPE_URL => {
+                let rotation: StarknetSequencerKeyRotation =
+                    <ConvertVia<ProstAny, ConvertIbcAny, UseContext>>::convert(
+                        &StarknetLightClientEncoding,
+                        &client_message,
+                    )?;
+
+                header_conflicts_with_consensus_states(ctx, client_id, &rotation.header)
+            }
+            type_url => Err(unknown_client_message(type_url)),
         }
-
-        // TODO: Timestamp validation
-
-        Ok(false)
     }
 
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
@@ -185,11 +146,27 @@ where
         if client_state.0.is_frozen > 0 {
             return Ok(Status::Frozen);
         }
-        Ok(Status::Active)
+
+        // A trusting period of 0 is used by client states created without one.
+        if client_state.0.trusting_period == 0 {
+            return Ok(Status::Active);
+        }
+
+        let latest_height = client_state.latest_height();
+
+        let latest_consensus_state = ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ))?;
+
+        Ok(client_state.expiry_status(&latest_consensus_state, &ctx.host_timestamp()?))
     }
 
     fn check_substitute(&self, ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
-        Ok(())
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        self.check_substitute_parameters(&substitute_client_state)
     }
 
     fn verify_upgrade_client(
@@ -300,20 +277,21 @@ where
         root: &CommitmentRoot,
         path: PathBytes,
         value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_batched_membership(proof, root, vec![(path, value)])
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        ctx: &V,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
     ) -> Result<(), ClientError> {
         let starknet_crypto_cw = StarknetCryptoLib;
 
-        let path_bytes = path.into_vec();
-        let processed_path = Path::from_str(
-            alloc::str::from_utf8(path_bytes.as_ref())
-                .map_err(|e| ClientError::Decoding(DecodingError::StrUtf8(e)))?,
-        )
-        .map_err(|e| {
-            ClientError::Decoding(DecodingError::InvalidRawData {
-                description: e.to_string(),
-            })
-        })?;
-        let felt_value = get_felt_from_value(&starknet_crypto_cw, &value, &processed_path)?;
+        let processed_path = parse_path(path)?;
         let felt_path = ibc_path_to_storage_key(&starknet_crypto_cw, processed_path);
 
         let storage_proof: StorageProof = serde_json::from_slice(proof.as_ref()).map_err(|e| {
@@ -329,6 +307,9 @@ where
         // commitment root is: contract_storage_root.to_bytes_be()
         let contract_root = Felt::from_bytes_be_slice(root.as_bytes());
 
+        // For non-membership proof, the expected value is a zero value
+        let felt_value = Felt::ZERO;
+
         verify_starknet_storage_proof(&storage_proof, contract_root, felt_path, felt_value)
             .map_err(|e| {
                 ClientError::FailedICS23Verification(CommitmentError::FailedToVerifyMembership)
@@ -336,28 +317,78 @@ where
 
         Ok(())
     }
+}
 
-    fn verify_non_membership_raw(
+impl ClientState {
+    /// Checks that a substitute client state tracks the same chain as this client.
+    ///
+    /// Only the height, the frozen status, the upgrade final height and the
+    /// client-chosen parameters are allowed to differ. The sequencer public key may
+    /// also differ when the substitute is ahead of this client, so that a client
+    /// which missed a sequencer key rotation can be recovered with a substitute
+    /// that verified the headers signed by the rotated key.
+    pub fn check_substitute_parameters(&self, substitute: &Self) -> Result<(), ClientError> {
+        let mismatch = |field: &str| ClientError::ClientSpecific {
+            description: format!(
+                "Substitute client state {field} does not match the subject client state"
+            ),
+        };
+
+        if substitute.0.chain_id != self.0.chain_id {
+            return Err(mismatch("chain_id"));
+        }
+
+        if substitute.0.sequencer_public_key != self.0.sequencer_public_key
+            && substitute.latest_height() <= self.latest_height()
+        {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "Substitute client state with a different sequencer_public_key must be above the subject client latest height {}",
+                    self.latest_height()
+                ),
+            });
+        }
+
+        if substitute.0.ibc_contract_address != self.0.ibc_contract_address {
+            return Err(mismatch("ibc_contract_address"));
+        }
+
+        Ok(())
+    }
+
+    /// Status of the client with the given latest consensus state, which is expired
+    /// once the trusting period has elapsed since the timestamp of the consensus state.
+    pub fn expiry_status(
+        &self,
+        latest_consensus_state: &ConsensusState,
+        host_timestamp: &Timestamp,
+    ) -> Status {
+        match host_timestamp.duration_since(&latest_consensus_state.0.time) {
+            Some(elapsed) if elapsed.as_secs() >= self.0.trusting_period => Status::Expired,
+            _ => Status::Active,
+        }
+    }
+
+    /// Verifies the membership of several paths with a single storage proof, which must
+    /// contain the Merkle nodes of all the paths under the given commitment root.
+    pub fn verify_batched_membership(
         &self,
-        ctx: &V,
-        _prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
         root: &CommitmentRoot,
-        path: PathBytes,
+        entries: Vec<(PathBytes, Vec<u8>)>,
     ) -> Result<(), ClientError> {
         let starknet_crypto_cw = StarknetCryptoLib;
 
-        let path_bytes = path.into_vec();
-        let processed_path = Path::from_str(
-            alloc::str::from_utf8(path_bytes.as_ref())
-                .map_err(|e| ClientError::Decoding(DecodingError::StrUtf8(e)))?,
-        )
-        .map_err(|e| {
-            ClientError::Decoding(DecodingError::InvalidRawData {
-                description: e.to_string(),
+        let felt_entries = entries
+            .into_iter()
+            .map(|(path, value)| {
+                let processed_path = parse_path(path)?;
+                let felt_value = get_felt_from_value(&starknet_crypto_cw, &value, &processed_path)?;
+                let felt_path = ibc_path_to_storage_key(&starknet_crypto_cw, processed_path);
+
+                Ok((felt_path, felt_value))
             })
-        })?;
-        let felt_path = ibc_path_to_storage_key(&starknet_crypto_cw, processed_path);
+            .collect::<Result<Vec<_>, ClientError>>()?;
 
         let storage_proof: StorageProof = serde_json::from_slice(proof.as_ref()).map_err(|e| {
             ClientError::Decoding(DecodingError::InvalidJson {
@@ -365,6 +396,7 @@ where
             })
         })?;
 
+        // the shared proof is only validated once for all the paths
         validate_storage_proof(&starknet_crypto_cw, &storage_proof).map_err(|e| {
             ClientError::FailedICS23Verification(CommitmentError::FailedToVerifyMembership)
         })?;
@@ -372,18 +404,362 @@ where
         // commitment root is: contract_storage_root.to_bytes_be()
         let contract_root = Felt::from_bytes_be_slice(root.as_bytes());
 
-        // For non-membership proof, the expected value is a zero value
-        let felt_value = Felt::ZERO;
+        verify_starknet_storage_proofs(&storage_proof, contract_root, &felt_entries).map_err(
+            |e| ClientError::FailedICS23Verification(CommitmentError::FailedToVerifyMembership),
+        )?;
 
-        verify_starknet_storage_proof(&storage_proof, contract_root, felt_path, felt_value)
-            .map_err(|e| {
-                ClientError::FailedICS23Verification(CommitmentError::FailedToVerifyMembership)
-            })?;
+        Ok(())
+    }
+
+    /// Verifies that the header is signed by the sequencer of this client, and that
+    /// its storage proof commits to the final height of the IBC contract.
+    ///
+    /// Returns the verified storage root of the IBC contract.
+    pub fn verify_header(
+        &self,
+        host_timestamp: &Timestamp,
+        header: &StarknetHeader,
+    ) -> Result<Felt, ClientError> {
+        let starknet_crypto_cw = StarknetCryptoLib;
+
+        let StarknetHeader {
+            block_header,
+            final_height,
+            block_signature,
+            storage_proof,
+        } = header;
+
+        // this is to make sure after a schedule upgrade, the client can't be updated after final_height.
+        // this way, any packet after the final_height will be rejected.
+        // only way to resume the client is to remove the scheduled upgrade on starknet after the upgrade is finished.
+        if *final_height != 0 && *final_height < block_header.block_number {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "Updating client at height {} after upgrade final height ({final_height}); \
+                    upgrade the Starknet Client or unschedule upgrade at Starknet",
+                    block_header.block_number
+                ),
+            });
+        }
+
+        // reject headers from the future, allowing for the configured clock drift.
+        if self.0.max_clock_drift != 0 {
+            let max_timestamp = host_timestamp
+                .nanoseconds()
+                .saturating_add(self.0.max_clock_drift.saturating_mul(1_000_000_000));
+            let header_timestamp = block_header.timestamp.saturating_mul(1_000_000_000);
+
+            if header_timestamp > max_timestamp {
+                return Err(ClientError::ClientSpecific {
+                    description: format!(
+                        "Header timestamp {} of block {} is ahead of host time {host_timestamp} \
+                        by more than the max clock drift of {}s",
+                        block_header.timestamp, block_header.block_number, self.0.max_clock_drift,
+                    ),
+                });
+            }
+        }
+
+        let sequencer_public_key = Felt::from_bytes_be_slice(&self.0.sequencer_public_key);
+        let ibc_contract_address = Felt::from_bytes_be_slice(&self.0.ibc_contract_address);
+
+        // 1. verify the block header
+        verify_block_signature(block_header, block_signature, &sequencer_public_key)?;
+
+        // 2. validate the storage proof with correct merkle nodes
+        validate_storage_proof(&starknet_crypto_cw, storage_proof).map_err(|e| {
+            ClientError::FailedToVerifyHeader {
+                description: e.to_string(),
+            }
+        })?;
+
+        // 3. verify the global contract storage root is correct
+        let global_contract_trie_root = verify_starknet_global_contract_root(
+            &starknet_crypto_cw,
+            storage_proof,
+            block_header.state_root,
+        )
+        .map_err(|e| ClientError::FailedToVerifyHeader {
+            description: e.to_string(),
+        })?;
+
+        // 4. verify the contract storage root is correct
+        let contract_root = verify_starknet_contract_proof(
+            &starknet_crypto_cw,
+            storage_proof,
+            global_contract_trie_root,
+            ibc_contract_address,
+        )
+        .map_err(|e| ClientError::FailedToVerifyHeader {
+            description: e.to_string(),
+        })?;
+
+        verify_starknet_storage_proof(
+            storage_proof,
+            contract_root,
+            // expansion of: selector!("final_height")
+            // to avoid import of: starknet_macros
+            Felt::from_raw([
+                282283167788747436,
+                16778837309615584552,
+                17246355766618278593,
+                8468359089124617139,
+            ]),
+            (*final_height).into(),
+        )
+        .map_err(|e| ClientError::FailedToVerifyHeader {
+            description: e.to_string(),
+        })?;
+
+        Ok(contract_root)
+    }
+
+    /// Verifies a rotation of the sequencer public key.
+    ///
+    /// The header must be above the latest height of the client and signed by the
+    /// currently trusted key. Its storage proof must show the new key in the sequencer
+    /// key registry of the IBC contract, where keys only become active after a timelock.
+    pub fn verify_sequencer_key_rotation(
+        &self,
+        host_timestamp: &Timestamp,
+        rotation: &StarknetSequencerKeyRotation,
+    ) -> Result<(), ClientError> {
+        let latest_height = self.latest_height();
+
+        if rotation.header.height() <= latest_height {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "Sequencer key rotation at height {} is not above the latest height {latest_height}",
+                    rotation.header.height()
+                ),
+            });
+        }
+
+        let contract_root = self.verify_header(host_timestamp, &rotation.header)?;
+
+        let current_key = Felt::from_bytes_be_slice(&self.0.sequencer_public_key);
+        let new_key = Felt::from_bytes_be_slice(&rotation.new_sequencer_public_key);
+
+        if new_key == Felt::ZERO || new_key == current_key {
+            return Err(ClientError::ClientSpecific {
+                description: format!("Invalid new sequencer public key {new_key:#x}"),
+            });
+        }
+
+        verify_starknet_storage_proof(
+            &rotation.header.storage_proof,
+            contract_root,
+            // expansion of: selector!("sequencer_public_key")
+            // to avoid import of: starknet_macros
+            Felt::from_raw([
+                220615067380097036,
+                10998240085726327422,
+                14112535003492495987,
+                7904034733593021784,
+            ]),
+            new_key,
+        )
+        .map_err(|e| ClientError::FailedToVerifyHeader {
+            description: format!(
+                "Sequencer key registry does not hold the new sequencer public key: {e}"
+            ),
+        })
+    }
+
+    /// Verifies that the misbehaviour evidence is for this client and that both
+    /// headers are distinct blocks signed by the sequencer of this client, with
+    /// storage proofs of the IBC contract.
+    ///
+    /// This does not check whether the headers actually conflict, which is done
+    /// by `check_for_misbehaviour`.
+    pub fn verify_misbehaviour(
+        &self,
+        host_timestamp: &Timestamp,
+        client_id: &ClientId,
+        evidence: &StarknetMisbehaviour,
+    ) -> Result<(), ClientError> {
+        if &evidence.client_id != client_id {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "Misbehaviour evidence is for client {}, not {client_id}",
+                    evidence.client_id
+                ),
+            });
+        }
+
+        if evidence.header_1.block_header.block_hash == evidence.header_2.block_header.block_hash {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "Misbehaviour evidence contains the same block {} twice",
+                    evidence.header_1.block_header.block_number
+                ),
+            });
+        }
+
+        // the storage proofs are verified too, as the commitment roots of the headers
+        // are compared with the consensus states of the client.
+        for header in [&evidence.header_1, &evidence.header_2] {
+            self.verify_header(host_timestamp, header)?;
+        }
 
         Ok(())
     }
 }
 
+/// Checks whether two verified headers conflict with each other, or with the
+/// consensus states stored by the client.
+fn evidence_is_misbehaviour<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    evidence: &StarknetMisbehaviour,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext<ConsensusStateRef = ConsensusState>,
+{
+    let StarknetMisbehaviour {
+        header_1, header_2, ..
+    } = evidence;
+
+    if headers_conflict(header_1, header_2) {
+        return Ok(true);
+    }
+
+    // Signed headers must also be consistent with the consensus states stored by the client
+    for header in [header_1, header_2] {
+        if header_conflicts_with_consensus_states(ctx, client_id, header)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks whether two verified headers conflict with each other: distinct blocks at
+/// the same height, or block timestamps decreasing as the height increases.
+fn headers_conflict(header_1: &StarknetHeader, header_2: &StarknetHeader) -> bool {
+    if header_1.height() == header_2.height() {
+        return header_1.block_header.block_hash != header_2.block_header.block_hash;
+    }
+
+    let (lower_header, higher_header) = if header_1.height() < header_2.height() {
+        (header_1, header_2)
+    } else {
+        (header_2, header_1)
+    };
+
+    higher_header.block_header.timestamp < lower_header.block_header.timestamp
+}
+
+fn parse_path(path: PathBytes) -> Result<Path, ClientError> {
+    let path_bytes = path.into_vec();
+
+    Path::from_str(
+        alloc::str::from_utf8(path_bytes.as_ref())
+            .map_err(|e| ClientError::Decoding(DecodingError::StrUtf8(e)))?,
+    )
+    .map_err(|e| {
+        ClientError::Decoding(DecodingError::InvalidRawData {
+            description: e.to_string(),
+        })
+    })
+}
+
+pub(crate) fn unknown_client_message(type_url: &str) -> ClientError {
+    ClientError::ClientSpecific {
+        description: format!("Unknown Starknet client message type URL: {type_url}"),
+    }
+}
+
+/// Verifies that the block hash is correctly computed and signed by the sequencer.
+fn verify_block_signature(
+    block_header: &Block,
+    block_signature: &Signature,
+    sequencer_public_key: &Felt,
+) -> Result<(), ClientError> {
+    if !block_header
+        .verify_signature(&StarknetCryptoLib, block_signature, sequencer_public_key)
+        .map_err(|e| ClientError::FailedToVerifyHeader {
+            description: e.to_string(),
+        })?
+    {
+        return Err(ClientError::FailedToVerifyHeader {
+            description: "Invalid Starknet block header signature".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks whether the header contradicts the consensus states stored by the client,
+/// either at the same height or by breaking the time monotonicity with its neighbours.
+fn header_conflicts_with_consensus_states<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    header: &StarknetHeader,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext<ConsensusStateRef = ConsensusState>,
+{
+    let height = header.height();
+
+    let consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+        .ok();
+
+    if let Some(consensus_state) = consensus_state {
+        return Ok(header_conflicts_with_consensus_state(
+            header,
+            &consensus_state,
+        ));
+    }
+
+    let prev_consensus_state = ctx.prev_consensus_state(client_id, &height)?;
+    let next_consensus_state = ctx.next_consensus_state(client_id, &height)?;
+
+    Ok(header_breaks_time_monotonicity(
+        header,
+        prev_consensus_state.as_ref(),
+        next_consensus_state.as_ref(),
+    ))
+}
+
+/// Checks whether the header differs from the consensus state stored at its height,
+/// either by its timestamp or by its commitment root.
+fn header_conflicts_with_consensus_state(
+    header: &StarknetHeader,
+    consensus_state: &ConsensusState,
+) -> bool {
+    let header_root = header
+        .storage_proof
+        .contracts_proof
+        .contract_leaves_data
+        .first()
+        .and_then(|leaf| leaf.storage_root);
+
+    let root_conflicts = header_root.is_some_and(|header_root| {
+        header_root.to_bytes_be().as_slice() != consensus_state.0.root.as_bytes()
+    });
+
+    consensus_state.0.time != header.timestamp() || root_conflicts
+}
+
+/// Checks whether the header timestamp is before the one of the previous consensus state,
+/// or after the one of the next consensus state.
+fn header_breaks_time_monotonicity(
+    header: &StarknetHeader,
+    prev_consensus_state: Option<&ConsensusState>,
+    next_consensus_state: Option<&ConsensusState>,
+) -> bool {
+    let timestamp = header.timestamp();
+
+    prev_consensus_state.is_some_and(|consensus_state| consensus_state.0.time > timestamp)
+        || next_consensus_state.is_some_and(|consensus_state| consensus_state.0.time < timestamp)
+}
+
 fn get_felt_from_value<C: StarknetCryptoFunctions>(
     crypto_lib: &C,
     value: &Vec<u8>,
@@ -474,6 +850,10 @@ fn get_felt_from_value<C: StarknetCryptoFunctions>(
                     sequencer_public_key,
                     ibc_contract_address,
                     is_frozen,
+                    // client-chosen parameters are not committed by Starknet
+                    trusting_period: _,
+                    max_clock_drift: _,
+                    max_consensus_states: _,
                 } = upgrade_client_state.0;
 
                 let chain_id_bytes = chain_id.as_str().as_bytes();
@@ -522,7 +902,10 @@ fn get_felt_from_value<C: StarknetCryptoFunctions>(
 #[cfg(test)]
 mod tests {
     use ibc_core::client::types::Height;
-    use ibc_core::primitives::Timestamp;
+    use starknet_block_verifier::{GasPrices, L1DataAvailabilityMode};
+    use starknet_core::types::{
+        ContractLeafData, ContractsProof, EdgeNode, GlobalRoots, MerkleNode,
+    };
 
     use super::*;
 
@@ -538,6 +921,9 @@ mod tests {
                 .to_bytes_be()
                 .to_vec(),
             is_frozen: 0,
+            trusting_period: 0,
+            max_clock_drift: 0,
+            max_consensus_states: 0,
         };
         let mut felts = vec![];
 
@@ -549,6 +935,7 @@ mod tests {
                 sequencer_public_key,
                 ibc_contract_address,
                 is_frozen,
+                ..
             } = client_state;
 
             let chain_id_bytes = chain_id.as_str().as_bytes();
@@ -601,4 +988,464 @@ mod tests {
                 .unwrap(),
         );
     }
+
+    fn subject_client_state() -> ClientState {
+        StarknetClientState {
+            latest_height: Height::new(0, 100).unwrap(),
+            final_height: 0,
+            chain_id: "SN_SEPOLIA".parse().unwrap(),
+            sequencer_public_key: Felt::from(0x12345).to_bytes_be().to_vec(),
+            ibc_contract_address: Felt::from(0x6789).to_bytes_be().to_vec(),
+            is_frozen: 1,
+            trusting_period: 3600,
+            max_clock_drift: 60,
+            max_consensus_states: 100,
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_check_substitute_valid() {
+        let subject = subject_client_state();
+
+        let substitute: ClientState = StarknetClientState {
+            latest_height: Height::new(0, 200).unwrap(),
+            final_height: 300,
+            is_frozen: 0,
+            trusting_period: 7200,
+            max_clock_drift: 30,
+            max_consensus_states: 0,
+            ..subject.0.clone()
+        }
+        .into();
+
+        assert!(subject.check_substitute_parameters(&substitute).is_ok());
+    }
+
+    #[test]
+    fn test_check_substitute_chain_id_mismatch() {
+        let subject = subject_client_state();
+
+        let substitute: ClientState = StarknetClientState {
+            chain_id: "SN_MAIN".parse().unwrap(),
+            ..subject.0.clone()
+        }
+        .into();
+
+        assert!(subject.check_substitute_parameters(&substitute).is_err());
+    }
+
+    #[test]
+    fn test_check_substitute_sequencer_public_key_mismatch() {
+        let subject = subject_client_state();
+
+        let substitute: ClientState = StarknetClientState {
+            sequencer_public_key: Felt::from(0x54321).to_bytes_be().to_vec(),
+            ..subject.0.clone()
+        }
+        .into();
+
+        assert!(subject.check_substitute_parameters(&substitute).is_err());
+    }
+
+    #[test]
+    fn test_check_substitute_sequencer_public_key_rotation() {
+        let subject = subject_client_state();
+
+        let substitute: ClientState = StarknetClientState {
+            latest_height: Height::new(0, 200).unwrap(),
+            sequencer_public_key: Felt::from(0x54321).to_bytes_be().to_vec(),
+            ..subject.0.clone()
+        }
+        .into();
+
+        assert!(subject.check_substitute_parameters(&substitute).is_ok());
+    }
+
+    #[test]
+    fn test_check_substitute_ibc_contract_address_mismatch() {
+        let subject = subject_client_state();
+
+        let substitute: ClientState = StarknetClientState {
+            ibc_contract_address: Felt::from(0x9876).to_bytes_be().to_vec(),
+            ..subject.0.clone()
+        }
+        .into();
+
+        assert!(subject.check_substitute_parameters(&substitute).is_err());
+    }
+
+    const SEQUENCER_PRIVATE_KEY: u64 = 0x1234;
+    const IBC_CONTRACT_ADDRESS: u64 = 0x6789;
+    const HOST_TIME: u64 = 1_000_000;
+
+    fn trusted_client_state() -> ClientState {
+        let sequencer_public_key = starknet_crypto::get_public_key(&SEQUENCER_PRIVATE_KEY.into());
+
+        StarknetClientState {
+            latest_height: Height::new(0, 100).unwrap(),
+            final_height: 0,
+            chain_id: "SN_SEPOLIA".parse().unwrap(),
+            sequencer_public_key: sequencer_public_key.to_bytes_be().to_vec(),
+            ibc_contract_address: Felt::from(IBC_CONTRACT_ADDRESS).to_bytes_be().to_vec(),
+            is_frozen: 0,
+            trusting_period: 3600,
+            max_clock_drift: 60,
+            max_consensus_states: 0,
+        }
+        .into()
+    }
+
+    fn host_timestamp() -> Timestamp {
+        Timestamp::from_unix_timestamp(HOST_TIME, 0).unwrap()
+    }
+
+    /// Merkle edge node spanning the full depth of the trie, with its hash.
+    fn leaf_edge_node(path: Felt, child: Felt) -> (Felt, MerkleNode) {
+        let length = 251;
+        let hash = StarknetCryptoLib.pedersen_hash(&child, &path) + Felt::from(length);
+
+        (
+            hash,
+            MerkleNode::EdgeNode(EdgeNode {
+                path,
+                length,
+                child,
+            }),
+        )
+    }
+
+    /// Builds a header for the block, signed by the given sequencer private key, whose
+    /// storage proof holds `registered_sequencer_public_key` in the IBC contract.
+    fn signed_header(
+        block_number: u64,
+        timestamp: u64,
+        sequencer_private_key: Felt,
+        registered_sequencer_public_key: Felt,
+    ) -> StarknetHeader {
+        let crypto_lib = StarknetCryptoLib;
+
+        let storage_node = leaf_edge_node(
+            crypto_lib.starknet_keccak(b"sequencer_public_key"),
+            registered_sequencer_public_key,
+        );
+        let storage_root = storage_node.0;
+
+        let class_hash = Felt::from(0xc1a55);
+        let nonce = Felt::ZERO;
+        let contract_hash = crypto_lib.pedersen_hash(
+            &crypto_lib.pedersen_hash(
+                &crypto_lib.pedersen_hash(&class_hash, &storage_root),
+                &nonce,
+            ),
+            &Felt::ZERO,
+        );
+
+        let contract_node = leaf_edge_node(Felt::from(IBC_CONTRACT_ADDRESS), contract_hash);
+        let contracts_tree_root = contract_node.0;
+
+        let storage_proof = StorageProof {
+            classes_proof: Default::default(),
+            contracts_proof: ContractsProof {
+                nodes: [contract_node].into_iter().collect(),
+                contract_leaves_data: vec![ContractLeafData {
+                    nonce,
+                    class_hash,
+                    storage_root: Some(storage_root),
+                }],
+            },
+            contracts_storage_proofs: vec![[storage_node].into_iter().collect()],
+            global_roots: GlobalRoots {
+                contracts_tree_root,
+                // with an empty classes tree, the state root is the contracts tree root
+                classes_tree_root: Felt::ZERO,
+                block_hash: Felt::ZERO,
+            },
+        };
+
+        let gas_prices = GasPrices {
+            price_in_wei: Felt::ONE,
+            price_in_fri: Felt::ONE,
+        };
+
+        let mut block_header = Block {
+            block_number,
+            state_root: contracts_tree_root,
+            sequencer_address: Felt::ZERO,
+            timestamp,
+            transactions: vec![],
+            transaction_receipts: vec![],
+            state_diff_length: None,
+            l1_da_mode: L1DataAvailabilityMode::Blob,
+            state_diff_commitment: None,
+            transaction_commitment: Felt::ZERO,
+            event_commitment: Felt::ZERO,
+            receipt_commitment: None,
+            l1_gas_price: gas_prices,
+            l1_data_gas_price: gas_prices,
+            l2_gas_price: Some(gas_prices),
+            parent_block_hash: Felt::ZERO,
+            block_hash: Felt::ZERO,
+            starknet_version: "0.13.5".to_string(),
+        };
+        block_header.block_hash = block_header.compute_hash(&crypto_lib);
+
+        let k = starknet_crypto::rfc6979_generate_k(
+            &block_header.block_hash,
+            &sequencer_private_key,
+            None,
+        );
+        let signature =
+            starknet_crypto::sign(&sequencer_private_key, &block_header.block_hash, &k).unwrap();
+
+        StarknetHeader {
+            block_signature: Signature {
+                block_hash: block_header.block_hash,
+                signature: [signature.r, signature.s],
+            },
+            block_header,
+            final_height: 0,
+            storage_proof,
+        }
+    }
+
+    fn key_rotation(
+        block_number: u64,
+        sequencer_private_key: Felt,
+        registered_sequencer_public_key: Felt,
+        new_sequencer_public_key: Felt,
+    ) -> StarknetSequencerKeyRotation {
+        StarknetSequencerKeyRotation {
+            header: signed_header(
+                block_number,
+                HOST_TIME,
+                sequencer_private_key,
+                registered_sequencer_public_key,
+            ),
+            new_sequencer_public_key: new_sequencer_public_key.to_bytes_be().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_sequencer_key_rotation_valid() {
+        let new_key = Felt::from(0x5678);
+        let rotation = key_rotation(101, SEQUENCER_PRIVATE_KEY.into(), new_key, new_key);
+
+        trusted_client_state()
+            .verify_sequencer_key_rotation(&host_timestamp(), &rotation)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sequencer_key_rotation_wrong_signer() {
+        let new_key = Felt::from(0x5678);
+        let rotation = key_rotation(101, Felt::from(0x9999), new_key, new_key);
+
+        assert!(trusted_client_state()
+            .verify_sequencer_key_rotation(&host_timestamp(), &rotation)
+            .is_err());
+    }
+
+    #[test]
+    fn test_sequencer_key_rotation_unregistered_key() {
+        let rotation = key_rotation(
+            101,
+            SEQUENCER_PRIVATE_KEY.into(),
+            Felt::from(0x5678),
+            Felt::from(0x8765),
+        );
+
+        assert!(trusted_client_state()
+            .verify_sequencer_key_rotation(&host_timestamp(), &rotation)
+            .is_err());
+    }
+
+    #[test]
+    fn test_sequencer_key_rotation_not_above_latest_height() {
+        let new_key = Felt::from(0x5678);
+        let rotation = key_rotation(100, SEQUENCER_PRIVATE_KEY.into(), new_key, new_key);
+
+        assert!(trusted_client_state()
+            .verify_sequencer_key_rotation(&host_timestamp(), &rotation)
+            .is_err());
+    }
+
+    fn misbehaviour(header_1: StarknetHeader, header_2: StarknetHeader) -> StarknetMisbehaviour {
+        StarknetMisbehaviour {
+            client_id: misbehaving_client_id(),
+            header_1,
+            header_2,
+        }
+    }
+
+    fn misbehaving_client_id() -> ClientId {
+        "08-wasm-0".parse().unwrap()
+    }
+
+    fn sequencer_signed_header(block_number: u64, timestamp: u64) -> StarknetHeader {
+        signed_header(
+            block_number,
+            timestamp,
+            SEQUENCER_PRIVATE_KEY.into(),
+            Felt::from(0x5678),
+        )
+    }
+
+    #[test]
+    fn test_misbehaviour_same_height_different_blocks() {
+        let evidence = misbehaviour(
+            sequencer_signed_header(101, HOST_TIME - 20),
+            sequencer_signed_header(101, HOST_TIME - 10),
+        );
+
+        trusted_client_state()
+            .verify_misbehaviour(&host_timestamp(), &misbehaving_client_id(), &evidence)
+            .unwrap();
+
+        assert!(headers_conflict(&evidence.header_1, &evidence.header_2));
+    }
+
+    #[test]
+    fn test_misbehaviour_identical_headers() {
+        let header = sequencer_signed_header(101, HOST_TIME - 10);
+        let evidence = misbehaviour(header.clone(), header);
+
+        assert!(trusted_client_state()
+            .verify_misbehaviour(&host_timestamp(), &misbehaving_client_id(), &evidence)
+            .is_err());
+    }
+
+    #[test]
+    fn test_misbehaviour_invalid_signature() {
+        let mut header_2 = sequencer_signed_header(101, HOST_TIME - 10);
+        header_2.block_signature.signature = [Felt::ONE, Felt::TWO];
+
+        let evidence = misbehaviour(sequencer_signed_header(101, HOST_TIME - 20), header_2);
+
+        assert!(trusted_client_state()
+            .verify_misbehaviour(&host_timestamp(), &misbehaving_client_id(), &evidence)
+            .is_err());
+    }
+
+    #[test]
+    fn test_misbehaviour_other_client() {
+        let evidence = misbehaviour(
+            sequencer_signed_header(101, HOST_TIME - 20),
+            sequencer_signed_header(101, HOST_TIME - 10),
+        );
+
+        assert!(trusted_client_state()
+            .verify_misbehaviour(&host_timestamp(), &"08-wasm-1".parse().unwrap(), &evidence)
+            .is_err());
+    }
+
+    #[test]
+    fn test_misbehaviour_non_monotonic_time() {
+        let evidence = misbehaviour(
+            sequencer_signed_header(101, HOST_TIME - 10),
+            sequencer_signed_header(102, HOST_TIME - 20),
+        );
+
+        trusted_client_state()
+            .verify_misbehaviour(&host_timestamp(), &misbehaving_client_id(), &evidence)
+            .unwrap();
+
+        assert!(headers_conflict(&evidence.header_1, &evidence.header_2));
+        assert!(headers_conflict(&evidence.header_2, &evidence.header_1));
+
+        assert!(!headers_conflict(
+            &sequencer_signed_header(101, HOST_TIME - 20),
+            &sequencer_signed_header(102, HOST_TIME - 10),
+        ));
+    }
+
+    #[test]
+    fn test_header_conflicts_with_consensus_state() {
+        let header = sequencer_signed_header(101, HOST_TIME - 10);
+        let consensus_state = ConsensusState(header.clone().into());
+
+        assert!(!header_conflicts_with_consensus_state(
+            &header,
+            &consensus_state
+        ));
+
+        let other_time = ConsensusState(StarknetConsensusState {
+            time: Timestamp::from_unix_timestamp(HOST_TIME - 20, 0).unwrap(),
+            ..consensus_state.0.clone()
+        });
+
+        assert!(header_conflicts_with_consensus_state(&header, &other_time));
+
+        let other_root = ConsensusState(StarknetConsensusState {
+            root: Felt::from(0x9999).to_bytes_be().to_vec().into(),
+            ..consensus_state.0.clone()
+        });
+
+        assert!(header_conflicts_with_consensus_state(&header, &other_root));
+    }
+
+    #[test]
+    fn test_header_breaks_time_monotonicity() {
+        let header = sequencer_signed_header(101, HOST_TIME - 10);
+
+        let consensus_state_at = |time: u64| {
+            ConsensusState(StarknetConsensusState {
+                time: Timestamp::from_unix_timestamp(time, 0).unwrap(),
+                ..header.clone().into()
+            })
+        };
+
+        let earlier = consensus_state_at(HOST_TIME - 20);
+        let later = consensus_state_at(HOST_TIME);
+
+        assert!(!header_breaks_time_monotonicity(
+            &header,
+            Some(&earlier),
+            Some(&later)
+        ));
+        assert!(!header_breaks_time_monotonicity(&header, None, None));
+        assert!(header_breaks_time_monotonicity(&header, Some(&later), None));
+        assert!(header_breaks_time_monotonicity(
+            &header,
+            None,
+            Some(&earlier)
+        ));
+    }
+
+    #[test]
+    fn test_status_expires_after_trusting_period() {
+        let client_state = trusted_client_state();
+
+        let latest_consensus_state = ConsensusState(sequencer_signed_header(100, HOST_TIME).into());
+
+        let status_at = |time: u64| {
+            client_state.expiry_status(
+                &latest_consensus_state,
+                &Timestamp::from_unix_timestamp(time, 0).unwrap(),
+            )
+        };
+
+        assert_eq!(status_at(HOST_TIME - 1), Status::Active);
+        assert_eq!(status_at(HOST_TIME + 3599), Status::Active);
+        assert_eq!(status_at(HOST_TIME + 3600), Status::Expired);
+    }
+
+    #[test]
+    fn test_header_within_max_clock_drift() {
+        let client_state = trusted_client_state();
+
+        client_state
+            .verify_header(
+                &host_timestamp(),
+                &sequencer_signed_header(101, HOST_TIME + 60),
+            )
+            .unwrap();
+
+        assert!(client_state
+            .verify_header(
+                &host_timestamp(),
+                &sequencer_signed_header(101, HOST_TIME + 61)
+            )
+            .is_err());
+    }
 }
@@ -19,6 +19,7 @@ use hermes_protobuf_encoding_components::types::any::Any;
 use hermes_protobuf_encoding_components::types::strategy::{ViaAny, ViaProtobuf};
 use ibc_client_starknet_types::encoding::components::*;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
 use ibc_client_starknet_types::{StarknetClientState, StarknetConsensusState};
 use ibc_core::client::types::error::ClientError;
@@ -210,9 +211,11 @@ pub trait CanUseStarknetLightClientEncoding:
     + CanEncodeAndDecode<ViaAny, StarknetConsensusState>
     + CanEncodeAndDecode<ViaAny, StarknetMisbehaviour>
     + CanEncodeAndDecode<ViaAny, StarknetHeader>
+    + CanEncodeAndDecode<ViaAny, StarknetSequencerKeyRotation>
     + CanConvertBothWays<Any, StarknetClientState>
     + CanConvertBothWays<Any, StarknetConsensusState>
     + CanConvertBothWays<Any, StarknetHeader>
+    + CanConvertBothWays<Any, StarknetSequencerKeyRotation>
     + CanEncodeAndDecodeMut<ViaProtobuf, Timestamp>
     + CanEncodeAndDecodeMut<ViaProtobuf, CommitmentRoot>
 {
@@ -0,0 +1,234 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display};
+use core::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use starknet_core::types::Felt;
+
+use crate::{Block, Signature, MAINNET_FEEDER_URL, SEPOLIA_FEEDER_URL};
+
+// https://community.starknet.io/t/feeder-gateway-deprecation/100233#full-list-of-endpoints-3
+const GET_BLOCK_PATH: &str = "get_block";
+const GET_PUBLIC_KEY: &str = "get_public_key";
+const GET_SIGNATURE: &str = "get_signature";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeederConfig {
+    /// Timeout of a single request, including reading the response body.
+    #[serde(with = "humantime_serde")]
+    pub request_timeout: Duration,
+    /// Timeout for establishing the connection to the feeder gateway.
+    #[serde(with = "humantime_serde")]
+    pub connect_timeout: Duration,
+    /// Number of retries after the first attempt. `0` disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry. It is doubled after every retry.
+    #[serde(with = "humantime_serde")]
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two retries.
+    #[serde(with = "humantime_serde")]
+    pub max_backoff: Duration,
+}
+
+impl Default for FeederConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FeederError {
+    /// The feeder gateway responded with `429 Too Many Requests`.
+    RateLimited { retry_after: Option<Duration> },
+    /// The feeder gateway responded with a `5xx` status.
+    Server { status: u16, body: String },
+    /// The feeder gateway responded with any other non-success status.
+    UnexpectedStatus { status: u16, body: String },
+    /// The request could not be sent, timed out, or its body could not be decoded.
+    Request(reqwest::Error),
+}
+
+impl FeederError {
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } | Self::Server { .. } => true,
+            Self::UnexpectedStatus { .. } => false,
+            Self::Request(err) => err.is_timeout() || err.is_connect(),
+        }
+    }
+}
+
+impl Display for FeederError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "feeder gateway rate limited the request, retry after {retry_after:?}"
+            ),
+            Self::RateLimited { retry_after: None } => {
+                write!(f, "feeder gateway rate limited the request")
+            }
+            Self::Server { status, body } => {
+                write!(f, "feeder gateway server error ({status}): {body}")
+            }
+            Self::UnexpectedStatus { status, body } => {
+                write!(f, "unexpected feeder gateway response ({status}): {body}")
+            }
+            Self::Request(err) => write!(f, "feeder gateway request failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FeederError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FeederError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(err)
+    }
+}
+
+/// Non-blocking feeder gateway client with timeouts and retries.
+///
+/// Requests that fail with `429`, a `5xx` status, a timeout or a connection
+/// error are retried with exponential backoff, as configured by [`FeederConfig`].
+#[derive(Debug, Clone)]
+pub struct AsyncEndpoint {
+    pub url: String,
+    pub config: FeederConfig,
+    client: Client,
+}
+
+impl AsyncEndpoint {
+    pub fn new(endpoint: &str) -> Result<Self, FeederError> {
+        Self::with_config(endpoint, FeederConfig::default())
+    }
+
+    pub fn with_config(endpoint: &str, config: FeederConfig) -> Result<Self, FeederError> {
+        let client = Client::builder()
+            .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()?;
+
+        Ok(Self {
+            url: endpoint.to_string(),
+            config,
+            client,
+        })
+    }
+
+    pub fn sepolia() -> Result<Self, FeederError> {
+        Self::new(SEPOLIA_FEEDER_URL)
+    }
+
+    pub fn mainnet() -> Result<Self, FeederError> {
+        Self::new(MAINNET_FEEDER_URL)
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &'static str,
+        block_number: Option<u64>,
+    ) -> Result<T, FeederError> {
+        let mut backoff = self.config.initial_backoff;
+        let mut retries = 0;
+
+        loop {
+            match self.try_get(path, block_number).await {
+                Err(err) if err.is_retryable() && retries < self.config.max_retries => {
+                    let delay = match err {
+                        FeederError::RateLimited {
+                            retry_after: Some(retry_after),
+                        } => retry_after.max(backoff),
+                        _ => backoff,
+                    }
+                    .min(self.config.max_backoff);
+
+                    tokio::time::sleep(delay).await;
+
+                    backoff = backoff.saturating_mul(2).min(self.config.max_backoff);
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_get<T: DeserializeOwned>(
+        &self,
+        path: &'static str,
+        block_number: Option<u64>,
+    ) -> Result<T, FeederError> {
+        let mut req = self
+            .client
+            .get(format!("{}/feeder_gateway/{path}", self.url));
+
+        if let Some(block_number) = block_number {
+            req = req.query(&[("blockNumber", block_number)]);
+        }
+
+        let response = req.send().await?;
+
+        let status = response.status();
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+                .map(Duration::from_secs);
+
+            return Err(FeederError::RateLimited { retry_after });
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+
+            return Err(if status.is_server_error() {
+                FeederError::Server {
+                    status: status.as_u16(),
+                    body,
+                }
+            } else {
+                FeederError::UnexpectedStatus {
+                    status: status.as_u16(),
+                    body,
+                }
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    pub async fn get_block_header(&self, block_number: Option<u64>) -> Result<Block, FeederError> {
+        self.get(GET_BLOCK_PATH, block_number).await
+    }
+
+    pub async fn get_public_key(&self, block_number: Option<u64>) -> Result<Felt, FeederError> {
+        self.get(GET_PUBLIC_KEY, block_number).await
+    }
+
+    pub async fn get_signature(&self, block_number: Option<u64>) -> Result<Signature, FeederError> {
+        self.get(GET_SIGNATURE, block_number).await
+    }
+}
@@ -1,12 +1,18 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "async-feeder")]
+extern crate std;
 
+#[cfg(feature = "async-feeder")]
+mod async_feeder;
 mod consts;
 #[cfg(feature = "feeder")]
 mod feeder;
 mod types;
 
+#[cfg(feature = "async-feeder")]
+pub use async_feeder::*;
 pub use consts::*;
 #[cfg(feature = "feeder")]
 pub use feeder::*;
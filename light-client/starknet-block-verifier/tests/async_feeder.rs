@@ -0,0 +1,122 @@
+use core::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use starknet_block_verifier::*;
+use starknet_core::types::Felt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serves the given raw HTTP responses in order, one per connection,
+/// and returns the endpoint URL together with the number of requests served.
+async fn mock_feeder(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let served = Arc::new(AtomicUsize::new(0));
+
+    let counter = served.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            counter.fetch_add(1, Ordering::SeqCst);
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+    });
+
+    (url, served)
+}
+
+fn response(status: &str, headers: &[&str], body: &str) -> String {
+    let mut response = format!("HTTP/1.1 {status}\r\nconnection: close\r\n");
+    for header in headers {
+        response.push_str(header);
+        response.push_str("\r\n");
+    }
+    response.push_str(&format!("content-length: {}\r\n\r\n{body}", body.len()));
+    response
+}
+
+fn fast_retry_config(max_retries: u32) -> FeederConfig {
+    FeederConfig {
+        max_retries,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(10),
+        ..FeederConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_retry_on_rate_limit_and_server_error() {
+    let (url, served) = mock_feeder(vec![
+        response("429 Too Many Requests", &["retry-after: 0"], ""),
+        response("503 Service Unavailable", &[], "unavailable"),
+        response("200 OK", &["content-type: application/json"], "\"0x1234\""),
+    ])
+    .await;
+
+    let endpoint = AsyncEndpoint::with_config(&url, fast_retry_config(2)).unwrap();
+
+    let public_key = endpoint.get_public_key(Some(1)).await.unwrap();
+
+    assert_eq!(public_key, Felt::from_hex_unchecked("0x1234"));
+    assert_eq!(served.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_retries_exhausted() {
+    let (url, served) = mock_feeder(vec![
+        response("502 Bad Gateway", &[], "first"),
+        response("502 Bad Gateway", &[], "second"),
+    ])
+    .await;
+
+    let endpoint = AsyncEndpoint::with_config(&url, fast_retry_config(1)).unwrap();
+
+    let err = endpoint.get_public_key(None).await.unwrap_err();
+
+    assert!(
+        matches!(&err, FeederError::Server { status: 502, body } if body == "second"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_no_retry_on_client_error() {
+    let (url, served) = mock_feeder(vec![response("400 Bad Request", &[], "bad block")]).await;
+
+    let endpoint = AsyncEndpoint::with_config(&url, fast_retry_config(3)).unwrap();
+
+    let err = endpoint.get_signature(Some(1)).await.unwrap_err();
+
+    assert!(
+        matches!(err, FeederError::UnexpectedStatus { status: 400, .. }),
+        "unexpected error: {err}"
+    );
+    assert_eq!(served.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_feeder_config_human_readable_durations() {
+    let config: FeederConfig = serde_json::from_value(serde_json::json!({
+        "request_timeout": "1m",
+        "initial_backoff": "250ms",
+    }))
+    .unwrap();
+
+    assert_eq!(config.request_timeout, Duration::from_secs(60));
+    assert_eq!(config.initial_backoff, Duration::from_millis(250));
+    assert_eq!(
+        config.connect_timeout,
+        FeederConfig::default().connect_timeout
+    );
+}
@@ -145,6 +145,26 @@ pub fn verify_starknet_merkle_proof(
     Err(StorageError::InvalidProof)
 }
 
+/// Computes the Starknet state root committed in the block header from the
+/// global contracts and classes tree roots.
+///
+/// When the classes tree is empty, the state root is the contracts tree root.
+pub fn compute_starknet_state_root<C: StarknetCryptoFunctions>(
+    crypto_lib: &C,
+    contracts_tree_root: Felt,
+    classes_tree_root: Felt,
+) -> Felt {
+    if classes_tree_root == Felt::ZERO {
+        contracts_tree_root
+    } else {
+        crypto_lib.poseidon_hash_many(&[
+            cairo_short_string_to_felt(GLOBAL_STATE_VERSION).unwrap(),
+            contracts_tree_root,
+            classes_tree_root,
+        ])
+    }
+}
+
 /// Validates a Starknet global contract state root against the state root.
 ///
 /// On success, returns the global contract state root.
@@ -155,15 +175,11 @@ pub fn verify_starknet_global_contract_root<C: StarknetCryptoFunctions>(
 ) -> Result<Felt, StorageError> {
     let global_roots = &storage_proof.global_roots;
 
-    let actual_state_root = if global_roots.classes_tree_root == Felt::ZERO {
-        global_roots.contracts_tree_root
-    } else {
-        crypto_lib.poseidon_hash_many(&[
-            cairo_short_string_to_felt(GLOBAL_STATE_VERSION).unwrap(),
-            global_roots.contracts_tree_root,
-            global_roots.classes_tree_root,
-        ])
-    };
+    let actual_state_root = compute_starknet_state_root(
+        crypto_lib,
+        global_roots.contracts_tree_root,
+        global_roots.classes_tree_root,
+    );
 
     if actual_state_root != state_root {
         return Err(StorageError::Generic(format!(
@@ -234,6 +250,16 @@ pub fn verify_starknet_storage_proof(
     contract_root: Felt,
     path: Felt,
     value: Felt,
+) -> Result<(), StorageError> {
+    verify_starknet_storage_proofs(storage_proof, contract_root, &[(path, value)])
+}
+
+/// Verifies many storage values of a contract against its storage root, using a single
+/// Starknet storage proof that contains the Merkle nodes for all of the paths.
+pub fn verify_starknet_storage_proofs(
+    storage_proof: &StorageProof,
+    contract_root: Felt,
+    entries: &[(Felt, Felt)],
 ) -> Result<(), StorageError> {
     if storage_proof.contracts_storage_proofs.len() != 1 {
         return Err(StorageError::Generic(format!(
@@ -247,8 +273,10 @@ pub fn verify_starknet_storage_proof(
         .first()
         .ok_or(StorageError::MissingContractStorageProof)?;
 
-    // Verify the value within the contract, with the Merkle proof for that contract.
-    verify_starknet_merkle_proof(contract_storage_proof, contract_root, path, value)?;
+    // Verify each value within the contract, with the Merkle proof for that contract.
+    for (path, value) in entries {
+        verify_starknet_merkle_proof(contract_storage_proof, contract_root, *path, *value)?;
+    }
 
     Ok(())
 }
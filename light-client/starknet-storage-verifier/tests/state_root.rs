@@ -0,0 +1,210 @@
+//! Regression tests for the state root committed in the block header against
+//! the global roots of a storage proof.
+//!
+//! Issue #468 disabled this check because the relayer always computed the state
+//! root as the Poseidon hash of the contracts and classes tree roots, while the
+//! verifier used the contracts tree root alone when the classes tree is empty.
+//! Both now use `compute_starknet_state_root`.
+//!
+//! The block fixtures under `tests/fixtures/state_root` are derived from the
+//! Sepolia block fixture of `starknet-block-verifier`, with the state root of
+//! `storage_proof_success.json` and one Starknet version per block hash
+//! version. Their block hashes are recomputed and signed with a test key.
+//!
+//! The signed blocks and storage proofs of Sepolia and mainnet, for each block
+//! hash version, are also fetched from the feeder gateway and the RPC endpoints by
+//! the ignored tests, which need network access:
+//!
+//! ```sh
+//! cargo test -p starknet-storage-verifier --test state_root -- --ignored
+//! ```
+//!
+//! The storage proofs of older blocks are only served by archive nodes, which can
+//! be set with `STARKNET_SEPOLIA_RPC` and `STARKNET_MAINNET_RPC`.
+
+use core::str::FromStr;
+
+use starknet_block_verifier::{
+    Block, Endpoint as FeederEndpoint, Signature, StarknetVersion, STARKNET_BLOCK_HASH0,
+    STARKNET_BLOCK_HASH1,
+};
+use starknet_core::types::{ConfirmedBlockId, Felt, StorageProof};
+use starknet_crypto_lib::StarknetCryptoLib;
+use starknet_macros::felt;
+use starknet_storage_verifier::endpoint::Endpoint as RpcEndpoint;
+use starknet_storage_verifier::verifier::{
+    compute_starknet_state_root, verify_starknet_global_contract_root,
+};
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &str) -> T {
+    let file = std::fs::File::open(path).expect("Failed to open file");
+    let reader = std::io::BufReader::new(file);
+    serde_json::from_reader(reader).expect("Failed to read JSON")
+}
+
+#[rstest::fixture]
+fn storage_proof() -> StorageProof {
+    read_json("tests/fixtures/storage_proof_success.json")
+}
+
+#[rstest::rstest]
+#[case::hash0("hash0", STARKNET_BLOCK_HASH0)]
+#[case::hash1("hash1", STARKNET_BLOCK_HASH1)]
+fn test_state_root_matches_block_header(
+    storage_proof: StorageProof,
+    #[case] fixture: &str,
+    #[case] hash_version: &[u8],
+) {
+    let block: Block = read_json(&format!("tests/fixtures/state_root/{fixture}/block.json"));
+    let signature: Signature = read_json(&format!(
+        "tests/fixtures/state_root/{fixture}/signature.json"
+    ));
+    let public_key: Felt = read_json(&format!(
+        "tests/fixtures/state_root/{fixture}/public_key.json"
+    ));
+
+    assert_eq!(block.hash_version(), hash_version);
+
+    assert!(block
+        .verify_signature(&StarknetCryptoLib, &signature, &public_key)
+        .unwrap());
+
+    assert_eq!(
+        compute_starknet_state_root(
+            &StarknetCryptoLib,
+            storage_proof.global_roots.contracts_tree_root,
+            storage_proof.global_roots.classes_tree_root,
+        ),
+        block.state_root
+    );
+
+    let global_contract_trie_root =
+        verify_starknet_global_contract_root(&StarknetCryptoLib, &storage_proof, block.state_root)
+            .unwrap();
+
+    assert_eq!(
+        global_contract_trie_root,
+        storage_proof.global_roots.contracts_tree_root
+    );
+}
+
+#[rstest::rstest]
+fn test_state_root_mismatch(mut storage_proof: StorageProof) {
+    let block: Block = read_json("tests/fixtures/state_root/hash1/block.json");
+
+    storage_proof.global_roots.classes_tree_root += Felt::ONE;
+
+    assert!(verify_starknet_global_contract_root(
+        &StarknetCryptoLib,
+        &storage_proof,
+        block.state_root
+    )
+    .is_err());
+}
+
+#[test]
+fn test_state_root_without_classes() {
+    let contracts_tree_root =
+        felt!("0x368991d64cd97e90a9da1fd9f3d676875d5d29b7136a6ecf77ddc35704f4c27");
+
+    assert_eq!(
+        compute_starknet_state_root(&StarknetCryptoLib, contracts_tree_root, Felt::ZERO),
+        contracts_tree_root
+    );
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Network {
+    Sepolia,
+    Mainnet,
+}
+
+impl Network {
+    fn feeder(self) -> FeederEndpoint {
+        match self {
+            Self::Sepolia => FeederEndpoint::sepolia(),
+            Self::Mainnet => FeederEndpoint::mainnet(),
+        }
+    }
+
+    fn rpc(self) -> RpcEndpoint {
+        let env = match self {
+            Self::Sepolia => "STARKNET_SEPOLIA_RPC",
+            Self::Mainnet => "STARKNET_MAINNET_RPC",
+        };
+
+        match (std::env::var(env), self) {
+            (Ok(url), _) => RpcEndpoint::new(url),
+            (Err(_), Self::Sepolia) => RpcEndpoint::sepolia(),
+            (Err(_), Self::Mainnet) => RpcEndpoint::mainnet(),
+        }
+    }
+}
+
+fn starknet_version(block: &Block) -> StarknetVersion {
+    StarknetVersion::from_str(&block.starknet_version).expect("Invalid Starknet version")
+}
+
+/// Finds the last block before the first block hashed with `STARKNET_BLOCK_HASH1`.
+fn last_hash0_block(feeder: &FeederEndpoint, latest_block: &Block) -> u64 {
+    let hash1_version = StarknetVersion::new(0, 13, 4);
+
+    let (mut low, mut high) = (0, latest_block.block_number);
+
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+
+        let block = feeder.get_block_header(Some(middle)).unwrap();
+
+        if starknet_version(&block) < hash1_version {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+#[rstest::rstest]
+#[case::sepolia_hash0(Network::Sepolia, STARKNET_BLOCK_HASH0)]
+#[case::sepolia_hash1(Network::Sepolia, STARKNET_BLOCK_HASH1)]
+#[case::mainnet_hash0(Network::Mainnet, STARKNET_BLOCK_HASH0)]
+#[case::mainnet_hash1(Network::Mainnet, STARKNET_BLOCK_HASH1)]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_state_root_matches_signed_block(
+    #[case] network: Network,
+    #[case] hash_version: &[u8],
+) {
+    let feeder = network.feeder();
+
+    let latest_block = feeder.get_block_header(None).unwrap();
+
+    let block_number = if hash_version == STARKNET_BLOCK_HASH0 {
+        last_hash0_block(&feeder, &latest_block)
+    } else {
+        latest_block.block_number
+    };
+
+    let block = feeder.get_block_header(Some(block_number)).unwrap();
+    let signature = feeder.get_signature(Some(block_number)).unwrap();
+    let public_key = feeder.get_public_key(Some(block_number)).unwrap();
+
+    assert_eq!(block.hash_version(), hash_version);
+
+    assert!(block
+        .verify_signature(&StarknetCryptoLib, &signature, &public_key)
+        .unwrap());
+
+    let storage_proof = network
+        .rpc()
+        .get_contract_proof(&[], ConfirmedBlockId::Number(block_number))
+        .await
+        .unwrap();
+
+    assert_eq!(storage_proof.global_roots.block_hash, block.block_hash);
+
+    verify_starknet_global_contract_root(&StarknetCryptoLib, &storage_proof, block.state_root)
+        .unwrap();
+}
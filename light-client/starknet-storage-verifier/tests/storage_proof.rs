@@ -1,11 +1,11 @@
-use starknet_core::types::StorageProof;
+use starknet_core::types::{BinaryNode, EdgeNode, MerkleNode, StorageProof};
 use starknet_crypto::{pedersen_hash, Felt};
 use starknet_crypto_lib::StarknetCryptoLib;
 use starknet_macros::felt;
 use starknet_storage_verifier::validate::validate_storage_proof;
 use starknet_storage_verifier::verifier::{
     verify_starknet_contract_proof, verify_starknet_global_contract_root,
-    verify_starknet_merkle_proof, verify_starknet_storage_proof,
+    verify_starknet_merkle_proof, verify_starknet_storage_proof, verify_starknet_storage_proofs,
 };
 use starknet_storage_verifier::StorageError;
 
@@ -115,3 +115,114 @@ fn test_verify_non_membership_proof() -> Result<(), Box<dyn core::error::Error>>
 
     Ok(())
 }
+
+#[test]
+fn test_verify_starknet_storage_proofs() -> Result<(), Box<dyn core::error::Error>> {
+    let storage_proof = serde_json::from_reader(std::fs::File::open(
+        "tests/fixtures/storage_proof_success.json",
+    )?)?;
+
+    let contract_address: Felt =
+        felt!("0x4017d0ad6ddbc7e97208e2639fc5bbf9856b4ede9a66a5995aec87b0d45837c");
+
+    let state_root = felt!("0x2bba45af2d71e57b1f82f1668bc53184762e6212c22e69f9949e3a607022fd2");
+
+    validate_storage_proof(&StarknetCryptoLib, &storage_proof)?;
+    let global_contract_trie_root =
+        verify_starknet_global_contract_root(&StarknetCryptoLib, &storage_proof, state_root)?;
+    let contract_root = verify_starknet_contract_proof(
+        &StarknetCryptoLib,
+        &storage_proof,
+        global_contract_trie_root,
+        contract_address,
+    )?;
+
+    // the edge node to the value at 0x0001 also proves that 0x0002 is not set
+    verify_starknet_storage_proofs(
+        &storage_proof,
+        contract_root,
+        &[
+            (felt!("0x0001"), felt!("0x9911")),
+            (felt!("0x0002"), Felt::ZERO),
+        ],
+    )?;
+
+    // a single invalid entry fails the whole batch
+    assert!(matches!(
+        verify_starknet_storage_proofs(
+            &storage_proof,
+            contract_root,
+            &[
+                (felt!("0x0001"), felt!("0x9911")),
+                (felt!("0x0002"), felt!("0x9911")),
+            ],
+        ),
+        Err(StorageError::MissingValue)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_starknet_storage_proofs_distinct_keys() -> Result<(), Box<dyn core::error::Error>> {
+    let mut storage_proof: StorageProof = serde_json::from_reader(std::fs::File::open(
+        "tests/fixtures/storage_proof_success.json",
+    )?)?;
+
+    // a storage trie with two values, one in each half of the tree
+    let left_path = felt!("0x0001");
+    let left_value = felt!("0x9911");
+    let right_path = felt!("0x0005");
+    let right_value = felt!("0x9922");
+
+    let left_key = left_path;
+    let right_key = Felt::TWO.pow(250u64) + right_path;
+
+    let edge_node = |path: Felt, child: Felt| {
+        (
+            pedersen_hash(&child, &path) + Felt::from(250),
+            MerkleNode::EdgeNode(EdgeNode {
+                path,
+                length: 250,
+                child,
+            }),
+        )
+    };
+
+    let (left, left_node) = edge_node(left_path, left_value);
+    let (right, right_node) = edge_node(right_path, right_value);
+
+    let contract_root = pedersen_hash(&left, &right);
+
+    storage_proof.contracts_storage_proofs = vec![[
+        (
+            contract_root,
+            MerkleNode::BinaryNode(BinaryNode { left, right }),
+        ),
+        (left, left_node),
+        (right, right_node),
+    ]
+    .into_iter()
+    .collect()];
+
+    verify_starknet_storage_proofs(
+        &storage_proof,
+        contract_root,
+        &[
+            (left_key, left_value),
+            (right_key, right_value),
+            (felt!("0x0003"), Felt::ZERO),
+        ],
+    )?;
+
+    assert!(matches!(
+        verify_starknet_storage_proofs(
+            &storage_proof,
+            contract_root,
+            &[(left_key, left_value), (right_key, left_value)],
+        ),
+        Err(StorageError::ChildNodeMismatchValue)
+    ));
+
+    Ok(())
+}
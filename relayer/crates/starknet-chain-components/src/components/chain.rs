@@ -107,13 +107,14 @@ mod preset {
 
     use crate::components::types::StarknetChainTypes;
     use crate::impls::{
-        BuildCreateCometClientMessage, BuildInvokeContractCall,
-        BuildStarknetChannelHandshakeMessages, BuildStarknetConnectionHandshakeMessages,
-        BuildStarknetCreateClientPayload, BuildStarknetIbcTransferMessage,
-        BuildStarknetPacketMessages, BuildStarknetUpdateClientPayload,
-        BuildStarknetUpgradeClientPayload, BuildUpdateCometClientMessage, CallStarknetContract,
-        CheckStarknetMisbehaviour, ConvertStarknetTokenAddressFromCosmos, DeclareSierraContract,
-        DeployStarknetContract, FilterStarknetPackets, GetContractAddressFromField,
+        AllocateStarknetNonceAndSendMessages, BuildCreateCometClientMessage,
+        BuildInvokeContractCall, BuildStarknetChannelHandshakeMessages,
+        BuildStarknetConnectionHandshakeMessages, BuildStarknetCreateClientPayload,
+        BuildStarknetIbcTransferMessage, BuildStarknetPacketMessages,
+        BuildStarknetUpdateClientPayload, BuildStarknetUpgradeClientPayload,
+        BuildUpdateCometClientMessage, CallStarknetContract, CheckStarknetMisbehaviour,
+        ConvertStarknetTokenAddressFromCosmos, DeclareSierraContract, DeployStarknetContract,
+        FilterStarknetPackets, GetContractAddressFromField, GetCosmosTokenDenomOnStarknet,
         GetCounterpartyCosmosHeightFromStarknetMessage, GetOrCreateCosmosTokenAddressOnStarknet,
         GetStarknetBlockEvents, GetStarknetCommitmentPrefix, IbcTransferTimeoutAfterSeconds,
         InvokeStarknetContract, ProvideFeltSelector, ProvideNoCreateClientMessageOptionsOverride,
@@ -123,21 +124,27 @@ mod preset {
         ProvideTokenAddressDenom, QueryChannelEndFromStarknet, QueryCometClientState,
         QueryCometConsensusState, QueryConnectionEndFromStarknet,
         QueryCosmosChainIdFromStarknetChannelId, QueryErc20TokenBalance,
-        QueryPacketIsReceivedOnStarknet, QueryStarknetAckCommitment, QueryStarknetBlock,
-        QueryStarknetChainStatus, QueryStarknetClientStatus, QueryStarknetNonce,
-        QueryStarknetPacketCommitment, QueryStarknetPacketReceipt, QueryStarknetWalletBalance,
+        QueryPacketIsReceivedOnStarknet, QueryStarknetAckCommitment,
+        QueryStarknetBatchedCommitmentProof, QueryStarknetBlock,
+        QueryStarknetBlockEventsFromSubscription, QueryStarknetBlockEventsInRange,
+        QueryStarknetChainStatus, QueryStarknetChannels, QueryStarknetClientStatus,
+        QueryStarknetNonce, QueryStarknetPacketCommitment, QueryStarknetPacketReceipt,
+        QueryStarknetPacketSequences, QueryStarknetSendPacketEvents, QueryStarknetWalletBalance,
         QueryTransactionReceipt, ReadPacketSrcStarknetFields, RecoverStarknetClient,
-        SendStarknetMessages, StarknetAddress, StarknetMessage, StarknetMisbehaviourMessageBuilder,
-        TransferErc20Token, UseStarknetCommitmentProof, UseStarknetContractTypes,
-        UseStarknetEvents, UseU256Amount,
+        SendMessagesWithStarknetSignerPool, SendStarknetMessages, StarknetAddress, StarknetMessage,
+        StarknetMisbehaviourMessageBuilder, TransferErc20Token, UseStarknetCommitmentProof,
+        UseStarknetContractTypes, UseStarknetEvents, UseU256Amount,
     };
     use crate::traits::{
-        BlobTypeProviderComponent, ContractAddressQuerierComponent, ContractCallerComponent,
+        BatchedCommitmentProofQuerierComponent, BlobTypeProviderComponent,
+        BlockEventsInRangeQuerierComponent, ChannelsQuerierComponent,
+        ContractAddressQuerierComponent, ContractCallerComponent,
         ContractClassHashTypeProviderComponent, ContractClassTypeProviderComponent,
         ContractDeclarerComponent, ContractDeployerComponent, ContractInvokerComponent,
-        CosmosTokenAddressOnStarknetQuerierComponent, InvokeContractMessageBuilderComponent,
-        SelectorTypeComponent, TokenBalanceQuerierComponent, TokenTransferComponent,
-        TransferTokenMessageBuilderComponent,
+        CosmosTokenAddressOnStarknetQuerierComponent, CosmosTokenDenomOnStarknetQuerierComponent,
+        InvokeContractMessageBuilderComponent, PacketSequencesQuerierComponent,
+        SelectorTypeComponent, SendPacketEventsQuerierComponent, TokenBalanceQuerierComponent,
+        TokenTransferComponent, TransferTokenMessageBuilderComponent,
     };
     use crate::types::{
         BuildTransferErc20TokenMessage, StarknetEvent, StarknetWallet, TxResponse,
@@ -201,6 +208,8 @@ mod preset {
                 ConvertStarknetTokenAddressFromCosmos,
             CosmosTokenAddressOnStarknetQuerierComponent:
                 GetOrCreateCosmosTokenAddressOnStarknet,
+            CosmosTokenDenomOnStarknetQuerierComponent:
+                GetCosmosTokenDenomOnStarknet,
             TxHashTypeProviderComponent:
                 UseType<Felt>,
             TxResponseTypeProviderComponent:
@@ -292,16 +301,19 @@ mod preset {
                 RetryQueryBlockEvents<
                     5,
                     WaitBlockHeightAndQueryEvents<
-                        GetStarknetBlockEvents
-                    >>,
+                        QueryStarknetBlockEventsFromSubscription<
+                            GetStarknetBlockEvents
+                        >>>,
             [
                 MessagesWithSignerAndNonceSenderComponent,
                 TxMessageResponseParserComponent,
             ]:
                 SendStarknetMessages,
+            MessageSenderComponent:
+                SendMessagesWithStarknetSignerPool,
+            MessagesWithSignerSenderComponent:
+                AllocateStarknetNonceAndSendMessages,
             [
-                MessageSenderComponent,
-                MessagesWithSignerSenderComponent,
                 NonceAllocatorComponent,
                 TxResponsePollerComponent,
             ]:
@@ -458,12 +470,22 @@ mod preset {
                 ChannelEndWithProofsQuerierComponent,
             ]:
                 QueryChannelEndFromStarknet,
+            ChannelsQuerierComponent:
+                QueryStarknetChannels,
             PacketCommitmentQuerierComponent:
                 QueryStarknetPacketCommitment,
+            PacketSequencesQuerierComponent:
+                QueryStarknetPacketSequences,
+            SendPacketEventsQuerierComponent:
+                QueryStarknetSendPacketEvents,
             PacketAckCommitmentQuerierComponent:
                 QueryStarknetAckCommitment,
             PacketReceiptQuerierComponent:
                 QueryStarknetPacketReceipt,
+            BatchedCommitmentProofQuerierComponent:
+                QueryStarknetBatchedCommitmentProof,
+            BlockEventsInRangeQuerierComponent:
+                QueryStarknetBlockEventsInRange,
             [
                 OutgoingPacketFilterComponent,
                 IncomingPacketFilterComponent,
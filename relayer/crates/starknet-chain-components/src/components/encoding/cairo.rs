@@ -35,7 +35,7 @@ mod preset {
 
     use crate::impls::StarknetAddress;
     use crate::types::{
-        AckStatus, Acknowledgement, BasePrefix, CairoStarknetClientState,
+        AckStatus, Acknowledgement, AttestationMode, BasePrefix, CairoStarknetClientState,
         CairoStarknetConsensusState, ChannelEnd, ClientId, ClientMessage, ClientStatus,
         CometClientState, CometConsensusState, ConnectionCounterparty, ConnectionEnd,
         ConnectionState, ConnectionVersion, CreateClientResponse, Denom, DeployErc20TokenMessage,
@@ -183,6 +183,7 @@ mod preset {
                 (ViaCairo, AckStatus),
                 (ViaCairo, Participant),
                 (ViaCairo, ClientStatus),
+                (ViaCairo, AttestationMode),
                 (ViaCairo, RawChannelState),
                 (ViaCairo, ClientMessage),
             ]:
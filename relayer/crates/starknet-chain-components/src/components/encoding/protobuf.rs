@@ -28,6 +28,7 @@ mod preset {
     use ibc::primitives::Timestamp;
     use ibc_client_starknet_types::encoding::components::StarknetLightClientEncodingComponents;
     use ibc_client_starknet_types::header::StarknetHeader;
+    use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
     use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
     use prost_types::Any;
 
@@ -79,11 +80,13 @@ mod preset {
                 (ViaProtobuf, StarknetConsensusState),
                 (ViaProtobuf, StarknetHeader),
                 (ViaProtobuf, StarknetMisbehaviour),
+                (ViaProtobuf, StarknetSequencerKeyRotation),
 
                 (ViaAny, StarknetClientState),
                 (ViaAny, StarknetConsensusState),
                 (ViaAny, StarknetHeader),
                 (ViaAny, StarknetMisbehaviour),
+                (ViaAny, StarknetSequencerKeyRotation),
             ]:
                 StarknetLightClientEncodingComponents::Provider,
 
@@ -117,6 +120,7 @@ mod preset {
                 (ViaProtobuf, StarknetConsensusState),
                 (ViaProtobuf, StarknetHeader),
                 (ViaProtobuf, StarknetMisbehaviour),
+                (ViaProtobuf, StarknetSequencerKeyRotation),
                 (ViaProtobuf, CommitmentRoot),
                 (ViaProtobuf, Timestamp),
             ]:
@@ -129,10 +133,16 @@ mod preset {
             (ClientMessage, Any): EncodeAsAnyProtobuf<ViaProtobuf, UseContext>,
             (Any, ClientMessage): DecodeAsAnyProtobuf<ViaProtobuf, UseContext>,
 
-            (StarknetHeader, Any):
+            [
+                (StarknetHeader, Any),
+                (StarknetSequencerKeyRotation, Any),
+            ]:
                 EncodeViaClientMessage,
 
-            (Any, StarknetHeader):
+            [
+                (Any, StarknetHeader),
+                (Any, StarknetSequencerKeyRotation),
+            ]:
                 DecodeViaClientMessage,
 
             [
@@ -176,6 +186,7 @@ mod preset {
                 StarknetConsensusState,
                 StarknetHeader,
                 StarknetMisbehaviour,
+                StarknetSequencerKeyRotation,
             ]:
                 StarknetLightClientEncodingComponents::Provider,
 
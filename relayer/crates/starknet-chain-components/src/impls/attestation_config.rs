@@ -0,0 +1,20 @@
+use core::marker::PhantomData;
+
+use hermes_prelude::*;
+
+use crate::impls::{StarknetAttestationConfig, StarknetChainConfig};
+use crate::traits::{AttestationConfigGetter, AttestationConfigGetterComponent};
+
+#[cgp_new_provider(AttestationConfigGetterComponent)]
+impl<Chain> AttestationConfigGetter<Chain> for GetStarknetAttestationConfig
+where
+    Chain: HasField<symbol!("chain_config"), Value = StarknetChainConfig>,
+{
+    fn attestation_config(chain: &Chain) -> StarknetAttestationConfig {
+        chain
+            .get_field(PhantomData)
+            .attestation
+            .clone()
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,369 @@
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+
+use hermes_prelude::*;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::{FeeEstimate, Felt, ResourceBounds};
+
+use crate::impls::{StarknetChainConfig, StarknetFeeConfig, StarknetResourceLimits};
+use crate::traits::{FeeConfigGetter, FeeConfigGetterComponent};
+
+/// Number of FRI in one STRK.
+pub const FRI_PER_STRK: u128 = 1_000_000_000_000_000_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StarknetResourceBounds {
+    pub l1_gas: ResourceBounds,
+    pub l1_data_gas: ResourceBounds,
+    pub l2_gas: ResourceBounds,
+}
+
+pub enum StarknetFeeError {
+    InvalidEstimate(String),
+    MaxAmount {
+        resource: &'static str,
+        estimated_amount: u64,
+        max_amount: u64,
+    },
+    MaxPricePerUnit {
+        resource: &'static str,
+        estimated_price: u128,
+        max_price_per_unit: u128,
+    },
+    MaxFee {
+        max_transaction_fee: u128,
+        max_fee: u128,
+    },
+}
+
+#[cgp_new_provider(FeeConfigGetterComponent)]
+impl<Chain> FeeConfigGetter<Chain> for GetStarknetFeeConfig
+where
+    Chain: HasField<symbol!("chain_config"), Value = StarknetChainConfig>,
+{
+    fn fee_config(chain: &Chain) -> StarknetFeeConfig {
+        chain
+            .get_field(PhantomData)
+            .fee_config
+            .clone()
+            .unwrap_or_default()
+    }
+}
+
+impl StarknetResourceBounds {
+    /// The maximum fee that can be charged for a transaction with these bounds, in FRI.
+    pub fn max_transaction_fee(&self) -> u128 {
+        [&self.l1_gas, &self.l1_data_gas, &self.l2_gas]
+            .into_iter()
+            .map(|bounds| u128::from(bounds.max_amount).saturating_mul(bounds.max_price_per_unit))
+            .fold(0, u128::saturating_add)
+    }
+}
+
+/// Derives the resource bounds of a transaction from its fee estimate.
+///
+/// Returns an error if the estimated amount or price of any resource is above its configured
+/// maximum, or if the resulting maximum transaction fee is above `max_fee`.
+pub fn compute_resource_bounds(
+    config: &StarknetFeeConfig,
+    estimate: &FeeEstimate,
+) -> Result<StarknetResourceBounds, StarknetFeeError> {
+    let resource_bounds = |resource: &'static str,
+                           limits: &StarknetResourceLimits,
+                           amount: &Felt,
+                           price: &Felt|
+     -> Result<ResourceBounds, StarknetFeeError> {
+        let estimated_amount = u64::try_from(*amount).map_err(|_| {
+            StarknetFeeError::InvalidEstimate(format!(
+                "estimated {resource} amount out of range: {amount}"
+            ))
+        })?;
+
+        let estimated_price = u128::try_from(*price).map_err(|_| {
+            StarknetFeeError::InvalidEstimate(format!(
+                "estimated {resource} price out of range: {price}"
+            ))
+        })?;
+
+        if let Some(max_amount) = limits.max_amount {
+            if estimated_amount > max_amount {
+                return Err(StarknetFeeError::MaxAmount {
+                    resource,
+                    estimated_amount,
+                    max_amount,
+                });
+            }
+        }
+
+        if let Some(max_price_per_unit) = limits.max_price_per_unit {
+            if estimated_price > max_price_per_unit {
+                return Err(StarknetFeeError::MaxPricePerUnit {
+                    resource,
+                    estimated_price,
+                    max_price_per_unit,
+                });
+            }
+        }
+
+        let max_amount = ((estimated_amount as f64) * config.gas_multiplier) as u64;
+
+        let max_price_per_unit = ((estimated_price as f64) * config.gas_price_multiplier) as u128;
+
+        Ok(ResourceBounds {
+            max_amount: limits
+                .max_amount
+                .map_or(max_amount, |limit| max_amount.min(limit)),
+            max_price_per_unit: limits
+                .max_price_per_unit
+                .map_or(max_price_per_unit, |limit| max_price_per_unit.min(limit)),
+        })
+    };
+
+    let l1_gas = resource_bounds(
+        "L1 gas",
+        &config.l1_gas,
+        &estimate.l1_gas_consumed,
+        &estimate.l1_gas_price,
+    )?;
+
+    let l1_data_gas = resource_bounds(
+        "L1 data gas",
+        &config.l1_data_gas,
+        &estimate.l1_data_gas_consumed,
+        &estimate.l1_data_gas_price,
+    )?;
+
+    let l2_gas = resource_bounds(
+        "L2 gas",
+        &config.l2_gas,
+        &estimate.l2_gas_consumed,
+        &estimate.l2_gas_price,
+    )?;
+
+    let bounds = StarknetResourceBounds {
+        l1_gas,
+        l1_data_gas,
+        l2_gas,
+    };
+
+    if let Some(StrkAmount(max_fee)) = config.max_fee {
+        let max_transaction_fee = bounds.max_transaction_fee();
+
+        if max_transaction_fee > max_fee {
+            return Err(StarknetFeeError::MaxFee {
+                max_transaction_fee,
+                max_fee,
+            });
+        }
+    }
+
+    Ok(bounds)
+}
+
+/// An amount of STRK in FRI, written in configs as a decimal amount of STRK such as `"2.5"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct StrkAmount(pub u128);
+
+impl TryFrom<String> for StrkAmount {
+    type Error = String;
+
+    fn try_from(amount: String) -> Result<Self, String> {
+        parse_strk_amount(&amount).map(Self)
+    }
+}
+
+impl From<StrkAmount> for String {
+    fn from(amount: StrkAmount) -> Self {
+        amount.to_string()
+    }
+}
+
+impl Display for StrkAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let integer = self.0 / FRI_PER_STRK;
+        let fraction = self.0 % FRI_PER_STRK;
+
+        if fraction == 0 {
+            write!(f, "{integer}")
+        } else {
+            let fraction = format!("{fraction:018}");
+
+            write!(f, "{integer}.{}", fraction.trim_end_matches('0'))
+        }
+    }
+}
+
+/// Parses a decimal amount of STRK, such as `2.5`, into FRI.
+pub fn parse_strk_amount(amount: &str) -> Result<u128, String> {
+    let invalid_amount = || format!("invalid STRK amount `{amount}`");
+
+    let (integer, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+
+    if (integer.is_empty() && fraction.is_empty())
+        || fraction.len() > 18
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid_amount());
+    }
+
+    let parse = |digits: &str| -> Result<u128, String> {
+        if digits.is_empty() {
+            Ok(0)
+        } else {
+            digits.parse().map_err(|_| invalid_amount())
+        }
+    };
+
+    let fraction_fri = parse(fraction)? * 10u128.pow(18 - fraction.len() as u32);
+
+    parse(integer)?
+        .checked_mul(FRI_PER_STRK)
+        .and_then(|fri| fri.checked_add(fraction_fri))
+        .ok_or_else(invalid_amount)
+}
+
+impl Debug for StarknetFeeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidEstimate(message) => write!(f, "invalid Starknet fee estimate: {message}"),
+            Self::MaxAmount {
+                resource,
+                estimated_amount,
+                max_amount,
+            } => write!(
+                f,
+                "refusing to submit transaction: estimated {resource} amount {estimated_amount} is above the maximum of {max_amount}"
+            ),
+            Self::MaxPricePerUnit {
+                resource,
+                estimated_price,
+                max_price_per_unit,
+            } => write!(
+                f,
+                "refusing to submit transaction: estimated {resource} price {estimated_price} FRI is above the maximum of {max_price_per_unit} FRI"
+            ),
+            Self::MaxFee {
+                max_transaction_fee,
+                max_fee,
+            } => write!(
+                f,
+                "refusing to submit transaction: maximum transaction fee {max_transaction_fee} FRI is above the fee cap of {max_fee} FRI"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use starknet::core::types::PriceUnit;
+
+    use super::*;
+
+    fn estimate() -> FeeEstimate {
+        FeeEstimate {
+            l1_gas_consumed: Felt::from(0u64),
+            l1_gas_price: Felt::from(30_000_000_000_000u64),
+            l2_gas_consumed: Felt::from(1_000_000u64),
+            l2_gas_price: Felt::from(10_000_000_000u64),
+            l1_data_gas_consumed: Felt::from(200u64),
+            l1_data_gas_price: Felt::from(1_000u64),
+            overall_fee: Felt::from(10_000_000_200_000u64),
+            unit: PriceUnit::Fri,
+        }
+    }
+
+    #[test]
+    fn test_parse_strk_amount() {
+        assert_eq!(parse_strk_amount("1"), Ok(FRI_PER_STRK));
+        assert_eq!(
+            parse_strk_amount("2.5"),
+            Ok(2 * FRI_PER_STRK + FRI_PER_STRK / 2)
+        );
+        assert_eq!(parse_strk_amount("0.000000000000000001"), Ok(1));
+        assert_eq!(parse_strk_amount(".5"), Ok(FRI_PER_STRK / 2));
+        assert!(parse_strk_amount("").is_err());
+        assert!(parse_strk_amount("1.0000000000000000001").is_err());
+        assert!(parse_strk_amount("-1").is_err());
+        assert!(parse_strk_amount("1e18").is_err());
+    }
+
+    #[test]
+    fn test_strk_amount_display() {
+        for amount in ["0", "1", "2.5", "0.000000000000000001", "10.01"] {
+            assert_eq!(
+                StrkAmount::try_from(amount.to_string())
+                    .unwrap()
+                    .to_string(),
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_resource_bounds() {
+        let config = StarknetFeeConfig {
+            gas_multiplier: 2.0,
+            gas_price_multiplier: 1.5,
+            l2_gas: StarknetResourceLimits {
+                max_amount: Some(1_500_000),
+                max_price_per_unit: None,
+            },
+            ..Default::default()
+        };
+
+        let bounds = compute_resource_bounds(&config, &estimate()).unwrap();
+
+        assert_eq!(
+            bounds.l2_gas,
+            ResourceBounds {
+                max_amount: 1_500_000,
+                max_price_per_unit: 15_000_000_000,
+            }
+        );
+        assert_eq!(
+            bounds.l1_data_gas,
+            ResourceBounds {
+                max_amount: 400,
+                max_price_per_unit: 1_500,
+            }
+        );
+        assert_eq!(bounds.max_transaction_fee(), 22_500_000_000_600_000);
+    }
+
+    #[test]
+    fn test_fee_caps() {
+        let config = StarknetFeeConfig {
+            l2_gas: StarknetResourceLimits {
+                max_amount: None,
+                max_price_per_unit: Some(5_000_000_000),
+            },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            compute_resource_bounds(&config, &estimate()),
+            Err(StarknetFeeError::MaxPricePerUnit { .. })
+        ));
+
+        let config = StarknetFeeConfig {
+            max_fee: Some(StrkAmount(FRI_PER_STRK / 100)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            compute_resource_bounds(&config, &estimate()),
+            Err(StarknetFeeError::MaxFee { .. })
+        ));
+
+        let config = StarknetFeeConfig {
+            max_fee: Some(StrkAmount(FRI_PER_STRK / 10)),
+            ..Default::default()
+        };
+
+        assert!(compute_resource_bounds(&config, &estimate()).is_ok());
+    }
+}
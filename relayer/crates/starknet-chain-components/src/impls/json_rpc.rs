@@ -1,10 +1,13 @@
+use std::time::Instant;
+
 use hermes_core::logging_components::traits::CanLog;
 use hermes_core::logging_components::types::LevelTrace;
 use hermes_prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::traits::{
-    HasJsonRpcUrl, HasReqwestClient, JsonRpcRequestSender, JsonRpcRequestSenderComponent,
+    HasJsonRpcUrl, HasReqwestClient, HasStarknetMetrics, JsonRpcRequestSender,
+    JsonRpcRequestSenderComponent,
 };
 
 #[cgp_new_provider(JsonRpcRequestSenderComponent)]
@@ -13,6 +16,7 @@ impl<Context, Request, Response> JsonRpcRequestSender<Context, Request, Response
 where
     Context: HasReqwestClient
         + HasJsonRpcUrl
+        + HasStarknetMetrics
         + CanLog<LevelTrace>
         + CanRaiseAsyncError<ureq::Error>
         + CanRaiseAsyncError<serde_json::Error>
@@ -46,12 +50,28 @@ where
             .post(context.json_rpc_url().as_str())
             .header("Content-Type", "application/json");
 
-        let mut response = request.send(request_string).map_err(Context::raise_error)?;
+        let start = Instant::now();
+
+        let response_string = request
+            .send(request_string)
+            .and_then(|mut response| response.body_mut().read_to_string());
+
+        let duration = start.elapsed();
+
+        let response_string = match response_string {
+            Ok(response_string) => response_string,
+            Err(e) => {
+                context
+                    .metrics()
+                    .record_rpc_request(method, duration, false);
+
+                return Err(Context::raise_error(e));
+            }
+        };
 
-        let response_string = response
-            .body_mut()
-            .read_to_string()
-            .map_err(Context::raise_error)?;
+        context
+            .metrics()
+            .record_rpc_response_size(method, response_string.len());
 
         context
             .log(
@@ -60,8 +80,22 @@ where
             )
             .await;
 
-        let rpc_response: JsonRpcResponse<Response> =
-            serde_json::from_str(&response_string).map_err(Context::raise_error)?;
+        let rpc_response: Result<JsonRpcResponse<Response>, _> =
+            serde_json::from_str(&response_string);
+
+        context.metrics().record_rpc_request(
+            method,
+            duration,
+            matches!(
+                rpc_response,
+                Ok(JsonRpcResponse {
+                    data: ResponseData::Result(_),
+                    ..
+                })
+            ),
+        );
+
+        let rpc_response = rpc_response.map_err(Context::raise_error)?;
 
         match rpc_response.data {
             ResponseData::Error(err) => {
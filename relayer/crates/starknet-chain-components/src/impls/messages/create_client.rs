@@ -1,6 +1,7 @@
 use core::marker::PhantomData;
 
-use attestator::AttestatorClient;
+use attestator::AsyncAttestatorClient;
+use futures::future::try_join_all;
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
@@ -15,7 +16,7 @@ use starknet::core::types::Felt;
 use starknet::macros::{selector, short_string};
 
 use crate::impls::{from_vec_u8_to_be_u32_slice, StarknetAddress, StarknetMessage};
-use crate::traits::{CanQueryContractAddress, HasEd25519AttestatorAddresses};
+use crate::traits::{CanQueryContractAddress, HasAttestationConfig, HasEd25519AttestatorAddresses};
 use crate::types::{ClientStatus, CometClientState, CometConsensusState, Height};
 
 pub struct BuildCreateCometClientMessage;
@@ -30,10 +31,11 @@ where
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasEd25519AttestatorAddresses
+        + HasAttestationConfig
         + CanRaiseAsyncError<String>
         + CanRaiseAsyncError<&'static str>
         + CanRaiseAsyncError<core::num::TryFromIntError>
-        + CanRaiseAsyncError<ureq::Error>
+        + CanRaiseAsyncError<reqwest::Error>
         + CanRaiseAsyncError<Encoding::Error>,
     Counterparty:
         HasCreateClientPayloadType<Chain, CreateClientPayload = CosmosCreateClientPayload>,
@@ -68,11 +70,15 @@ where
             .ok_or("No Ed25519 attestators")
             .map_err(Chain::raise_error)?;
 
-        let attestator_keys = ed25519_attestator_addresses
-            .iter()
-            .map(|addr| AttestatorClient(addr.as_ref()).get_public_key())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Chain::raise_error)?;
+        let http_client = reqwest::Client::new();
+
+        let attestator_keys = try_join_all(
+            ed25519_attestator_addresses
+                .iter()
+                .map(|addr| AsyncAttestatorClient::new(&http_client, addr).get_public_key()),
+        )
+        .await
+        .map_err(Chain::raise_error)?;
 
         let client_state = CometClientState {
             latest_height: height,
@@ -86,6 +92,8 @@ where
             upgrade_path: payload.client_state.upgrade_path,
             attestator_keys,
             attestator_quorum_percentage: 50, // hardcoded to 50%
+            attestation_mode: chain.attestation_config().mode,
+            attestator_key_epoch: chain.attestation_config().key_epoch,
         };
 
         let consensus_state = CometConsensusState {
@@ -1,10 +1,13 @@
 use core::marker::PhantomData;
 
-use attestator::{AttestatorClient, Ed25519};
+use attestator::{
+    AsyncAttestatorClient, AttestationDomain, Ed25519, ATTESTATION_DOMAIN, ATTESTATION_VERSION,
+};
+use futures::stream::{FuturesUnordered, StreamExt};
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
-    CanQueryClientStateWithLatestHeight, HasClientIdType, HasClientStateType,
+    CanQueryChainHeight, CanQueryClientStateWithLatestHeight, HasClientIdType, HasClientStateType,
     HasCreateClientMessageOptionsType, HasMessageType, HasUpdateClientPayloadType,
     UpdateClientMessageBuilder, UpdateClientMessageBuilderComponent,
 };
@@ -15,6 +18,7 @@ use hermes_core::logging_components::types::{LevelDebug, LevelWarn};
 use hermes_cosmos_core::chain_components::types::CosmosUpdateClientPayload;
 use hermes_prelude::*;
 use ibc::clients::tendermint::types::Header;
+use ibc::core::host::types::identifiers::ChainId;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawHeader;
 use ibc_proto::Protobuf;
 use num_bigint::BigUint;
@@ -25,8 +29,11 @@ use tendermint::block::CommitSig;
 use tendermint::vote::{SignedVote, ValidatorIndex, Vote};
 
 use crate::impls::{StarknetAddress, StarknetMessage};
-use crate::traits::{CanQueryContractAddress, HasEd25519AttestatorAddresses};
-use crate::types::{ClientId, ClientMessage, CometClientState};
+use crate::traits::{
+    CanQueryContractAddress, HasAttestationConfig, HasAttestatorHttpClient,
+    HasEd25519AttestatorAddresses, HasStarknetMetrics,
+};
+use crate::types::{time_request, AttestationMode, ClientId, ClientMessage, CometClientState};
 
 pub struct BuildUpdateCometClientMessage;
 
@@ -41,9 +48,12 @@ where
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + CanQueryClientStateWithLatestHeight<Counterparty>
+        + CanQueryChainHeight<Height = u64>
         + CanLog<LevelWarn>
         + CanLog<LevelDebug>
         + HasEd25519AttestatorAddresses
+        + HasAttestationConfig
+        + HasAttestatorHttpClient
         + CanRaiseAsyncError<&'static str>
         + CanRaiseAsyncError<Encoding::Error>,
     Counterparty: HasClientStateType<Chain, ClientState = CometClientState>
@@ -104,14 +114,25 @@ where
 
             let attestator_quorum_percentage = client_state.attestator_quorum_percentage;
 
+            let attestation_domain = build_attestation_domain(
+                chain,
+                client_id,
+                &client_state.chain_id,
+                client_state.attestation_mode,
+                client_state.attestator_key_epoch,
+            )
+            .await?;
+
             let signature_hints = comet_signature_hints(
                 chain,
                 &header,
                 encoding,
                 ed25519_attestator_addresses,
+                &attestator_keys,
                 attestator_quorum_percentage,
+                attestation_domain.as_ref(),
             )
-            .await;
+            .await?;
 
             let serialized_signature_hints = encoding
                 .encode(&signature_hints)
@@ -140,15 +161,52 @@ where
     }
 }
 
+/// Builds the domain that the attestations used to update the client `client_id`
+/// are bound to, for the attestator keys of `key_epoch` trusted by the client and
+/// expiring `expiry_blocks` after the current Starknet height.
+/// Returns `None` for clients in the legacy attestation mode.
+pub async fn build_attestation_domain<Chain>(
+    chain: &Chain,
+    client_id: &ClientId,
+    counterparty_chain_id: &ChainId,
+    attestation_mode: AttestationMode,
+    key_epoch: u64,
+) -> Result<Option<AttestationDomain>, Chain::Error>
+where
+    Chain: HasAttestationConfig + CanQueryChainHeight<Height = u64>,
+{
+    if attestation_mode == AttestationMode::Legacy {
+        return Ok(None);
+    }
+
+    let config = chain.attestation_config();
+
+    let height = chain.query_chain_height().await?;
+
+    Ok(Some(AttestationDomain {
+        counterparty_chain_id: counterparty_chain_id.to_string(),
+        client_id: client_id.to_string(),
+        key_epoch,
+        expiry_height: height.saturating_add(config.expiry_blocks),
+    }))
+}
+
 pub async fn comet_signature_hints<Chain, Encoding>(
     chain: &Chain,
     header: &Header,
     encoding: &Encoding,
     attestator_addresses: &[String],
+    attestator_keys: &[Felt],
     attestator_quorum_percentage: usize,
-) -> Vec<Vec<Felt>>
+    attestation_domain: Option<&AttestationDomain>,
+) -> Result<Vec<Vec<Felt>>, Chain::Error>
 where
-    Chain: CanLog<LevelWarn> + CanLog<LevelDebug>,
+    Chain: HasStarknetMetrics
+        + HasAttestationConfig
+        + HasAttestatorHttpClient
+        + CanLog<LevelWarn>
+        + CanLog<LevelDebug>
+        + CanRaiseAsyncError<Encoding::Error>,
     Encoding: HasEncodedType<Encoded = Vec<Felt>>
         + CanDecode<ViaCairo, Product![Product![U256, U256, U256, Vec<u8>], Vec<Felt>, U256, U256]>
         + CanEncode<ViaCairo, Product![Vec<Felt>, U256, U256]>
@@ -163,9 +221,7 @@ where
         .map(|v| (v.address, v.pub_key))
         .collect();
 
-    let mut hints: Vec<Vec<Felt>> = Vec::new();
-
-    for value in signed_header
+    let challenges: Vec<Option<Ed25519>> = signed_header
         .commit
         .signatures
         .iter()
@@ -210,29 +266,37 @@ where
 
             let ed25519_public_key: [u8; 32] = ed25519_public_key.as_bytes().try_into().ok()?;
 
-            Some((msg, signature, ed25519_public_key))
+            Some(Ed25519 {
+                message: msg,
+                signature,
+                public_key: ed25519_public_key,
+            })
         })
-    {
-        if let Some((msg, signature, public_key)) = value {
-            hints.push(
-                compute_attestator_hints(
-                    chain,
-                    encoding,
-                    attestator_addresses,
-                    attestator_quorum_percentage,
-                    &msg,
-                    &signature,
-                    &public_key,
-                )
-                .await,
-            );
-        } else {
-            // only return hints for the valid signatures
-            hints.push(vec![]);
-        }
-    }
+        .collect();
 
-    hints
+    // All valid signatures of the header are sent to each attestator as a single batch.
+    let batch: Vec<Ed25519> = challenges.iter().flatten().cloned().collect();
+
+    let mut attestations = compute_attestator_hints(
+        chain,
+        encoding,
+        attestator_addresses,
+        attestator_keys,
+        attestator_quorum_percentage,
+        attestation_domain,
+        &batch,
+    )
+    .await?
+    .into_iter();
+
+    Ok(challenges
+        .iter()
+        .map(|challenge| match challenge {
+            Some(_) => attestations.next().unwrap_or_default(),
+            // only return hints for the valid signatures
+            None => vec![],
+        })
+        .collect())
 }
 
 pub fn compute_garaga_hints<Encoding>(
@@ -278,69 +342,144 @@ where
         .unwrap()
 }
 
+/// Queries all the attestators concurrently, sending each of them the full
+/// batch of challenges, and returns one encoded hint per challenge.
+///
+/// With an attestation domain, each hint is prefixed with the domain tag, the
+/// payload version and the expiry height, which the Comet client
+/// needs to rebuild the signed payload.
+///
+/// The responses are collected in a random order until the attestator quorum
+/// is reached for every challenge. Attestators that fail, or that do not return exactly one
+/// signature per challenge, or that sign with a key which is not one of the
+/// `attestator_keys` trusted by the client, are ignored so that the attestator network stays
+/// fault-tolerant. When an attestator rejects some of the Ed25519 signatures,
+/// its attestations of the other challenges are still used.
 pub async fn compute_attestator_hints<Chain, Encoding>(
     chain: &Chain,
     encoding: &Encoding,
     attestator_addresses: &[String],
+    attestator_keys: &[Felt],
     attestator_quorum_percentage: usize,
-    msg: &[u8],
-    signature: &[u8; 64],
-    public_key: &[u8; 32],
-) -> Vec<Felt>
+    attestation_domain: Option<&AttestationDomain>,
+    challenges: &[Ed25519],
+) -> Result<Vec<Vec<Felt>>, Chain::Error>
 where
-    Chain: CanLog<LevelWarn> + CanLog<LevelDebug>,
+    Chain: HasStarknetMetrics
+        + HasAttestationConfig
+        + HasAttestatorHttpClient
+        + CanLog<LevelWarn>
+        + CanLog<LevelDebug>
+        + CanRaiseAsyncError<Encoding::Error>,
     Encoding:
         HasEncodedType<Encoded = Vec<Felt>> + CanEncode<ViaCairo, Vec<Product![Felt, Felt, Felt]>>,
 {
-    let mut signatures = Vec::new();
+    if challenges.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut signatures: Vec<Vec<Product![Felt, Felt, Felt]>> = vec![Vec::new(); challenges.len()];
 
     let mut attestator_addresses = attestator_addresses.to_vec();
     // shuffle attestator_addresses
     attestator_addresses.shuffle(&mut rand::thread_rng());
 
-    let mut attestation_count = 0;
+    let http_client = chain.attestator_http_client();
 
-    for client in attestator_addresses
+    let auth_token_envs = chain.attestation_config().auth_token_envs;
+
+    let mut pending_attestations = attestator_addresses
         .iter()
-        .map(|addr| AttestatorClient(addr.as_str()))
-    {
-        // Error calls will be ignored: `.ok()?`
-        // This allows attestator network to be fault-tolerant.
-
-        let (public_key, r, s) = match client.get_attestation(&[Ed25519 {
-            message: msg.to_vec(),
-            signature: *signature,
-            public_key: *public_key,
-        }]) {
-            Ok((public_key, signatures)) => {
-                if signatures.len() != 1 {
+        .map(|address| {
+            let auth_token = auth_token_envs
+                .get(address)
+                .and_then(|env| std::env::var(env).ok());
+
+            async move {
+                let client = AsyncAttestatorClient::new(http_client, address)
+                    .with_auth_token(auth_token.as_deref());
+
+                let (result, duration) =
+                    time_request(client.get_valid_attestation(attestation_domain, challenges))
+                        .await;
+                (address, result, duration)
+            }
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    let metrics = chain.metrics();
+
+    while let Some((address, result, duration)) = pending_attestations.next().await {
+        let valid = result.as_ref().is_ok_and(|(public_key, attestations)| {
+            attestations.len() == challenges.len() && attestator_keys.contains(public_key)
+        });
+
+        metrics.record_attestation(address, duration, valid);
+
+        let (public_key, attestations) = match result {
+            Ok((public_key, attestations)) => {
+                // An attestator may already have rotated to a key that is not yet
+                // trusted by the client, or may not have rotated yet.
+                if !attestator_keys.contains(&public_key) {
                     chain
                         .log(
-                            &format!("Unexpected number of signatures: {}", signatures.len()),
+                            &format!(
+                                "Attestator {address} signed with key {public_key:#x}, which is not trusted by the client"
+                            ),
                             &LevelWarn,
                         )
                         .await;
                     continue;
-                } else {
-                    let (r, s) = signatures[0];
-                    (public_key, r, s)
                 }
+
+                if attestations.len() != challenges.len() {
+                    chain
+                        .log(
+                            &format!(
+                                "Unexpected number of signatures from attestator {address}: expected {}, got {}",
+                                challenges.len(),
+                                attestations.len()
+                            ),
+                            &LevelWarn,
+                        )
+                        .await;
+                    continue;
+                }
+
+                (public_key, attestations)
             }
             Err(err) => {
                 chain
-                    .log(&format!("Failed to get attestation: {err}"), &LevelWarn)
+                    .log(
+                        &format!("Failed to get attestation from attestator {address}: {err}"),
+                        &LevelWarn,
+                    )
                     .await;
                 continue;
             }
         };
 
-        signatures.push(product![public_key, r, s]);
-        attestation_count += 1;
+        for (challenge_signatures, attestation) in signatures.iter_mut().zip(attestations) {
+            if let Some((r, s)) = attestation {
+                challenge_signatures.push(product![public_key, r, s]);
+            }
+        }
+
+        // an attestator may reject some of the challenges, so the quorum is
+        // only reached once every challenge has enough attestations.
+        let quorum_reached = signatures.iter().all(|challenge_signatures| {
+            challenge_signatures.len() * 100
+                >= attestator_quorum_percentage * attestator_addresses.len()
+        });
+
+        if quorum_reached {
+            let min_attestation_count = signatures.iter().map(Vec::len).min().unwrap_or_default();
 
-        if attestation_count * 100 >= attestator_quorum_percentage * attestator_addresses.len() {
             chain
                 .log(
-                    &format!("Reached attestator quorum with {attestation_count} attestators"),
+                    &format!(
+                        "Reached attestator quorum with at least {min_attestation_count} attestations per challenge"
+                    ),
                     &LevelDebug,
                 )
                 .await;
@@ -348,5 +487,26 @@ where
         }
     }
 
-    encoding.encode(&signatures).unwrap()
+    let hint_prefix = match attestation_domain {
+        Some(domain) => vec![
+            ATTESTATION_DOMAIN,
+            ATTESTATION_VERSION,
+            Felt::from(domain.key_epoch),
+            Felt::from(domain.expiry_height),
+        ],
+        None => vec![],
+    };
+
+    signatures
+        .iter()
+        .map(|challenge_signatures| -> Result<Vec<Felt>, Chain::Error> {
+            let mut hint = hint_prefix.clone();
+            hint.extend(
+                encoding
+                    .encode(challenge_signatures)
+                    .map_err(Chain::raise_error)?,
+            );
+            Ok(hint)
+        })
+        .collect()
 }
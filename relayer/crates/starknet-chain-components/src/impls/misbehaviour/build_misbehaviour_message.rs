@@ -3,9 +3,9 @@ use core::marker::PhantomData;
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
-    CanQueryClientStateWithLatestHeight, HasAddressType, HasChainId, HasClientIdType,
-    HasClientStateType, HasEvidenceType, HasMessageType, MisbehaviourMessageBuilder,
-    MisbehaviourMessageBuilderComponent,
+    CanQueryChainHeight, CanQueryClientStateWithLatestHeight, HasAddressType, HasChainId,
+    HasClientIdType, HasClientStateType, HasEvidenceType, HasMessageType,
+    MisbehaviourMessageBuilder, MisbehaviourMessageBuilderComponent,
 };
 use hermes_core::encoding_components::traits::{CanDecode, CanEncode, HasEncodedType, HasEncoding};
 use hermes_core::logging_components::traits::CanLog;
@@ -21,9 +21,13 @@ use starknet::macros::selector;
 use tendermint_proto::Error as TendermintProtoError;
 
 use crate::impls::{
-    comet_signature_hints, CosmosStarknetMisbehaviour, StarknetAddress, StarknetMessage,
+    build_attestation_domain, comet_signature_hints, CosmosStarknetMisbehaviour, StarknetAddress,
+    StarknetMessage,
+};
+use crate::traits::{
+    CanQueryContractAddress, HasAttestationConfig, HasAttestatorHttpClient,
+    HasEd25519AttestatorAddresses, HasStarknetMetrics,
 };
-use crate::traits::{CanQueryContractAddress, HasEd25519AttestatorAddresses};
 use crate::types::{ClientMessage, CometClientState};
 
 #[cgp_new_provider(MisbehaviourMessageBuilderComponent)]
@@ -37,8 +41,12 @@ where
         + HasMessageType<Message = StarknetMessage>
         + HasAddressType<Address = StarknetAddress>
         + CanQueryClientStateWithLatestHeight<Counterparty>
+        + CanQueryChainHeight<Height = u64>
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasEd25519AttestatorAddresses
+        + HasAttestationConfig
+        + HasAttestatorHttpClient
+        + HasStarknetMetrics
         + CanLog<LevelWarn>
         + CanLog<LevelDebug>
         + HasMessageType
@@ -96,6 +104,15 @@ where
 
         let attestator_quorum_percentage = client_state.attestator_quorum_percentage;
 
+        let attestation_domain = build_attestation_domain(
+            chain,
+            client_id,
+            &client_state.chain_id,
+            client_state.attestation_mode,
+            client_state.attestator_key_epoch,
+        )
+        .await?;
+
         let signature_hint_1 = comet_signature_hints(
             chain,
             &decoded_evidence
@@ -105,9 +122,11 @@ where
                 .map_err(Chain::raise_error)?,
             encoding,
             ed25519_attestator_addresses,
+            &attestator_keys,
             attestator_quorum_percentage,
+            attestation_domain.as_ref(),
         )
-        .await;
+        .await?;
 
         let signature_hint_2 = comet_signature_hints(
             chain,
@@ -118,9 +137,11 @@ where
                 .map_err(Chain::raise_error)?,
             encoding,
             ed25519_attestator_addresses,
+            &attestator_keys,
             attestator_quorum_percentage,
+            attestation_domain.as_ref(),
         )
-        .await;
+        .await?;
 
         let serialized_signature_hints = encoding
             .encode(&(signature_hint_1, signature_hint_2))
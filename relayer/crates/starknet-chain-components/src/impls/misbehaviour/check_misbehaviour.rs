@@ -15,17 +15,18 @@ use hermes_core::runtime_components::traits::CanSleep;
 use hermes_cosmos_core::chain_components::types::CosmosUpdateClientEvent;
 use hermes_prelude::*;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
 use prost_types::Any;
 use starknet::core::types::Felt;
 use starknet::macros::selector;
 use starknet::providers::Provider;
-use starknet_block_verifier::Endpoint as FeederGatewayEndpoint;
+use starknet_block_verifier::FeederError;
 use starknet_v14::core::types::StorageProof;
 
 use crate::traits::{
     CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType,
-    HasFeederGatewayUrl, HasSelectorType, HasStarknetClient,
+    HasFeederGatewayEndpoint, HasSelectorType, HasStarknetClient, HasStarknetMetrics,
 };
 use crate::types::StarknetChainStatus;
 
@@ -39,14 +40,15 @@ where
         + HasEncoding<AsFelt, Encoding = CairoEncoding>
         + CanQueryBlock<Block = StarknetChainStatus>
         + CanQueryStorageProof<StorageProof = StorageProof>
-        + HasFeederGatewayUrl
+        + HasFeederGatewayEndpoint
+        + HasStarknetMetrics
         + CanCallContract
         + HasSelectorType<Selector = Felt>
         + HasBlobType<Blob = Vec<Felt>>
         + HasHeightType<Height = u64>
         + HasStarknetClient<Client: Provider>
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
-        + CanRaiseAsyncError<ureq::Error>
+        + CanRaiseAsyncError<FeederError>
         + CanRaiseAsyncError<CairoEncoding::Error>
         + CanRaiseAsyncError<ProtoEncoding::Error>,
     Counterparty: HasUpdateClientEvent<UpdateClientEvent = CosmosUpdateClientEvent>
@@ -54,6 +56,7 @@ where
         + Async,
     ProtoEncoding: Async
         + CanConvert<Any, StarknetHeader>
+        + CanConvert<Any, StarknetSequencerKeyRotation>
         + CanConvert<StarknetMisbehaviour, Any>
         + HasAsyncErrorType,
     CairoEncoding:
@@ -67,9 +70,17 @@ where
     ) -> Result<Option<Counterparty::Evidence>, Chain::Error> {
         let encoding = Chain::default_encoding();
 
-        let header: StarknetHeader = encoding
-            .convert(&update_client_event.header)
-            .map_err(Chain::raise_error)?;
+        // the header of a sequencer key rotation is signed by the previous sequencer key
+        let header: StarknetHeader = match encoding.convert(&update_client_event.header) {
+            Ok(header) => header,
+            Err(_) => {
+                let key_rotation: StarknetSequencerKeyRotation = encoding
+                    .convert(&update_client_event.header)
+                    .map_err(Chain::raise_error)?;
+
+                key_rotation.header
+            }
+        };
 
         let target_height = header.block_header.block_number;
         let hash = header.block_header.block_hash;
@@ -82,21 +93,35 @@ where
 
         let trusted_block = chain.query_block(&target_height).await?;
 
-        let feeder_endpoint_url = chain.feeder_gateway_url();
-        let feeder_endpoint = FeederGatewayEndpoint::new(feeder_endpoint_url.as_str());
+        let feeder_endpoint = chain.feeder_gateway_endpoint();
 
-        let block_header = feeder_endpoint
-            .get_block_header(Some(target_height))
+        let block_header = chain
+            .metrics()
+            .time_feeder_request(
+                "get_block_header",
+                feeder_endpoint.get_block_header(Some(target_height)),
+            )
+            .await
             .map_err(Chain::raise_error)?;
 
-        let block_signature = feeder_endpoint
-            .get_signature(Some(target_height))
+        let block_signature = chain
+            .metrics()
+            .time_feeder_request(
+                "get_signature",
+                feeder_endpoint.get_signature(Some(target_height)),
+            )
+            .await
             .map_err(Chain::raise_error)?;
 
         let ibc_core_address = chain.query_contract_address(PhantomData).await?;
 
+        // the client verifies the final height of both headers of the evidence
         let storage_proof = chain
-            .query_storage_proof(&target_height, &ibc_core_address, &[])
+            .query_storage_proof(
+                &target_height,
+                &ibc_core_address,
+                &[selector!("final_height")],
+            )
             .await?;
 
         let final_height = {
@@ -1,6 +1,9 @@
 mod assert;
 pub use assert::*;
 
+mod attestation_config;
+pub use attestation_config::*;
+
 mod client_refresh_rate;
 pub use client_refresh_rate::*;
 
@@ -25,6 +28,9 @@ pub use error::*;
 mod events;
 pub use events::*;
 
+mod fee;
+pub use fee::*;
+
 mod ibc_amount;
 pub use ibc_amount::*;
 
@@ -52,6 +58,9 @@ pub use queries::*;
 mod send_message;
 pub use send_message::*;
 
+mod signer_pool;
+pub use signer_pool::*;
+
 mod starknet_to_cosmos;
 pub use starknet_to_cosmos::*;
 
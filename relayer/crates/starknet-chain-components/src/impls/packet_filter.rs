@@ -1,33 +1,331 @@
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
 use hermes_core::chain_components::traits::{
     HasIncomingPacketType, HasOutgoingPacketType, IncomingPacketFilter,
     IncomingPacketFilterComponent, OutgoingPacketFilter, OutgoingPacketFilterComponent,
 };
 use hermes_prelude::*;
+use ibc::apps::transfer::types::packet::PacketData as IbcIcs20PacketData;
+use ibc::apps::transfer::types::Amount;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use serde::{Deserialize, Serialize};
+
+use crate::impls::{ChannelFilterConfig, StarknetChainConfig, StarknetPacketFilterConfig};
+use crate::traits::{
+    HasPacketFilterConfig, PacketFilterConfigGetter, PacketFilterConfigGetterComponent,
+};
 
 pub struct FilterStarknetPackets;
 
 #[cgp_provider(OutgoingPacketFilterComponent)]
 impl<Chain, Counterparty> OutgoingPacketFilter<Chain, Counterparty> for FilterStarknetPackets
 where
-    Chain: HasOutgoingPacketType<Counterparty> + HasAsyncErrorType,
+    Chain: HasOutgoingPacketType<Counterparty, OutgoingPacket = Packet>
+        + HasPacketFilterConfig
+        + HasAsyncErrorType,
 {
     async fn should_relay_outgoing_packet(
-        _chain: &Chain,
-        _packet: &Chain::OutgoingPacket,
+        chain: &Chain,
+        packet: &Packet,
     ) -> Result<bool, Chain::Error> {
-        Ok(true)
+        // outgoing packets are sent from Starknet
+        Ok(should_relay_packet(
+            &chain.packet_filter_config(),
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &packet.data,
+        ))
     }
 }
 
 #[cgp_provider(IncomingPacketFilterComponent)]
 impl<Chain, Counterparty> IncomingPacketFilter<Chain, Counterparty> for FilterStarknetPackets
 where
-    Chain: HasIncomingPacketType<Counterparty> + HasAsyncErrorType,
+    Chain: HasIncomingPacketType<Counterparty, IncomingPacket = Packet>
+        + HasPacketFilterConfig
+        + HasAsyncErrorType,
 {
     async fn should_relay_incoming_packet(
-        _chain: &Chain,
-        _packet: &Chain::IncomingPacket,
+        chain: &Chain,
+        packet: &Packet,
     ) -> Result<bool, Chain::Error> {
-        Ok(true)
+        // incoming packets are received on Starknet
+        Ok(should_relay_packet(
+            &chain.packet_filter_config(),
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            &packet.data,
+        ))
+    }
+}
+
+#[cgp_new_provider(PacketFilterConfigGetterComponent)]
+impl<Chain> PacketFilterConfigGetter<Chain> for GetStarknetPacketFilterConfig
+where
+    Chain: HasField<symbol!("chain_config"), Value = StarknetChainConfig>,
+{
+    fn packet_filter_config(chain: &Chain) -> StarknetPacketFilterConfig {
+        chain
+            .get_field(PhantomData)
+            .packet_filter
+            .clone()
+            .unwrap_or_default()
+    }
+}
+
+/// Checks a packet against the filter, with the port and channel on the Starknet side.
+///
+/// The denom and amount filters only apply to packets carrying ICS20 packet data.
+/// Other packets are relayed unless `deny_non_transfer_packets` is set.
+pub fn should_relay_packet(
+    config: &StarknetPacketFilterConfig,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    packet_data: &[u8],
+) -> bool {
+    let port_id = port_id.as_str();
+    let channel_id = channel_id.as_str();
+
+    let channel_matches = |filter: &ChannelFilterConfig| {
+        glob_match(&filter.port_id, port_id) && glob_match(&filter.channel_id, channel_id)
+    };
+
+    if config.deny.iter().any(channel_matches) {
+        return false;
+    }
+
+    if !config.allow.is_empty() && !config.allow.iter().any(channel_matches) {
+        return false;
+    }
+
+    if config.allow_denoms.is_empty()
+        && config.deny_denoms.is_empty()
+        && config.min_amount.is_none()
+        && !config.deny_non_transfer_packets
+    {
+        return true;
+    }
+
+    let Ok(ics20_packet_data) = serde_json::from_slice::<IbcIcs20PacketData>(packet_data) else {
+        return !config.deny_non_transfer_packets;
+    };
+
+    let denom = ics20_packet_data.token.denom.to_string();
+
+    if config
+        .deny_denoms
+        .iter()
+        .any(|pattern| glob_match(pattern, &denom))
+    {
+        return false;
+    }
+
+    if !config.allow_denoms.is_empty()
+        && !config
+            .allow_denoms
+            .iter()
+            .any(|pattern| glob_match(pattern, &denom))
+    {
+        return false;
+    }
+
+    if let Some(TransferAmount(min_amount)) = config.min_amount {
+        if ics20_packet_data.token.amount < min_amount {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// An ICS20 transfer amount, written in configs as a decimal string such as `"1000"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TransferAmount(pub Amount);
+
+impl TryFrom<String> for TransferAmount {
+    type Error = String;
+
+    fn try_from(amount: String) -> Result<Self, String> {
+        Amount::from_str(&amount)
+            .map(Self)
+            .map_err(|e| format!("invalid transfer amount `{amount}`: {e}"))
+    }
+}
+
+impl From<TransferAmount> for String {
+    fn from(amount: TransferAmount) -> Self {
+        amount.to_string()
+    }
+}
+
+impl Display for TransferAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Matches a value against a glob pattern, where `*` matches any sequence of characters
+/// and `?` matches any single character.
+pub fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                // let the last `*` match one more character
+                Some((star_p, star_v)) => {
+                    backtrack = Some((star_p, star_v + 1));
+                    p = star_p + 1;
+                    v = star_v + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn channel(port_id: &str, channel_id: &str) -> ChannelFilterConfig {
+        ChannelFilterConfig {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        }
+    }
+
+    fn transfer_data(denom: &str, amount: &str) -> Vec<u8> {
+        format!(
+            r#"{{"denom":"{denom}","amount":"{amount}","sender":"alice","receiver":"bob","memo":""}}"#
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("transfer", "transfer"));
+        assert!(glob_match("channel-*", "channel-12"));
+        assert!(glob_match("channel-?", "channel-1"));
+        assert!(!glob_match("channel-?", "channel-12"));
+        assert!(glob_match("*/channel-*/uatom", "transfer/channel-3/uatom"));
+        assert!(!glob_match("transfer", "transfer2"));
+        assert!(!glob_match("channel-1*", "channel-2"));
+    }
+
+    #[test]
+    fn test_channel_filter() {
+        let config = StarknetPacketFilterConfig {
+            allow: vec![channel("transfer", "channel-*")],
+            deny: vec![channel("*", "channel-13")],
+            ..Default::default()
+        };
+
+        let transfer = PortId::transfer();
+
+        assert!(should_relay_packet(
+            &config,
+            &transfer,
+            &ChannelId::new(0),
+            b""
+        ));
+        assert!(!should_relay_packet(
+            &config,
+            &transfer,
+            &ChannelId::new(13),
+            b""
+        ));
+        assert!(!should_relay_packet(
+            &config,
+            &"other".parse().unwrap(),
+            &ChannelId::new(0),
+            b""
+        ));
+    }
+
+    #[test]
+    fn test_transfer_filter() {
+        let config = StarknetPacketFilterConfig {
+            allow_denoms: vec!["*uatom".to_string()],
+            deny_denoms: vec!["transfer/channel-9/*".to_string()],
+            min_amount: Some(TransferAmount::try_from("100".to_string()).unwrap()),
+            ..Default::default()
+        };
+
+        let transfer = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        let should_relay =
+            |data: Vec<u8>| should_relay_packet(&config, &transfer, &channel_id, &data);
+
+        assert!(should_relay(transfer_data("uatom", "100")));
+        assert!(should_relay(transfer_data(
+            "transfer/channel-1/uatom",
+            "1000"
+        )));
+        assert!(!should_relay(transfer_data("uatom", "99")));
+        assert!(!should_relay(transfer_data("stake", "1000")));
+        assert!(!should_relay(transfer_data(
+            "transfer/channel-9/uatom",
+            "1000"
+        )));
+
+        // packets without ICS20 packet data are only filtered by channel
+        assert!(should_relay(b"not a transfer".to_vec()));
+    }
+
+    #[test]
+    fn test_deny_non_transfer_packets() {
+        let config = StarknetPacketFilterConfig {
+            deny_non_transfer_packets: true,
+            ..Default::default()
+        };
+
+        let transfer = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        assert!(should_relay_packet(
+            &config,
+            &transfer,
+            &channel_id,
+            &transfer_data("uatom", "1")
+        ));
+        assert!(!should_relay_packet(
+            &config,
+            &transfer,
+            &channel_id,
+            b"not a transfer"
+        ));
+    }
+
+    #[test]
+    fn test_transfer_amount_config() {
+        let config: StarknetPacketFilterConfig = toml::from_str(r#"min_amount = "1000""#);
+
+        assert_eq!(
+            config.min_amount,
+            Some(TransferAmount(Amount::from(1000u64)))
+        );
+
+        assert!(toml::from_str::<StarknetPacketFilterConfig>(r#"min_amount = "1.5""#).is_err());
     }
 }
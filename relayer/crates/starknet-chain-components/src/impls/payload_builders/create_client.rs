@@ -17,13 +17,13 @@ use ibc::core::host::types::identifiers::ChainId;
 use ibc::primitives::Timestamp;
 use starknet::core::types::Felt;
 use starknet::macros::selector;
-use starknet_block_verifier::Endpoint as FeederGatewayEndpoint;
+use starknet_block_verifier::FeederError;
 use starknet_v14::core::types::StorageProof;
 
 use crate::impls::StarknetAddress;
 use crate::traits::{
     CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType,
-    HasFeederGatewayUrl, HasSelectorType,
+    HasFeederGatewayEndpoint, HasSelectorType, HasStarknetMetrics,
 };
 use crate::types::{
     StarknetChainStatus, StarknetConsensusState, StarknetCreateClientPayload,
@@ -45,14 +45,15 @@ where
         + CanCallContract
         + HasSelectorType<Selector = Felt>
         + HasBlobType<Blob = Vec<Felt>>
-        + HasFeederGatewayUrl
+        + HasFeederGatewayEndpoint
+        + HasStarknetMetrics
         + CanQueryStorageProof<StorageProof = StorageProof>
         + HasAddressType<Address = StarknetAddress>
         + CanQueryChainHeight<Height = u64>
         + HasChainId<ChainId = ChainId>
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanRaiseAsyncError<&'static str>
-        + CanRaiseAsyncError<ureq::Error>
+        + CanRaiseAsyncError<FeederError>
         + CanRaiseAsyncError<ClientError>
         + CanRaiseAsyncError<Encoding::Error>,
     Encoding: Async + CanDecode<ViaCairo, u64> + HasEncodedType<Encoded = Vec<Felt>>,
@@ -98,10 +99,15 @@ where
 
         let ibc_core_address = chain.query_contract_address(PhantomData).await?;
 
-        let feeder_endpoint = FeederGatewayEndpoint::new(chain.feeder_gateway_url().as_str());
+        let feeder_endpoint = chain.feeder_gateway_endpoint();
 
-        let sequencer_public_key = feeder_endpoint
-            .get_public_key(Some(height))
+        let sequencer_public_key = chain
+            .metrics()
+            .time_feeder_request(
+                "get_public_key",
+                feeder_endpoint.get_public_key(Some(height)),
+            )
+            .await
             .map_err(Chain::raise_error)?;
 
         let final_height = {
@@ -128,6 +134,9 @@ where
             consensus_state,
             sequencer_public_key: sequencer_public_key.to_bytes_be().to_vec(),
             ibc_contract_address: ibc_core_address.to_bytes_be().to_vec(),
+            trusting_period: create_client_options.trusting_period,
+            max_clock_drift: create_client_options.max_clock_drift,
+            max_consensus_states: create_client_options.max_consensus_states,
         })
     }
 }
@@ -145,8 +154,11 @@ where
 {
     fn override_create_client_payload_options(
         payload_options: &StarknetCreateClientPayloadOptions,
-        _new_period: Duration,
+        new_period: Duration,
     ) -> StarknetCreateClientPayloadOptions {
-        payload_options.clone()
+        StarknetCreateClientPayloadOptions {
+            trusting_period: new_period,
+            ..payload_options.clone()
+        }
     }
 }
@@ -7,29 +7,27 @@ use hermes_core::chain_components::traits::{
     UpdateClientPayloadBuilder, UpdateClientPayloadBuilderComponent,
 };
 use hermes_core::encoding_components::traits::{
-    CanDecode, CanEncode, HasDefaultEncoding, HasEncodedType, HasEncoding,
+    CanDecode, HasDefaultEncoding, HasEncodedType, HasEncoding,
 };
 use hermes_core::encoding_components::types::AsBytes;
 use hermes_core::logging_components::traits::CanLog;
-use hermes_core::logging_components::types::LevelWarn;
-use hermes_cosmos_core::protobuf_encoding_components::types::strategy::ViaProtobuf;
+use hermes_core::logging_components::types::{LevelInfo, LevelWarn};
 use hermes_prelude::*;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use starknet::core::types::Felt;
-use starknet::core::utils::cairo_short_string_to_felt;
 use starknet::macros::selector;
 use starknet::providers::ProviderError;
-use starknet_block_verifier::Endpoint as FeederGatewayEndpoint;
-use starknet_crypto_lib::{StarknetCryptoFunctions, StarknetCryptoLib};
+use starknet_block_verifier::FeederError;
+use starknet_crypto_lib::StarknetCryptoLib;
+use starknet_storage_verifier::verifier::compute_starknet_state_root;
 use starknet_v14::core::types::StorageProof;
 
 use crate::traits::{
     CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType,
-    HasFeederGatewayUrl, HasSelectorType, HasStarknetClient,
+    HasFeederGatewayEndpoint, HasSelectorType, HasStarknetClient, HasStarknetMetrics,
 };
-use crate::types::{StarknetChainStatus, StarknetUpdateClientPayload};
-
-const GLOBAL_STATE_VERSION: &str = "STARKNET_STATE_V0";
+use crate::types::{StarknetChainStatus, StarknetUpdateClientPayload, WasmStarknetClientState};
 
 pub struct BuildStarknetUpdateClientPayload;
 
@@ -38,7 +36,7 @@ impl<Chain, Counterparty, ProtoEncoding, CairoEncoding>
     UpdateClientPayloadBuilder<Chain, Counterparty> for BuildStarknetUpdateClientPayload
 where
     Chain: HasHeightType<Height = u64>
-        + HasClientStateType<Counterparty>
+        + HasClientStateType<Counterparty, ClientState = WasmStarknetClientState>
         + HasUpdateClientPayloadType<Counterparty, UpdateClientPayload = StarknetUpdateClientPayload>
         + CanQueryBlock<Block = StarknetChainStatus>
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
@@ -47,105 +45,223 @@ where
         + HasSelectorType<Selector = Felt>
         + HasBlobType<Blob = Vec<Felt>>
         + HasStarknetClient
-        + HasFeederGatewayUrl
+        + HasFeederGatewayEndpoint
+        + HasStarknetMetrics
         + CanRaiseAsyncError<&'static str>
         + HasDefaultEncoding<AsBytes, Encoding = ProtoEncoding>
         + HasEncoding<AsFelt, Encoding = CairoEncoding>
         + CanLog<LevelWarn>
+        + CanLog<LevelInfo>
         + CanRaiseAsyncError<String>
         + CanRaiseAsyncError<ProviderError>
-        + CanRaiseAsyncError<ureq::Error>
+        + CanRaiseAsyncError<FeederError>
         + CanRaiseAsyncError<serde_json::Error>
         + CanRaiseAsyncError<ProtoEncoding::Error>
         + CanRaiseAsyncError<CairoEncoding::Error>,
-    ProtoEncoding: Async + CanEncode<ViaProtobuf, StarknetHeader, Encoded = Vec<u8>>,
-    CairoEncoding: Async + CanDecode<ViaCairo, u64> + HasEncodedType<Encoded = Vec<Felt>>,
+    ProtoEncoding: Async,
+    CairoEncoding: Async
+        + CanDecode<ViaCairo, u64>
+        + CanDecode<ViaCairo, Felt>
+        + HasEncodedType<Encoded = Vec<Felt>>,
 {
     async fn build_update_client_payload(
         chain: &Chain,
-        _trusted_height: &u64,
+        trusted_height: &u64,
         target_height: &u64,
-        _client_state: Chain::ClientState,
+        client_state: WasmStarknetClientState,
     ) -> Result<Chain::UpdateClientPayload, Chain::Error> {
-        let feeder_endpoint_url = chain.feeder_gateway_url();
-        let feeder_endpoint = FeederGatewayEndpoint::new(feeder_endpoint_url.as_str());
+        chain
+            .metrics()
+            .set_client_update_lag(target_height.saturating_sub(*trusted_height));
 
-        let block_header = feeder_endpoint
-            .get_block_header(Some(*target_height))
-            .map_err(Chain::raise_error)?;
-
-        let block_signature = feeder_endpoint
-            .get_signature(Some(*target_height))
-            .map_err(Chain::raise_error)?;
+        let feeder_endpoint = chain.feeder_gateway_endpoint();
 
         let ibc_core_address = chain.query_contract_address(PhantomData).await?;
 
         let final_height_key = selector!("final_height");
 
-        let final_height = {
-            let output = chain
-                .call_contract(
-                    &ibc_core_address,
-                    &selector!("get_final_height"),
-                    &vec![],
-                    Some(target_height),
-                )
-                .await?;
+        let trusted_sequencer_public_key =
+            Felt::from_bytes_be_slice(&client_state.client_state.sequencer_public_key);
 
-            chain
-                .encoding()
-                .decode(&output)
-                .map_err(Chain::raise_error)?
-        };
+        let target_sequencer_public_key = chain
+            .metrics()
+            .time_feeder_request(
+                "get_public_key",
+                feeder_endpoint.get_public_key(Some(*target_height)),
+            )
+            .await
+            .map_err(Chain::raise_error)?;
+
+        // If the sequencer key was rotated since the trusted height, the client is first
+        // moved to the new key with the last block signed by the trusted key, whose storage
+        // proof opens the timelocked sequencer key registry of the IBC contract.
+        let rotation_height = if target_sequencer_public_key != trusted_sequencer_public_key {
+            let mut signed_height = *trusted_height;
+            let mut rotated_height = *target_height;
+
+            while rotated_height - signed_height > 1 {
+                let height = signed_height + (rotated_height - signed_height) / 2;
+
+                let sequencer_public_key = chain
+                    .metrics()
+                    .time_feeder_request(
+                        "get_public_key",
+                        feeder_endpoint.get_public_key(Some(height)),
+                    )
+                    .await
+                    .map_err(Chain::raise_error)?;
+
+                if sequencer_public_key == trusted_sequencer_public_key {
+                    signed_height = height;
+                } else {
+                    rotated_height = height;
+                }
+            }
+
+            // The client only accepts a rotation with a header above its latest height.
+            let client_latest_height = client_state.client_state.latest_height.revision_height();
+
+            if signed_height <= client_latest_height {
+                return Err(Chain::raise_error(format!(
+                    "sequencer public key rotated to {target_sequencer_public_key:#x} at height {rotated_height}, \
+                    with no block above the client latest height {client_latest_height} signed by the trusted key; \
+                    recover the client with `recover client` once it has expired",
+                )));
+            }
+
+            let registered_sequencer_public_key: Felt = {
+                let output = chain
+                    .call_contract(
+                        &ibc_core_address,
+                        &selector!("get_sequencer_public_key"),
+                        &vec![],
+                        Some(&signed_height),
+                    )
+                    .await?;
+
+                chain
+                    .encoding()
+                    .decode(&output)
+                    .map_err(Chain::raise_error)?
+            };
+
+            if registered_sequencer_public_key != target_sequencer_public_key {
+                return Err(Chain::raise_error(format!(
+                    "sequencer public key rotated to {target_sequencer_public_key:#x} at height {rotated_height}, \
+                    but the sequencer key registry holds {registered_sequencer_public_key:#x} at height {signed_height}",
+                )));
+            }
 
-        if final_height != 0 && final_height < *target_height {
             chain
                 .log(
                     &format!(
-                        "final height({final_height}) is less than target height({target_height})",
+                        "sequencer public key rotated from {trusted_sequencer_public_key:#x} to {target_sequencer_public_key:#x} at height {rotated_height}",
                     ),
-                    &LevelWarn,
+                    &LevelInfo,
                 )
                 .await;
-        }
 
-        let storage_proof = chain
-            .query_storage_proof(target_height, &ibc_core_address, &[final_height_key])
-            .await?;
-
-        let contracts_tree_root = storage_proof.global_roots.contracts_tree_root;
-        let classes_tree_root = storage_proof.global_roots.classes_tree_root;
-
-        let state_root = StarknetCryptoLib.poseidon_hash_many(&[
-            cairo_short_string_to_felt(GLOBAL_STATE_VERSION).unwrap(),
-            contracts_tree_root,
-            classes_tree_root,
-        ]);
-
-        // FIXME: uncomment this when #468 is resolved
-        // if block_header.state_root != state_root {
-        //     return Err(Chain::raise_error(
-        //         "state root does not match between block and storage proof",
-        //     ));
-        // }
-
-        if block_header.block_hash != storage_proof.global_roots.block_hash {
-            return Err(Chain::raise_error(
-                "block hash does not match between block and storage proof",
-            ));
+            Some(signed_height)
+        } else {
+            None
+        };
+
+        let mut headers = Vec::new();
+
+        for (height, storage_keys) in rotation_height
+            .map(|height| {
+                (
+                    height,
+                    vec![final_height_key, selector!("sequencer_public_key")],
+                )
+            })
+            .into_iter()
+            .chain([(*target_height, vec![final_height_key])])
+        {
+            let block_header = chain
+                .metrics()
+                .time_feeder_request(
+                    "get_block_header",
+                    feeder_endpoint.get_block_header(Some(height)),
+                )
+                .await
+                .map_err(Chain::raise_error)?;
+
+            let block_signature = chain
+                .metrics()
+                .time_feeder_request("get_signature", feeder_endpoint.get_signature(Some(height)))
+                .await
+                .map_err(Chain::raise_error)?;
+
+            let final_height = {
+                let output = chain
+                    .call_contract(
+                        &ibc_core_address,
+                        &selector!("get_final_height"),
+                        &vec![],
+                        Some(&height),
+                    )
+                    .await?;
+
+                chain
+                    .encoding()
+                    .decode(&output)
+                    .map_err(Chain::raise_error)?
+            };
+
+            if final_height != 0 && final_height < height {
+                chain
+                    .log(
+                        &format!(
+                            "final height({final_height}) is less than target height({height})",
+                        ),
+                        &LevelWarn,
+                    )
+                    .await;
+            }
+
+            let storage_proof = chain
+                .query_storage_proof(&height, &ibc_core_address, &storage_keys)
+                .await?;
+
+            let state_root = compute_starknet_state_root(
+                &StarknetCryptoLib,
+                storage_proof.global_roots.contracts_tree_root,
+                storage_proof.global_roots.classes_tree_root,
+            );
+
+            if block_header.state_root != state_root {
+                return Err(Chain::raise_error(
+                    "state root does not match between block and storage proof",
+                ));
+            }
+
+            if block_header.block_hash != storage_proof.global_roots.block_hash {
+                return Err(Chain::raise_error(
+                    "block hash does not match between block and storage proof",
+                ));
+            }
+
+            headers.push(StarknetHeader {
+                block_header,
+                final_height,
+                block_signature,
+                storage_proof,
+            });
         }
 
-        let header = StarknetHeader {
-            block_header,
-            final_height,
-            block_signature,
-            storage_proof,
-        };
+        let header = headers
+            .pop()
+            .ok_or_else(|| Chain::raise_error("missing header at target height"))?;
 
-        let encoded_header = Chain::default_encoding()
-            .encode(&header)
-            .map_err(Chain::raise_error)?;
+        let sequencer_key_rotation = headers.pop().map(|header| StarknetSequencerKeyRotation {
+            header,
+            new_sequencer_public_key: target_sequencer_public_key.to_bytes_be().to_vec(),
+        });
 
-        Ok(StarknetUpdateClientPayload { header })
+        Ok(StarknetUpdateClientPayload {
+            header,
+            sequencer_key_rotation,
+        })
     }
 }
@@ -0,0 +1,55 @@
+use core::marker::PhantomData;
+
+use hermes_core::chain_components::traits::{CanQueryBlock, HasCommitmentProofType, HasHeightType};
+use hermes_prelude::*;
+use ibc::core::host::types::path::Path;
+use starknet::core::types::Felt;
+use starknet_crypto_lib::StarknetCryptoLib;
+use starknet_storage_verifier::ibc::ibc_path_to_storage_key;
+use starknet_v14::core::types::StorageProof;
+
+use crate::traits::{
+    BatchedCommitmentProofQuerier, BatchedCommitmentProofQuerierComponent, CanQueryContractAddress,
+    CanQueryStorageProof, HasStorageKeyType, HasStorageProofType,
+};
+use crate::types::{StarknetChainStatus, StarknetCommitmentProof};
+
+#[cgp_new_provider(BatchedCommitmentProofQuerierComponent)]
+impl<Chain> BatchedCommitmentProofQuerier<Chain> for QueryStarknetBatchedCommitmentProof
+where
+    Chain: HasHeightType<Height = u64>
+        + CanQueryStorageProof
+        + HasStorageKeyType<StorageKey = Felt>
+        + HasStorageProofType<StorageProof = StorageProof>
+        + CanQueryBlock<Block = StarknetChainStatus>
+        + HasCommitmentProofType<CommitmentProof = StarknetCommitmentProof>
+        + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + CanRaiseAsyncError<serde_json::Error>,
+{
+    async fn query_batched_commitment_proof(
+        chain: &Chain,
+        height: &u64,
+        paths: &[Path],
+    ) -> Result<StarknetCommitmentProof, Chain::Error> {
+        let contract_address = chain.query_contract_address(PhantomData).await?;
+
+        let block = chain.query_block(height).await?;
+
+        let storage_keys = paths
+            .iter()
+            .map(|path| ibc_path_to_storage_key(&StarknetCryptoLib, path.clone()))
+            .collect::<Vec<Felt>>();
+
+        // key == path
+        let storage_proof: StorageProof = chain
+            .query_storage_proof(height, &contract_address, &storage_keys)
+            .await?;
+
+        let storage_proof_bytes = serde_json::to_vec(&storage_proof).map_err(Chain::raise_error)?;
+
+        Ok(StarknetCommitmentProof {
+            proof_height: block.height,
+            proof_bytes: storage_proof_bytes,
+        })
+    }
+}
@@ -5,13 +5,18 @@ use hermes_core::chain_components::traits::{
 };
 use hermes_core::chain_type_components::traits::HasAddressType;
 use hermes_prelude::*;
-use starknet::core::types::{BlockId, EventFilter};
+use starknet::core::types::{BlockId, EmittedEvent, EventFilter, Felt};
 use starknet::providers::{Provider, ProviderError};
 
-use crate::impls::StarknetAddress;
-use crate::traits::{CanQueryContractAddress, HasStarknetClient};
+use crate::impls::{StarknetAddress, StarknetChainConfig};
+use crate::traits::{
+    BlockEventsInRangeQuerier, BlockEventsInRangeQuerierComponent, CanQueryContractAddress,
+    EventChunkSizeGetter, EventChunkSizeGetterComponent, HasEventChunkSize, HasStarknetClient,
+};
 use crate::types::StarknetEvent;
 
+pub const DEFAULT_EVENT_CHUNK_SIZE: u64 = 1000;
+
 #[cgp_new_provider(BlockEventsQuerierComponent)]
 impl<Chain> BlockEventsQuerier<Chain> for GetStarknetBlockEvents
 where
@@ -20,35 +25,301 @@ where
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasAddressType<Address = StarknetAddress>
         + HasStarknetClient<Client: Provider>
+        + HasEventChunkSize
         + CanRaiseAsyncError<ProviderError>,
 {
     async fn query_block_events(
         chain: &Chain,
         height: &u64,
     ) -> Result<Vec<StarknetEvent>, Chain::Error> {
-        let provider = chain.provider();
-        let address = chain.query_contract_address(PhantomData).await?;
-
-        let raw_events = provider
-            .get_events(
-                EventFilter {
-                    from_block: Some(BlockId::Number(*height)),
-                    to_block: Some(BlockId::Number(*height)),
-                    address: Some(*address),
-                    keys: None,
+        let raw_events = query_ibc_core_events(
+            chain,
+            BlockId::Number(*height),
+            BlockId::Number(*height),
+            None,
+        )
+        .await?;
+
+        let events = raw_events.into_iter().map(StarknetEvent::from).collect();
+
+        Ok(events)
+    }
+}
+
+#[cgp_new_provider(BlockEventsInRangeQuerierComponent)]
+impl<Chain> BlockEventsInRangeQuerier<Chain> for QueryStarknetBlockEventsInRange
+where
+    Chain: HasHeightType<Height = u64>
+        + HasEventType<Event = StarknetEvent>
+        + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + HasAddressType<Address = StarknetAddress>
+        + HasStarknetClient<Client: Provider>
+        + HasEventChunkSize
+        + CanRaiseAsyncError<ProviderError>,
+{
+    async fn query_block_events_in_range(
+        chain: &Chain,
+        start_height: &u64,
+        end_height: &u64,
+    ) -> Result<Vec<(u64, Vec<StarknetEvent>)>, Chain::Error> {
+        let raw_events = query_ibc_core_events(
+            chain,
+            BlockId::Number(*start_height),
+            BlockId::Number(*end_height),
+            None,
+        )
+        .await?;
+
+        Ok(group_events_by_block(raw_events))
+    }
+}
+
+#[cgp_new_provider(EventChunkSizeGetterComponent)]
+impl<Chain> EventChunkSizeGetter<Chain> for GetStarknetEventChunkSize
+where
+    Chain: HasField<symbol!("chain_config"), Value = StarknetChainConfig>,
+{
+    fn event_chunk_size(chain: &Chain) -> u64 {
+        chain
+            .get_field(PhantomData)
+            .event_chunk_size
+            .unwrap_or(DEFAULT_EVENT_CHUNK_SIZE)
+    }
+}
+
+/// Fetches all events emitted by the IBC core contract from `from_block` to `to_block`
+/// that match the given keys filter.
+pub async fn query_ibc_core_events<Chain>(
+    chain: &Chain,
+    from_block: BlockId,
+    to_block: BlockId,
+    keys: Option<Vec<Vec<Felt>>>,
+) -> Result<Vec<EmittedEvent>, Chain::Error>
+where
+    Chain: CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + HasAddressType<Address = StarknetAddress>
+        + HasStarknetClient<Client: Provider>
+        + HasEventChunkSize
+        + CanRaiseAsyncError<ProviderError>,
+{
+    let address = chain.query_contract_address(PhantomData).await?;
+
+    let filter = EventFilter {
+        from_block: Some(from_block),
+        to_block: Some(to_block),
+        address: Some(*address),
+        keys,
+    };
+
+    get_all_events(chain.provider(), filter, chain.event_chunk_size())
+        .await
+        .map_err(Chain::raise_error)
+}
+
+/// Groups the events by block height, relying on the events being returned in block order.
+fn group_events_by_block(raw_events: Vec<EmittedEvent>) -> Vec<(u64, Vec<StarknetEvent>)> {
+    let mut block_events: Vec<(u64, Vec<StarknetEvent>)> = Vec::new();
+
+    for raw_event in raw_events {
+        // only events of pending blocks have no block number,
+        // and those are never included in a range of block numbers
+        let Some(height) = raw_event.block_number else {
+            continue;
+        };
+
+        let event = StarknetEvent::from(raw_event);
+
+        match block_events.last_mut() {
+            Some((last_height, events)) if *last_height == height => events.push(event),
+            _ => block_events.push((height, vec![event])),
+        }
+    }
+
+    block_events
+}
+
+/// Fetches all events matching the filter in pages of `chunk_size` events, following
+/// the continuation tokens until the last page is read.
+pub async fn get_all_events<P>(
+    provider: &P,
+    filter: EventFilter,
+    chunk_size: u64,
+) -> Result<Vec<EmittedEvent>, ProviderError>
+where
+    P: Provider,
+{
+    let mut events = Vec::new();
+    let mut continuation_token = None;
+
+    loop {
+        let page = provider
+            .get_events(filter.clone(), continuation_token, chunk_size)
+            .await?;
+
+        events.extend(page.events);
+
+        match page.continuation_token {
+            Some(token) => continuation_token = Some(token),
+            None => break,
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use starknet::providers::jsonrpc::{JsonRpcMethod, JsonRpcResponse, JsonRpcTransport};
+    use starknet::providers::{JsonRpcClient, ProviderRequestData};
+
+    use super::*;
+
+    /// Serves `starknet_getEvents` from a fixed list of events, emitted four per block,
+    /// using the offset of the next page as continuation token.
+    #[derive(Debug)]
+    struct MockEventsTransport {
+        event_count: u64,
+        requests: Arc<Mutex<Vec<(Option<String>, u64)>>>,
+    }
+
+    #[async_trait]
+    impl JsonRpcTransport for MockEventsTransport {
+        type Error = serde_json::Error;
+
+        async fn send_request<P, R>(
+            &self,
+            method: JsonRpcMethod,
+            params: P,
+        ) -> Result<JsonRpcResponse<R>, Self::Error>
+        where
+            P: Serialize + Send + Sync,
+            R: DeserializeOwned,
+        {
+            assert!(matches!(method, JsonRpcMethod::GetEvents));
+
+            // the filter is the only positional parameter of `starknet_getEvents`
+            let filter = serde_json::to_value(params)?[0].clone();
+
+            let continuation_token = filter["continuation_token"].as_str().map(String::from);
+            let chunk_size = filter["chunk_size"].as_u64().unwrap();
+
+            self.requests
+                .lock()
+                .unwrap()
+                .push((continuation_token.clone(), chunk_size));
+
+            let start = continuation_token.map_or(0, |token| token.parse().unwrap());
+            let end = (start + chunk_size).min(self.event_count);
+
+            let events: Vec<Value> = (start..end)
+                .map(|index| {
+                    json!({
+                        "from_address": "0x1",
+                        "keys": [],
+                        "data": [format!("{index:#x}")],
+                        "block_hash": "0x2",
+                        "block_number": index / 4,
+                        "transaction_hash": "0x3",
+                    })
+                })
+                .collect();
+
+            let continuation_token = (end < self.event_count).then(|| end.to_string());
+
+            serde_json::from_value(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "events": events,
+                    "continuation_token": continuation_token,
                 },
-                None,
-                1000,
-            )
-            .await
-            .map_err(Chain::raise_error)?;
-
-        let events = raw_events
-            .events
-            .into_iter()
-            .map(StarknetEvent::from)
+            }))
+        }
+
+        async fn send_requests<R>(
+            &self,
+            _requests: R,
+        ) -> Result<Vec<JsonRpcResponse<Value>>, Self::Error>
+        where
+            R: AsRef<[ProviderRequestData]> + Send + Sync,
+        {
+            Err(serde::de::Error::custom(
+                "batch requests are not served by the mock transport",
+            ))
+        }
+    }
+
+    fn filter() -> EventFilter {
+        EventFilter {
+            from_block: Some(BlockId::Number(1)),
+            to_block: Some(BlockId::Number(1)),
+            address: Some(Felt::ONE),
+            keys: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_all_events_follows_continuation_tokens() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let provider = JsonRpcClient::new(MockEventsTransport {
+            event_count: 25,
+            requests: requests.clone(),
+        });
+
+        let events = get_all_events(&provider, filter(), 10).await.unwrap();
+
+        let data: Vec<Felt> = events.iter().map(|event| event.data[0]).collect();
+
+        assert_eq!(data, (0..25).map(Felt::from).collect::<Vec<_>>());
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                (None, 10),
+                (Some("10".to_string()), 10),
+                (Some("20".to_string()), 10),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_group_events_by_block() {
+        let provider = JsonRpcClient::new(MockEventsTransport {
+            event_count: 10,
+            requests: Arc::new(Mutex::new(Vec::new())),
+        });
+
+        let events = get_all_events(&provider, filter(), 3).await.unwrap();
+
+        let block_events = group_events_by_block(events);
+
+        let event_counts: Vec<(u64, usize)> = block_events
+            .iter()
+            .map(|(height, events)| (*height, events.len()))
             .collect();
 
-        Ok(events)
+        assert_eq!(event_counts, vec![(0, 4), (1, 4), (2, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_events_single_page() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let provider = JsonRpcClient::new(MockEventsTransport {
+            event_count: 3,
+            requests: requests.clone(),
+        });
+
+        let events = get_all_events(&provider, filter(), 10).await.unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(*requests.lock().unwrap(), vec![(None, 10)]);
     }
 }
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
-    CanQueryBlock, CanQueryChainHeight, ChannelEndQuerier, ChannelEndQuerierComponent,
+    CanQueryChainHeight, ChannelEndQuerier, ChannelEndQuerierComponent,
     ChannelEndWithProofsQuerier, ChannelEndWithProofsQuerierComponent, HasChannelEndType,
     HasChannelIdType, HasCommitmentProofType, HasHeightType, HasIbcCommitmentPrefix, HasPortIdType,
 };
@@ -12,15 +12,12 @@ use hermes_prelude::*;
 use ibc::core::host::types::path::{ChannelEndPath, Path};
 use starknet::core::types::Felt;
 use starknet::macros::selector;
-use starknet_crypto_lib::StarknetCryptoLib;
-use starknet_storage_verifier::ibc::ibc_path_to_storage_key;
-use starknet_v14::core::types::StorageProof;
 
 use crate::traits::{
-    CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType, HasSelectorType,
-    HasStorageKeyType, HasStorageProofType,
+    CanCallContract, CanQueryBatchedCommitmentProof, CanQueryContractAddress, HasBlobType,
+    HasSelectorType,
 };
-use crate::types::{ChannelEnd, ChannelId, PortId, StarknetChainStatus, StarknetCommitmentProof};
+use crate::types::{ChannelEnd, ChannelId, PortId, StarknetCommitmentProof};
 
 pub struct QueryChannelEndFromStarknet;
 
@@ -74,11 +71,8 @@ impl<Chain, Counterparty, Encoding> ChannelEndWithProofsQuerier<Chain, Counterpa
     for QueryChannelEndFromStarknet
 where
     Chain: HasHeightType<Height = u64>
-        + CanQueryStorageProof
-        + HasStorageKeyType<StorageKey = Felt>
-        + HasStorageProofType<StorageProof = StorageProof>
+        + CanQueryBatchedCommitmentProof
         + CanQueryChainHeight
-        + CanQueryBlock<Block = StarknetChainStatus>
         + HasIbcCommitmentPrefix<CommitmentPrefix = Vec<u8>>
         + HasChannelIdType<Counterparty, ChannelId = ChannelId>
         + HasPortIdType<Counterparty, PortId = PortId>
@@ -89,7 +83,6 @@ where
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanCallContract
-        + CanRaiseAsyncError<serde_json::Error>
         + CanRaiseAsyncError<Encoding::Error>,
     Encoding: CanEncode<ViaCairo, Product![PortId, ChannelId]>
         + CanDecode<ViaCairo, ChannelEnd>
@@ -120,24 +113,12 @@ where
 
         let channel_end = encoding.decode(&output).map_err(Chain::raise_error)?;
 
-        let block = chain.query_block(height).await?;
-
         let ibc_path = Path::ChannelEnd(ChannelEndPath::new(port_id, channel_id));
 
-        let felt_path: Felt = ibc_path_to_storage_key(&StarknetCryptoLib, ibc_path);
-
-        // key == path
-        let storage_proof: StorageProof = chain
-            .query_storage_proof(height, &contract_address, &[felt_path])
+        let dummy_proof = chain
+            .query_batched_commitment_proof(height, &[ibc_path])
             .await?;
 
-        let storage_proof_bytes = serde_json::to_vec(&storage_proof).map_err(Chain::raise_error)?;
-
-        let dummy_proof = StarknetCommitmentProof {
-            proof_height: block.height,
-            proof_bytes: storage_proof_bytes,
-        };
-
         Ok((channel_end, dummy_proof))
     }
 }
@@ -0,0 +1,65 @@
+use hermes_cairo_encoding_components::strategy::ViaCairo;
+use hermes_cairo_encoding_components::types::as_starknet_event::AsStarknetEvent;
+use hermes_core::chain_type_components::traits::HasAddressType;
+use hermes_core::encoding_components::traits::{CanDecode, HasEncodedType, HasEncoding};
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use starknet::core::types::{BlockId, BlockTag};
+use starknet::macros::selector;
+use starknet::providers::{Provider, ProviderError};
+
+use crate::impls::{query_ibc_core_events, StarknetAddress};
+use crate::traits::{
+    CanQueryContractAddress, ChannelsQuerier, ChannelsQuerierComponent, HasEventChunkSize,
+    HasStarknetClient,
+};
+use crate::types::{ChannelHandshakeEvents, StarknetEvent};
+
+#[cgp_new_provider(ChannelsQuerierComponent)]
+impl<Chain, Encoding> ChannelsQuerier<Chain> for QueryStarknetChannels
+where
+    Chain: CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + HasAddressType<Address = StarknetAddress>
+        + HasStarknetClient<Client: Provider>
+        + HasEventChunkSize
+        + HasEncoding<AsStarknetEvent, Encoding = Encoding>
+        + CanRaiseAsyncError<ProviderError>,
+    Encoding: HasEncodedType<Encoded = StarknetEvent>
+        + CanDecode<ViaCairo, Option<ChannelHandshakeEvents>>,
+{
+    async fn query_channels(chain: &Chain) -> Result<Vec<(PortId, ChannelId)>, Chain::Error> {
+        // The IBC core contract does not index its channels, so they are listed from
+        // the events emitted by the first step of their handshake on this chain.
+        let keys = vec![vec![
+            selector!("ChanOpenInitEvent"),
+            selector!("ChanOpenTryEvent"),
+        ]];
+
+        let raw_events = query_ibc_core_events(
+            chain,
+            BlockId::Number(0),
+            BlockId::Tag(BlockTag::Latest),
+            Some(keys),
+        )
+        .await?;
+
+        let encoding = chain.encoding();
+
+        let channels = raw_events
+            .into_iter()
+            .filter_map(|raw_event| {
+                match encoding.decode(&StarknetEvent::from(raw_event)).ok()?? {
+                    ChannelHandshakeEvents::Init(event) => {
+                        Some((event.port_id_on_a, event.channel_id_on_a))
+                    }
+                    ChannelHandshakeEvents::Try(event) => {
+                        Some((event.port_id_on_b, event.channel_id_on_b))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Ok(channels)
+    }
+}
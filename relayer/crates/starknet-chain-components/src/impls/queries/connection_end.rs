@@ -3,25 +3,21 @@ use core::marker::PhantomData;
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
-    CanQueryBlock, ConnectionEndQuerier, ConnectionEndQuerierComponent,
-    ConnectionEndWithProofsQuerier, ConnectionEndWithProofsQuerierComponent,
-    HasCommitmentProofType, HasConnectionEndType, HasConnectionIdType, HasHeightType,
-    HasIbcCommitmentPrefix,
+    ConnectionEndQuerier, ConnectionEndQuerierComponent, ConnectionEndWithProofsQuerier,
+    ConnectionEndWithProofsQuerierComponent, HasCommitmentProofType, HasConnectionEndType,
+    HasConnectionIdType, HasHeightType, HasIbcCommitmentPrefix,
 };
 use hermes_core::encoding_components::traits::{CanDecode, CanEncode, HasEncodedType, HasEncoding};
 use hermes_prelude::*;
 use ibc::core::host::types::path::{ConnectionPath, Path};
 use starknet::core::types::Felt;
 use starknet::macros::selector;
-use starknet_crypto_lib::StarknetCryptoLib;
-use starknet_storage_verifier::ibc::ibc_path_to_storage_key;
-use starknet_v14::core::types::StorageProof;
 
 use crate::traits::{
-    CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType, HasSelectorType,
-    HasStorageKeyType, HasStorageProofType,
+    CanCallContract, CanQueryBatchedCommitmentProof, CanQueryContractAddress, HasBlobType,
+    HasSelectorType,
 };
-use crate::types::{ConnectionEnd, ConnectionId, StarknetChainStatus, StarknetCommitmentProof};
+use crate::types::{ConnectionEnd, ConnectionId, StarknetCommitmentProof};
 
 pub struct QueryConnectionEndFromStarknet;
 
@@ -71,10 +67,7 @@ impl<Chain, Counterparty, Encoding> ConnectionEndWithProofsQuerier<Chain, Counte
     for QueryConnectionEndFromStarknet
 where
     Chain: HasHeightType<Height = u64>
-        + CanQueryStorageProof
-        + HasStorageKeyType<StorageKey = Felt>
-        + HasStorageProofType<StorageProof = StorageProof>
-        + CanQueryBlock<Block = StarknetChainStatus>
+        + CanQueryBatchedCommitmentProof
         + HasIbcCommitmentPrefix<CommitmentPrefix = Vec<u8>>
         + HasCommitmentProofType<CommitmentProof = StarknetCommitmentProof>
         + HasConnectionIdType<Counterparty, ConnectionId = ConnectionId>
@@ -84,7 +77,6 @@ where
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanCallContract
-        + CanRaiseAsyncError<serde_json::Error>
         + CanRaiseAsyncError<Encoding::Error>,
     Encoding: CanEncode<ViaCairo, ConnectionId>
         + CanDecode<ViaCairo, ConnectionEnd>
@@ -112,24 +104,12 @@ where
 
         let connection_end = encoding.decode(&output).map_err(Chain::raise_error)?;
 
-        let block = chain.query_block(height).await?;
-
         let ibc_path = Path::Connection(ConnectionPath::new(connection_id));
 
-        let felt_path: Felt = ibc_path_to_storage_key(&StarknetCryptoLib, ibc_path);
-
-        // key == path
-        let storage_proof: StorageProof = chain
-            .query_storage_proof(height, &contract_address, &[felt_path])
+        let dummy_proof = chain
+            .query_batched_commitment_proof(height, &[ibc_path])
             .await?;
 
-        let storage_proof_bytes = serde_json::to_vec(&storage_proof).map_err(Chain::raise_error)?;
-
-        let dummy_proof = StarknetCommitmentProof {
-            proof_height: block.height,
-            proof_bytes: storage_proof_bytes,
-        };
-
         Ok((connection_end, dummy_proof))
     }
 }
@@ -10,9 +10,15 @@ pub use block::*;
 mod block_events;
 pub use block_events::*;
 
+mod batched_commitment_proof;
+pub use batched_commitment_proof::*;
+
 mod channel_end;
 pub use channel_end::*;
 
+mod channels;
+pub use channels::*;
+
 mod client_state;
 pub use client_state::*;
 
@@ -43,12 +49,21 @@ pub use packet_receipt::*;
 mod packet_received;
 pub use packet_received::*;
 
+mod packet_sequences;
+pub use packet_sequences::*;
+
+mod send_packet_events;
+pub use send_packet_events::*;
+
 mod status;
 pub use status::*;
 
 mod storage_proof;
 pub use storage_proof::*;
 
+mod subscribed_block_events;
+pub use subscribed_block_events::*;
+
 mod token_address;
 pub use token_address::*;
 
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 use hermes_cairo_encoding_components::strategy::ViaCairo;
 use hermes_cairo_encoding_components::types::as_felt::AsFelt;
 use hermes_core::chain_components::traits::{
-    CanQueryBlock, HasChannelIdType, HasCommitmentProofType, HasHeightType, HasIbcCommitmentPrefix,
+    HasChannelIdType, HasCommitmentProofType, HasHeightType, HasIbcCommitmentPrefix,
     HasPacketCommitmentType, HasPortIdType, HasSequenceType, PacketCommitmentQuerier,
     PacketCommitmentQuerierComponent,
 };
@@ -13,17 +13,12 @@ use ibc::core::host::types::identifiers::{PortId as IbcPortId, Sequence as IbcSe
 use ibc::core::host::types::path::{CommitmentPath, Path};
 use starknet::core::types::Felt;
 use starknet::macros::selector;
-use starknet_crypto_lib::StarknetCryptoLib;
-use starknet_storage_verifier::ibc::ibc_path_to_storage_key;
-use starknet_v14::core::types::StorageProof;
 
 use crate::traits::{
-    CanCallContract, CanQueryContractAddress, CanQueryStorageProof, HasBlobType, HasSelectorType,
-    HasStorageKeyType, HasStorageProofType,
-};
-use crate::types::{
-    ChannelId, PortId as CairoPortId, Sequence, StarknetChainStatus, StarknetCommitmentProof,
+    CanCallContract, CanQueryBatchedCommitmentProof, CanQueryContractAddress, HasBlobType,
+    HasSelectorType,
 };
+use crate::types::{ChannelId, PortId as CairoPortId, Sequence, StarknetCommitmentProof};
 
 pub struct QueryStarknetPacketCommitment;
 
@@ -32,10 +27,7 @@ impl<Chain, Counterparty, Encoding> PacketCommitmentQuerier<Chain, Counterparty>
     for QueryStarknetPacketCommitment
 where
     Chain: HasHeightType<Height = u64>
-        + CanQueryStorageProof
-        + HasStorageKeyType<StorageKey = Felt>
-        + HasStorageProofType<StorageProof = StorageProof>
-        + CanQueryBlock<Block = StarknetChainStatus>
+        + CanQueryBatchedCommitmentProof
         + HasIbcCommitmentPrefix<CommitmentPrefix = Vec<u8>>
         + HasChannelIdType<Counterparty, ChannelId = ChannelId>
         + HasPortIdType<Counterparty, PortId = IbcPortId>
@@ -47,7 +39,6 @@ where
         + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
         + HasEncoding<AsFelt, Encoding = Encoding>
         + CanCallContract
-        + CanRaiseAsyncError<serde_json::Error>
         + CanRaiseAsyncError<Encoding::Error>,
     Encoding: CanEncode<ViaCairo, Product![CairoPortId, ChannelId, Sequence]>
         + CanDecode<ViaCairo, Product![Option<[u32; 8]>]>
@@ -87,24 +78,12 @@ where
                 .collect::<Vec<_>>()
         });
 
-        let block = chain.query_block(height).await?;
-
         let ibc_path = Path::Commitment(CommitmentPath::new(port_id, channel_id, *sequence));
 
-        let felt_path: Felt = ibc_path_to_storage_key(&StarknetCryptoLib, ibc_path);
-
-        // key == path
-        let storage_proof: StorageProof = chain
-            .query_storage_proof(height, &contract_address, &[felt_path])
+        let dummy_proof = chain
+            .query_batched_commitment_proof(height, &[ibc_path])
             .await?;
 
-        let storage_proof_bytes = serde_json::to_vec(&storage_proof).map_err(Chain::raise_error)?;
-
-        let dummy_proof = StarknetCommitmentProof {
-            proof_height: block.height,
-            proof_bytes: storage_proof_bytes,
-        };
-
         Ok((commitment_bytes, dummy_proof))
     }
 }
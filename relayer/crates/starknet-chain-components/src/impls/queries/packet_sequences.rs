@@ -0,0 +1,92 @@
+use core::marker::PhantomData;
+
+use hermes_cairo_encoding_components::strategy::ViaCairo;
+use hermes_cairo_encoding_components::types::as_felt::AsFelt;
+use hermes_core::chain_type_components::traits::HasAddressType;
+use hermes_core::encoding_components::traits::{CanDecode, CanEncode, HasEncodedType, HasEncoding};
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use starknet::core::types::Felt;
+use starknet::macros::selector;
+
+use crate::impls::StarknetAddress;
+use crate::traits::{
+    CanCallContract, CanQueryContractAddress, HasBlobType, HasSelectorType, PacketSequencesQuerier,
+    PacketSequencesQuerierComponent,
+};
+
+#[cgp_new_provider(PacketSequencesQuerierComponent)]
+impl<Chain, Encoding> PacketSequencesQuerier<Chain> for QueryStarknetPacketSequences
+where
+    Chain: HasAddressType<Address = StarknetAddress>
+        + HasBlobType<Blob = Vec<Felt>>
+        + HasSelectorType<Selector = Felt>
+        + CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + HasEncoding<AsFelt, Encoding = Encoding>
+        + CanCallContract
+        + CanRaiseAsyncError<Encoding::Error>,
+    Encoding: CanEncode<ViaCairo, Product![PortId, ChannelId]>
+        + CanEncode<ViaCairo, Product![PortId, ChannelId, Vec<Sequence>]>
+        + CanDecode<ViaCairo, Vec<Sequence>>
+        + HasEncodedType<Encoded = Vec<Felt>>,
+{
+    async fn query_packet_commitment_sequences(
+        chain: &Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<Sequence>, Chain::Error> {
+        let encoding = chain.encoding();
+
+        let contract_address = chain.query_contract_address(PhantomData).await?;
+
+        let calldata = encoding
+            .encode(&product![port_id.clone(), channel_id.clone()])
+            .map_err(Chain::raise_error)?;
+
+        let output = chain
+            .call_contract(
+                &contract_address,
+                &selector!("packet_commitment_sequences"),
+                &calldata,
+                None,
+            )
+            .await?;
+
+        encoding.decode(&output).map_err(Chain::raise_error)
+    }
+
+    async fn query_unreceived_packet_sequences(
+        chain: &Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[Sequence],
+    ) -> Result<Vec<Sequence>, Chain::Error> {
+        // the contract rejects an empty list of sequences
+        if sequences.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let encoding = chain.encoding();
+
+        let contract_address = chain.query_contract_address(PhantomData).await?;
+
+        let calldata = encoding
+            .encode(&product![
+                port_id.clone(),
+                channel_id.clone(),
+                sequences.to_vec()
+            ])
+            .map_err(Chain::raise_error)?;
+
+        let output = chain
+            .call_contract(
+                &contract_address,
+                &selector!("unreceived_packet_sequences"),
+                &calldata,
+                None,
+            )
+            .await?;
+
+        encoding.decode(&output).map_err(Chain::raise_error)
+    }
+}
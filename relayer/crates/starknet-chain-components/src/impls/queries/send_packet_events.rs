@@ -0,0 +1,74 @@
+use hermes_cairo_encoding_components::strategy::ViaCairo;
+use hermes_cairo_encoding_components::types::as_starknet_event::AsStarknetEvent;
+use hermes_core::chain_type_components::traits::HasAddressType;
+use hermes_core::encoding_components::traits::{CanDecode, HasEncodedType, HasEncoding};
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use starknet::core::types::{BlockId, BlockTag, Felt};
+use starknet::macros::selector;
+use starknet::providers::{Provider, ProviderError};
+
+use crate::impls::{query_ibc_core_events, StarknetAddress};
+use crate::traits::{
+    CanQueryContractAddress, HasEventChunkSize, HasStarknetClient, SendPacketEventsQuerier,
+    SendPacketEventsQuerierComponent,
+};
+use crate::types::{PacketRelayEvents, SendPacketEvent, StarknetEvent};
+
+#[cgp_new_provider(SendPacketEventsQuerierComponent)]
+impl<Chain, Encoding> SendPacketEventsQuerier<Chain> for QueryStarknetSendPacketEvents
+where
+    Chain: CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + HasAddressType<Address = StarknetAddress>
+        + HasStarknetClient<Client: Provider>
+        + HasEventChunkSize
+        + HasEncoding<AsStarknetEvent, Encoding = Encoding>
+        + CanRaiseAsyncError<ProviderError>,
+    Encoding:
+        HasEncodedType<Encoded = StarknetEvent> + CanDecode<ViaCairo, Option<PacketRelayEvents>>,
+{
+    async fn query_send_packet_events(
+        chain: &Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[Sequence],
+    ) -> Result<Vec<SendPacketEvent>, Chain::Error> {
+        if sequences.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // The sequence is the first key of the send packet event after the selector,
+        // so the node can filter the events of the requested sequences across the
+        // whole chain history without scanning every block.
+        let keys = vec![
+            vec![selector!("SendPacketEvent")],
+            sequences
+                .iter()
+                .map(|sequence| Felt::from(sequence.value()))
+                .collect(),
+        ];
+
+        let raw_events = query_ibc_core_events(
+            chain,
+            BlockId::Number(0),
+            BlockId::Tag(BlockTag::Latest),
+            Some(keys),
+        )
+        .await?;
+
+        let encoding = chain.encoding();
+
+        let events = raw_events
+            .into_iter()
+            .filter_map(|raw_event| {
+                match encoding.decode(&StarknetEvent::from(raw_event)).ok()?? {
+                    PacketRelayEvents::Send(event) => Some(event),
+                    _ => None,
+                }
+            })
+            .filter(|event| &event.port_id_on_a == port_id && &event.channel_id_on_a == channel_id)
+            .collect();
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,39 @@
+use core::marker::PhantomData;
+
+use hermes_core::chain_components::traits::{
+    BlockEventsQuerier, BlockEventsQuerierComponent, HasEventType, HasHeightType,
+};
+use hermes_prelude::*;
+
+use crate::traits::HasStarknetEventSubscription;
+use crate::types::StarknetEvent;
+
+/// Serves the block events from the WebSocket event subscription when it covers the
+/// queried height, and falls back to `InQuerier` otherwise.
+pub struct QueryStarknetBlockEventsFromSubscription<InQuerier>(pub PhantomData<InQuerier>);
+
+#[cgp_provider(BlockEventsQuerierComponent)]
+impl<Chain, InQuerier> BlockEventsQuerier<Chain>
+    for QueryStarknetBlockEventsFromSubscription<InQuerier>
+where
+    Chain: HasHeightType<Height = u64>
+        + HasEventType<Event = StarknetEvent>
+        + HasStarknetEventSubscription
+        + HasAsyncErrorType,
+    InQuerier: BlockEventsQuerier<Chain>,
+{
+    async fn query_block_events(
+        chain: &Chain,
+        height: &u64,
+    ) -> Result<Vec<StarknetEvent>, Chain::Error> {
+        if let Some(events) = chain
+            .event_subscription()
+            .as_ref()
+            .and_then(|subscription| subscription.block_events(*height))
+        {
+            return Ok(events);
+        }
+
+        InQuerier::query_block_events(chain, height).await
+    }
+}
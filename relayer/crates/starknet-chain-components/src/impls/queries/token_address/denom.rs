@@ -0,0 +1,44 @@
+use core::marker::PhantomData;
+
+use hermes_cairo_encoding_components::strategy::ViaCairo;
+use hermes_cairo_encoding_components::types::as_felt::AsFelt;
+use hermes_core::chain_type_components::traits::HasDenomType;
+use hermes_core::encoding_components::traits::{CanDecode, HasEncodedType, HasEncoding};
+use hermes_prelude::*;
+use starknet::core::types::Felt;
+use starknet::macros::selector;
+
+use crate::impls::StarknetAddress;
+use crate::traits::{
+    CanCallContract, CanQueryContractAddress, CosmosTokenDenomOnStarknetQuerier,
+    CosmosTokenDenomOnStarknetQuerierComponent,
+};
+
+#[cgp_new_provider(CosmosTokenDenomOnStarknetQuerierComponent)]
+impl<Chain, Encoding> CosmosTokenDenomOnStarknetQuerier<Chain> for GetCosmosTokenDenomOnStarknet
+where
+    Chain: HasEncoding<AsFelt, Encoding = Encoding>
+        + HasDenomType<Denom = StarknetAddress>
+        + CanQueryContractAddress<symbol!("ibc_ics20_contract_address")>
+        + CanRaiseAsyncError<Encoding::Error>
+        + CanCallContract<Selector = Felt, Blob = Vec<Felt>>,
+    Encoding: HasEncodedType<Encoded = Vec<Felt>> + CanDecode<ViaCairo, Option<String>>,
+{
+    async fn query_cosmos_token_denom_on_starknet(
+        chain: &Chain,
+        token_address: &StarknetAddress,
+    ) -> Result<Option<String>, Chain::Error> {
+        let ics20_contract_address = chain.query_contract_address(PhantomData).await?;
+
+        let output = chain
+            .call_contract(
+                &ics20_contract_address,
+                &selector!("ibc_token_denom"),
+                &vec![**token_address],
+                None,
+            )
+            .await?;
+
+        chain.encoding().decode(&output).map_err(Chain::raise_error)
+    }
+}
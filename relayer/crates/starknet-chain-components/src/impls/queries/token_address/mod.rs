@@ -1,7 +1,9 @@
 mod create;
+mod denom;
 mod get;
 mod get_or_create;
 
 pub use create::*;
+pub use denom::*;
 pub use get::*;
 pub use get_or_create::*;
@@ -13,9 +13,12 @@ use starknet::core::types::{
     Call, ExecuteInvocation, Felt, FunctionInvocation, RevertedInvocation, TransactionTrace,
 };
 
-use crate::impls::StarknetMessage;
-use crate::traits::{CanBuildAccountFromSigner, CanUseStarknetAccount, HasStarknetAccountType};
-use crate::types::{StarknetEvent, StarknetMessageResponse, TxResponse};
+use crate::impls::{compute_resource_bounds, StarknetFeeError, StarknetMessage};
+use crate::traits::{
+    CanBuildAccountFromSigner, CanUseStarknetAccount, HasStarknetAccountType, HasStarknetFeeConfig,
+    HasStarknetMetrics,
+};
+use crate::types::{StarknetEvent, StarknetMessageResponse, StarknetWallet, TxResponse};
 
 pub struct UnexpectedTransactionTraceType {
     pub trace: TransactionTrace,
@@ -25,21 +28,25 @@ pub struct UnexpectedTransactionTraceType {
 impl<Chain> MessagesWithSignerAndNonceSender<Chain> for SendStarknetMessages
 where
     Chain: HasStarknetAccountType
-        + HasSignerType
+        + HasSignerType<Signer = StarknetWallet>
         + CanBuildAccountFromSigner
         + HasNonceType<Nonce = Felt>
         + HasMessageType<Message = StarknetMessage>
         + HasTxHashType<TxHash = Felt>
+        + HasTxResponseType<TxResponse = TxResponse>
         + CanPollTxResponse
+        + HasStarknetFeeConfig
+        + HasStarknetMetrics
         + CanRaiseAsyncError<&'static str>
+        + CanRaiseAsyncError<StarknetFeeError>
         + CanUseStarknetAccount,
 {
     async fn send_messages_with_signer_and_nonce(
         chain: &Chain,
-        signer: &Chain::Signer,
+        signer: &StarknetWallet,
         nonce: &Felt,
         messages: &[StarknetMessage],
-    ) -> Result<Chain::TxResponse, Chain::Error> {
+    ) -> Result<TxResponse, Chain::Error> {
         let calls: Vec<Call> = messages
             .iter()
             .map(|message| Call {
@@ -53,13 +60,38 @@ where
 
         let execution = account.execute_v3(calls).nonce(*nonce);
 
+        // the fee estimation executes the transaction, so that transactions
+        // that would revert are rejected before being submitted
+        let fee_estimate = execution.estimate_fee().await.map_err(Chain::raise_error)?;
+
+        let bounds = compute_resource_bounds(&chain.fee_config(), &fee_estimate)
+            .map_err(Chain::raise_error)?;
+
         let tx_hash = execution
+            .l1_gas(bounds.l1_gas.max_amount)
+            .l1_gas_price(bounds.l1_gas.max_price_per_unit)
+            .l1_data_gas(bounds.l1_data_gas.max_amount)
+            .l1_data_gas_price(bounds.l1_data_gas.max_price_per_unit)
+            .l2_gas(bounds.l2_gas.max_amount)
+            .l2_gas_price(bounds.l2_gas.max_price_per_unit)
             .send()
             .await
             .map_err(Chain::raise_error)?
             .transaction_hash;
 
-        chain.poll_tx_response(&tx_hash).await
+        let metrics = chain.metrics();
+
+        metrics.record_tx_submitted(&signer.account_address);
+
+        let tx_response = chain.poll_tx_response(&tx_hash).await?;
+
+        metrics.record_fee_spent(&signer.account_address, tx_response.actual_fee());
+
+        if tx_response.is_reverted().is_some() {
+            metrics.record_tx_reverted(&signer.account_address);
+        }
+
+        Ok(tx_response)
     }
 }
 
@@ -0,0 +1,126 @@
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use futures::lock::Mutex;
+use hermes_core::chain_components::traits::{
+    HasMessageType, MessageSender, MessageSenderComponent,
+};
+use hermes_core::chain_type_components::traits::{
+    HasAddressType, HasAmountType, HasMessageResponseType,
+};
+use hermes_core::relayer_components::transaction::traits::{
+    CanParseTxMessageResponse, CanQueryNonce, CanSendMessagesWithSigner,
+    CanSendMessagesWithSignerAndNonce, HasNonceType, HasSignerType, MessagesWithSignerSender,
+    MessagesWithSignerSenderComponent,
+};
+use hermes_prelude::*;
+use starknet::core::types::{Felt, U256};
+
+use crate::impls::StarknetAddress;
+use crate::traits::{CanQueryTokenBalance, HasStarknetSignerPool};
+use crate::types::{StarknetAmount, StarknetWallet};
+
+/// Sends each batch of messages with a signer selected by the signer pool of the chain.
+#[cgp_new_provider(MessageSenderComponent)]
+impl<Chain> MessageSender<Chain> for SendMessagesWithStarknetSignerPool
+where
+    Chain: HasStarknetSignerPool
+        + HasSignerType<Signer = StarknetWallet>
+        + HasField<symbol!("signers"), Value = Vec<StarknetWallet>>
+        + HasAddressType<Address = StarknetAddress>
+        + HasAmountType<Amount = StarknetAmount>
+        + CanQueryTokenBalance
+        + CanSendMessagesWithSigner
+        + CanRaiseAsyncError<&'static str>,
+{
+    async fn send_messages(
+        chain: &Chain,
+        messages: Vec<Chain::Message>,
+    ) -> Result<Vec<Chain::MessageResponse>, Chain::Error> {
+        let pool = chain.signer_pool();
+        let signers = chain.get_field(PhantomData);
+
+        if let Some(min_balance) = pool.config.min_balance {
+            let min_balance = U256::from(min_balance.0);
+
+            for index in pool.signers_due_for_balance_check() {
+                let balance = chain
+                    .query_token_balance(
+                        &pool.config.fee_token_address,
+                        &signers[index].account_address,
+                    )
+                    .await?;
+
+                pool.update_balance_status(index, balance.quantity < min_balance);
+            }
+        }
+
+        let lease = pool.acquire_signer().ok_or_else(|| {
+            Chain::raise_error("no Starknet signer with a balance above the minimum balance")
+        })?;
+
+        chain
+            .send_messages_with_signer(&signers[lease.index], &messages)
+            .await
+    }
+}
+
+/// Sends messages with the next nonce of the signer.
+///
+/// For the signers of the signer pool, the nonce is allocated while holding the lock of
+/// that signer only, and the nonce of the last transaction is remembered in case the
+/// node has not caught up with it yet. The other signers share the global nonce lock
+/// of the chain.
+#[cgp_new_provider(MessagesWithSignerSenderComponent)]
+impl<Chain> MessagesWithSignerSender<Chain> for AllocateStarknetNonceAndSendMessages
+where
+    Chain: HasStarknetSignerPool
+        + HasSignerType<Signer = StarknetWallet>
+        + HasField<symbol!("signers"), Value = Vec<StarknetWallet>>
+        + HasField<symbol!("nonce_mutex"), Value = Arc<Mutex<()>>>
+        + HasNonceType<Nonce = Felt>
+        + HasMessageType
+        + HasMessageResponseType
+        + CanQueryNonce
+        + CanSendMessagesWithSignerAndNonce
+        + CanParseTxMessageResponse,
+{
+    async fn send_messages_with_signer(
+        chain: &Chain,
+        signer: &StarknetWallet,
+        messages: &[Chain::Message],
+    ) -> Result<Vec<Chain::MessageResponse>, Chain::Error> {
+        let slot = chain
+            .get_field(PhantomData::<symbol!("signers")>)
+            .iter()
+            .position(|pool_signer| pool_signer.account_address == signer.account_address)
+            .map(|index| &chain.signer_pool().slots[index]);
+
+        let (mut next_nonce, _global_nonce_lock) = match slot {
+            Some(slot) => (Some(slot.next_nonce.lock().await), None),
+            None => {
+                let nonce_mutex = chain.get_field(PhantomData::<symbol!("nonce_mutex")>);
+
+                (None, Some(nonce_mutex.lock().await))
+            }
+        };
+
+        let queried_nonce = chain.query_nonce(signer).await?;
+
+        let nonce = match next_nonce.as_deref() {
+            Some(Some(next_nonce)) => queried_nonce.max(*next_nonce),
+            _ => queried_nonce,
+        };
+
+        let result = chain
+            .send_messages_with_signer_and_nonce(signer, &nonce, messages)
+            .await;
+
+        if let Some(next_nonce) = next_nonce.as_deref_mut() {
+            // on failure, the nonce is queried again for the next transaction
+            *next_nonce = result.is_ok().then(|| nonce + Felt::ONE);
+        }
+
+        Chain::parse_tx_message_response(result?)
+    }
+}
@@ -46,6 +46,9 @@ where
             sequencer_public_key: payload.sequencer_public_key,
             ibc_contract_address: payload.ibc_contract_address,
             is_frozen: 0,
+            trusting_period: payload.trusting_period.as_secs(),
+            max_clock_drift: payload.max_clock_drift.as_secs(),
+            max_consensus_states: payload.max_consensus_states,
         };
 
         let client_state = WasmStarknetClientState {
@@ -9,6 +9,7 @@ use hermes_cosmos_core::chain_components::types::CosmosUpdateClientMessage;
 use hermes_prelude::*;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use prost_types::Any;
 
 use crate::types::StarknetUpdateClientPayload;
@@ -24,7 +25,8 @@ where
         + CanRaiseAsyncError<Encoding::Error>,
     Counterparty: HasUpdateClientPayloadType<Chain, UpdateClientPayload = StarknetUpdateClientPayload>
         + HasDefaultEncoding<AsBytes, Encoding = Encoding>,
-    Encoding: Async + CanConvert<StarknetHeader, Any>,
+    Encoding:
+        Async + CanConvert<StarknetHeader, Any> + CanConvert<StarknetSequencerKeyRotation, Any>,
 {
     async fn build_update_client_message(
         _chain: &Chain,
@@ -33,6 +35,22 @@ where
     ) -> Result<Vec<CosmosMessage>, Chain::Error> {
         let encoding = Counterparty::default_encoding();
 
+        let mut messages = Vec::new();
+
+        if let Some(sequencer_key_rotation) = &payload.sequencer_key_rotation {
+            let key_rotation_any: Any = encoding
+                .convert(sequencer_key_rotation)
+                .map_err(Chain::raise_error)?;
+
+            messages.push(
+                CosmosUpdateClientMessage {
+                    client_id: client_id.clone(),
+                    header: key_rotation_any,
+                }
+                .to_cosmos_message(),
+            );
+        }
+
         let signed_header_any: Any = encoding
             .convert(&payload.header)
             .map_err(Chain::raise_error)?;
@@ -43,6 +61,8 @@ where
         }
         .to_cosmos_message();
 
-        Ok(vec![update_client_message])
+        messages.push(update_client_message);
+
+        Ok(messages)
     }
 }
@@ -67,9 +67,11 @@ where
         client_state.client_state.is_frozen != 0
     }
 
-    fn client_state_has_expired(_client_state: &Chain::ClientState, elapsed: Duration) -> bool {
-        // WasmStarknetClientState can't expire
-        false
+    fn client_state_has_expired(client_state: &Chain::ClientState, elapsed: Duration) -> bool {
+        // A trusting period of 0 means the client never expires
+        let trusting_period = client_state.client_state.trusting_period;
+
+        trusting_period != 0 && elapsed.as_secs() >= trusting_period
     }
 
     fn client_state_chain_id(client_state: &WasmStarknetClientState) -> Chain::ChainId {
@@ -77,6 +79,10 @@ where
     }
 
     fn client_state_trusting_period(client_state: &WasmStarknetClientState) -> Option<Duration> {
-        None
+        // A trusting period of 0 means the client never expires
+        match client_state.client_state.trusting_period {
+            0 => None,
+            trusting_period => Some(Duration::from_secs(trusting_period)),
+        }
     }
 }
@@ -1,11 +1,20 @@
 use core::time::Duration;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
 
 use hermes_core::chain_type_components::impls::BatchConfig;
 use hermes_cosmos_core::chain_components::impls::CosmosChainConfig;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use starknet::core::types::Felt;
+use starknet::macros::felt;
+use starknet_block_verifier::FeederConfig;
 
-use crate::impls::StarknetAddress;
+use crate::impls::{StarknetAddress, StrkAmount, TransferAmount};
+use crate::types::AttestationMode;
+
+/// Address of the STRK token contract, which is the same on mainnet and on Sepolia.
+pub const STRK_TOKEN_ADDRESS: Felt =
+    felt!("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d");
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StarknetRelayerConfig {
@@ -18,8 +27,16 @@ pub struct StarknetChainConfig {
     pub json_rpc_url: String,
     pub feeder_gateway_url: String,
     pub ed25519_attestator_addresses: Option<Vec<String>>,
-    pub relayer_wallet_1: String,
-    pub relayer_wallet_2: String,
+    pub attestation: Option<StarknetAttestationConfig>,
+    /// Paths of the wallet files of the relayer. The first wallet is the default signer.
+    #[serde(default)]
+    pub relayer_wallets: Vec<String>,
+    /// Deprecated, prepended to `relayer_wallets`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_wallet_1: Option<String>,
+    /// Deprecated, prepended to `relayer_wallets` after `relayer_wallet_1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_wallet_2: Option<String>,
     #[serde(with = "humantime_serde")]
     pub poll_interval: Duration,
     pub block_time: Duration,
@@ -27,6 +44,26 @@ pub struct StarknetChainConfig {
     pub contract_classes: StarknetContractClasses,
 
     pub batch_config: Option<BatchConfig>,
+    pub feeder_gateway_config: Option<FeederConfig>,
+    pub packet_filter: Option<StarknetPacketFilterConfig>,
+    pub event_chunk_size: Option<u64>,
+    pub event_source: Option<StarknetEventSourceConfig>,
+    pub fee_config: Option<StarknetFeeConfig>,
+    pub signer_pool: Option<StarknetSignerPoolConfig>,
+    pub telemetry: Option<StarknetTelemetryConfig>,
+}
+
+impl StarknetChainConfig {
+    /// Paths of all the wallet files of the relayer, starting with the deprecated
+    /// `relayer_wallet_1` and `relayer_wallet_2` when they are set.
+    pub fn relayer_wallet_paths(&self) -> Vec<String> {
+        self.relayer_wallet_1
+            .iter()
+            .chain(&self.relayer_wallet_2)
+            .chain(&self.relayer_wallets)
+            .cloned()
+            .collect()
+    }
 }
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
@@ -42,3 +79,313 @@ pub struct StarknetContractClasses {
     pub ics20: Option<Felt>,
     pub ibc_client: Option<Felt>,
 }
+
+/// Resource bounds and fee cap of the transactions submitted by the relayer.
+///
+/// The bounds of each resource are derived from the fee estimate of the transaction,
+/// scaled by the multipliers. Transactions exceeding any of the limits are not submitted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarknetFeeConfig {
+    /// Multiplier applied to the estimated amount of each resource, at least `1.0`.
+    #[serde(
+        default = "default_fee_multiplier",
+        deserialize_with = "deserialize_fee_multiplier"
+    )]
+    pub gas_multiplier: f64,
+    /// Multiplier applied to the estimated price of each resource, at least `1.0`.
+    #[serde(
+        default = "default_fee_multiplier",
+        deserialize_with = "deserialize_fee_multiplier"
+    )]
+    pub gas_price_multiplier: f64,
+    #[serde(default)]
+    pub l1_gas: StarknetResourceLimits,
+    #[serde(default)]
+    pub l1_data_gas: StarknetResourceLimits,
+    #[serde(default)]
+    pub l2_gas: StarknetResourceLimits,
+    /// Maximum fee paid per transaction, in STRK as a decimal string.
+    #[serde(default)]
+    pub max_fee: Option<StrkAmount>,
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct StarknetResourceLimits {
+    /// Maximum amount of the resource used per transaction.
+    #[serde(default)]
+    pub max_amount: Option<u64>,
+    /// Maximum price per unit of the resource, in FRI.
+    #[serde(default)]
+    pub max_price_per_unit: Option<u128>,
+}
+
+impl Default for StarknetFeeConfig {
+    fn default() -> Self {
+        Self {
+            gas_multiplier: default_fee_multiplier(),
+            gas_price_multiplier: default_fee_multiplier(),
+            l1_gas: Default::default(),
+            l1_data_gas: Default::default(),
+            l2_gas: Default::default(),
+            max_fee: None,
+        }
+    }
+}
+
+fn default_fee_multiplier() -> f64 {
+    1.5
+}
+
+/// A multiplier below `1.0` would bound the transactions below their fee estimate,
+/// so that they would all fail.
+fn deserialize_fee_multiplier<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let multiplier = f64::deserialize(deserializer)?;
+
+    if !(multiplier.is_finite() && multiplier >= 1.0) {
+        return Err(serde::de::Error::custom(format!(
+            "fee multiplier must be at least 1.0, got {multiplier}"
+        )));
+    }
+
+    Ok(multiplier)
+}
+
+/// Selection of the relayer wallet that signs each batch of messages.
+///
+/// Each wallet has its own nonce, so batches signed by different wallets can be
+/// submitted concurrently.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarknetSignerPoolConfig {
+    #[serde(default)]
+    pub strategy: SignerSelectionStrategy,
+    /// Wallets with a fee token balance below this amount are skipped, in STRK as a
+    /// decimal string.
+    #[serde(default)]
+    pub min_balance: Option<StrkAmount>,
+    #[serde(default = "default_balance_check_interval", with = "humantime_serde")]
+    pub balance_check_interval: Duration,
+    #[serde(default = "default_fee_token_address")]
+    pub fee_token_address: StarknetAddress,
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerSelectionStrategy {
+    #[default]
+    RoundRobin,
+    /// The wallet with the fewest transactions in flight.
+    LeastPending,
+}
+
+impl Default for StarknetSignerPoolConfig {
+    fn default() -> Self {
+        Self {
+            strategy: Default::default(),
+            min_balance: None,
+            balance_check_interval: default_balance_check_interval(),
+            fee_token_address: default_fee_token_address(),
+        }
+    }
+}
+
+fn default_balance_check_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_fee_token_address() -> StarknetAddress {
+    STRK_TOKEN_ADDRESS.into()
+}
+
+/// Format of the attestations requested from the Ed25519 attestators.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarknetAttestationConfig {
+    /// Attestation mode of the Comet clients created by the relayer. The attestations
+    /// used to update a client follow the mode stored in its client state.
+    #[serde(default)]
+    pub mode: AttestationMode,
+    /// Epoch of the attestator keys trusted by the Comet clients created by the relayer.
+    /// The attestations used to update a client are bound to the epoch of its client state.
+    #[serde(default)]
+    pub key_epoch: u64,
+    /// Number of Starknet blocks after the current height during which the
+    /// attestations can be submitted.
+    #[serde(default = "default_attestation_expiry_blocks")]
+    pub expiry_blocks: u64,
+    /// Environment variables holding the bearer token sent to each attestator,
+    /// by attestator address.
+    #[serde(default)]
+    pub auth_token_envs: BTreeMap<String, String>,
+}
+
+impl Default for StarknetAttestationConfig {
+    fn default() -> Self {
+        Self {
+            mode: Default::default(),
+            key_epoch: 0,
+            expiry_blocks: default_attestation_expiry_blocks(),
+            auth_token_envs: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_attestation_expiry_blocks() -> u64 {
+    100
+}
+
+/// Server exposing the metrics of the Starknet chains at `/metrics` in the Prometheus
+/// text format, and at `/health` as JSON together with the health status.
+///
+/// A single server is started at the `listen_addr` of the first chain built, and
+/// serves the metrics of all the chains.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarknetTelemetryConfig {
+    pub listen_addr: SocketAddr,
+    /// Interval at which the chain height and the pending packets are queried.
+    #[serde(
+        default = "default_telemetry_refresh_interval",
+        with = "humantime_serde"
+    )]
+    pub refresh_interval: Duration,
+    /// Channels on Starknet whose pending packets are counted.
+    #[serde(default)]
+    pub channels: Vec<StarknetChannelConfig>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarknetChannelConfig {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+fn default_telemetry_refresh_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+/// Source of the IBC events of the Starknet chain.
+///
+/// In `push` mode, the relayer subscribes to the events of the IBC core contract over the
+/// Starknet JSON-RPC WebSocket API, and falls back to polling with `starknet_getEvents`
+/// for the blocks not covered by the subscription. As the subscribed events are served
+/// from memory, the chain is then polled every `poll_interval` of the push source instead
+/// of the `poll_interval` of the chain.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum StarknetEventSourceConfig {
+    #[default]
+    Pull,
+    Push {
+        url: String,
+        #[serde(default = "default_reconnect_delay", with = "humantime_serde")]
+        reconnect_delay: Duration,
+        #[serde(default = "default_push_poll_interval", with = "humantime_serde")]
+        poll_interval: Duration,
+    },
+}
+
+fn default_reconnect_delay() -> Duration {
+    Duration::from_secs(5)
+}
+
+fn default_push_poll_interval() -> Duration {
+    Duration::from_secs(1)
+}
+
+/// Filter applied to the packets relayed in both directions, matched against the
+/// port and channel on the Starknet side.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct StarknetPacketFilterConfig {
+    /// Channels to relay. Every channel is relayed when empty.
+    #[serde(default)]
+    pub allow: Vec<ChannelFilterConfig>,
+    /// Channels never relayed, even if allowed.
+    #[serde(default)]
+    pub deny: Vec<ChannelFilterConfig>,
+    /// Glob patterns of the ICS20 denoms to relay. Every denom is relayed when empty.
+    #[serde(default)]
+    pub allow_denoms: Vec<String>,
+    /// Glob patterns of the ICS20 denoms never relayed, even if allowed.
+    #[serde(default)]
+    pub deny_denoms: Vec<String>,
+    /// Minimum ICS20 transfer amount to relay, as a decimal string.
+    #[serde(default)]
+    pub min_amount: Option<TransferAmount>,
+    /// Whether to skip the packets not carrying ICS20 packet data. These packets are
+    /// otherwise relayed without checking the denom and amount filters.
+    #[serde(default)]
+    pub deny_non_transfer_packets: bool,
+}
+
+/// Glob patterns of a port and channel, where `*` matches any sequence of characters
+/// and `?` matches any single character.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelFilterConfig {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CHAIN_CONFIG: &str = r#"
+        json_rpc_url = "http://localhost:5050"
+        feeder_gateway_url = "http://localhost:5050"
+        poll_interval = "1s"
+        block_time = { secs = 1, nanos = 0 }
+        [contract_addresses]
+        [contract_classes]
+    "#;
+
+    #[test]
+    fn test_legacy_relayer_wallets() {
+        let config: StarknetChainConfig = toml::from_str(&format!(
+            "relayer_wallet_1 = \"wallet_1.toml\"\nrelayer_wallet_2 = \"wallet_2.toml\"\nrelayer_wallets = [ \"wallet_3.toml\" ]\n{CHAIN_CONFIG}"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            config.relayer_wallet_paths(),
+            vec!["wallet_1.toml", "wallet_2.toml", "wallet_3.toml"]
+        );
+    }
+
+    #[test]
+    fn test_signer_pool_min_balance() {
+        let config: StarknetSignerPoolConfig = toml::from_str("min_balance = \"2.5\"").unwrap();
+
+        assert_eq!(
+            config.min_balance,
+            Some(StrkAmount(2_500_000_000_000_000_000))
+        );
+
+        assert!(toml::from_str::<StarknetSignerPoolConfig>("min_balance = \"-1\"").is_err());
+        assert!(toml::from_str::<StarknetSignerPoolConfig>("min_balance = \"ten\"").is_err());
+    }
+
+    #[test]
+    fn test_fee_config_validation() {
+        let config: StarknetFeeConfig =
+            toml::from_str("gas_multiplier = 1.0\nmax_fee = \"0.5\"").unwrap();
+
+        assert_eq!(config.gas_multiplier, 1.0);
+        assert_eq!(config.gas_price_multiplier, default_fee_multiplier());
+        assert_eq!(config.max_fee, Some(StrkAmount(500_000_000_000_000_000)));
+
+        for invalid_config in [
+            "gas_multiplier = 0.9",
+            "gas_price_multiplier = -1.5",
+            "gas_multiplier = nan",
+            "gas_price_multiplier = inf",
+            "max_fee = \"-1\"",
+            "max_fee = \"five\"",
+        ] {
+            assert!(
+                toml::from_str::<StarknetFeeConfig>(invalid_config).is_err(),
+                "{invalid_config}"
+            );
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use hermes_prelude::*;
+
+use crate::types::StarknetEventSubscription;
+
+#[cgp_getter {
+    name: StarknetEventSubscriptionGetterComponent,
+    provider: StarknetEventSubscriptionGetter,
+}]
+pub trait HasStarknetEventSubscription {
+    fn event_subscription(&self) -> &Option<StarknetEventSubscription>;
+}
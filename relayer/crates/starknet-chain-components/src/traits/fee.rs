@@ -0,0 +1,11 @@
+use hermes_prelude::*;
+
+use crate::impls::StarknetFeeConfig;
+
+#[cgp_component {
+    name: FeeConfigGetterComponent,
+    provider: FeeConfigGetter,
+}]
+pub trait HasStarknetFeeConfig {
+    fn fee_config(&self) -> StarknetFeeConfig;
+}
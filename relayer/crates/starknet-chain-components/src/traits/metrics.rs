@@ -0,0 +1,11 @@
+use hermes_prelude::*;
+
+use crate::types::StarknetMetrics;
+
+#[cgp_getter {
+    name: StarknetMetricsGetterComponent,
+    provider: StarknetMetricsGetter,
+}]
+pub trait HasStarknetMetrics {
+    fn metrics(&self) -> &StarknetMetrics;
+}
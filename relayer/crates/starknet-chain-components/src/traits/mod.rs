@@ -10,18 +10,33 @@ pub use commitment_proof::*;
 mod contract;
 pub use contract::*;
 
+mod event_subscription;
+pub use event_subscription::*;
+
+mod fee;
+pub use fee::*;
+
 mod json_rpc;
 pub use json_rpc::*;
 
 mod messages;
 pub use messages::*;
 
+mod metrics;
+pub use metrics::*;
+
+mod packet_filter;
+pub use packet_filter::*;
+
 mod queries;
 pub use queries::*;
 
 mod rpc_client;
 pub use rpc_client::*;
 
+mod signer_pool;
+pub use signer_pool::*;
+
 mod transfer;
 pub use transfer::*;
 
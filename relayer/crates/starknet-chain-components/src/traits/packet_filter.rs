@@ -0,0 +1,11 @@
+use hermes_prelude::*;
+
+use crate::impls::StarknetPacketFilterConfig;
+
+#[cgp_component {
+    name: PacketFilterConfigGetterComponent,
+    provider: PacketFilterConfigGetter,
+}]
+pub trait HasPacketFilterConfig {
+    fn packet_filter_config(&self) -> StarknetPacketFilterConfig;
+}
@@ -0,0 +1,28 @@
+use hermes_core::chain_components::traits::{HasEventType, HasHeightType};
+use hermes_prelude::*;
+
+#[cgp_component {
+    provider: BlockEventsInRangeQuerier,
+}]
+#[async_trait]
+pub trait CanQueryBlockEventsInRange: HasHeightType + HasEventType + HasAsyncErrorType {
+    /// Queries the events of all blocks from `start_height` to `end_height` inclusive,
+    /// grouped by block height in ascending order. Blocks without events are omitted.
+    ///
+    /// The events of the whole range are fetched in one paginated scan, instead of
+    /// one query per block.
+    async fn query_block_events_in_range(
+        &self,
+        start_height: &Self::Height,
+        end_height: &Self::Height,
+    ) -> Result<Vec<(Self::Height, Vec<Self::Event>)>, Self::Error>;
+}
+
+#[cgp_component {
+    name: EventChunkSizeGetterComponent,
+    provider: EventChunkSizeGetter,
+}]
+pub trait HasEventChunkSize {
+    /// The maximum number of events fetched per `starknet_getEvents` request.
+    fn event_chunk_size(&self) -> u64;
+}
@@ -0,0 +1,14 @@
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+
+#[cgp_component {
+  name: ChannelsQuerierComponent,
+  provider: ChannelsQuerier,
+  context: Chain,
+}]
+#[async_trait]
+pub trait CanQueryChannels: HasAsyncErrorType {
+    /// Lists the port and channel identifiers of all the channels opened on the chain,
+    /// including the channels with an unfinished handshake.
+    async fn query_channels(&self) -> Result<Vec<(PortId, ChannelId)>, Self::Error>;
+}
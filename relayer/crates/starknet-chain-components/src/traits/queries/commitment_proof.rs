@@ -0,0 +1,19 @@
+use hermes_core::chain_components::traits::{HasCommitmentProofType, HasHeightType};
+use hermes_prelude::*;
+use ibc::core::host::types::path::Path;
+
+#[cgp_component {
+    provider: BatchedCommitmentProofQuerier,
+}]
+#[async_trait]
+pub trait CanQueryBatchedCommitmentProof:
+    HasHeightType + HasCommitmentProofType + HasAsyncErrorType
+{
+    /// Queries a single commitment proof at the given height covering all the given IBC paths,
+    /// so that a whole relay batch can be proven with one storage proof.
+    async fn query_batched_commitment_proof(
+        &self,
+        height: &Self::Height,
+        paths: &[Path],
+    ) -> Result<Self::CommitmentProof, Self::Error>;
+}
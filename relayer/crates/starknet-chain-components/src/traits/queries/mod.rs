@@ -1,6 +1,21 @@
+mod block_events;
+pub use block_events::*;
+
+mod channels;
+pub use channels::*;
+
+mod commitment_proof;
+pub use commitment_proof::*;
+
 mod contract_address;
 pub use contract_address::*;
 
+mod packet_sequences;
+pub use packet_sequences::*;
+
+mod send_packet_events;
+pub use send_packet_events::*;
+
 mod storage_proof;
 pub use storage_proof::*;
 
@@ -0,0 +1,27 @@
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+
+#[cgp_component {
+  name: PacketSequencesQuerierComponent,
+  provider: PacketSequencesQuerier,
+  context: Chain,
+}]
+#[async_trait]
+pub trait CanQueryPacketSequences: HasAsyncErrorType {
+    /// Returns the sequences of the packets sent on the channel whose commitment is
+    /// not cleared yet, i.e. that are not acknowledged or timed out.
+    async fn query_packet_commitment_sequences(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<Sequence>, Self::Error>;
+
+    /// Returns the subset of the given sequences of packets sent to the channel
+    /// that have not been received yet.
+    async fn query_unreceived_packet_sequences(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[Sequence],
+    ) -> Result<Vec<Sequence>, Self::Error>;
+}
@@ -0,0 +1,22 @@
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+
+use crate::types::SendPacketEvent;
+
+#[cgp_component {
+  name: SendPacketEventsQuerierComponent,
+  provider: SendPacketEventsQuerier,
+  context: Chain,
+}]
+#[async_trait]
+pub trait CanQuerySendPacketEvents: HasAsyncErrorType {
+    /// Searches the chain history for the events emitted when the packets with the
+    /// given sequences were sent on the channel. Sequences without a matching event
+    /// are omitted from the result.
+    async fn query_send_packet_events(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[Sequence],
+    ) -> Result<Vec<SendPacketEvent>, Self::Error>;
+}
@@ -13,3 +13,17 @@ pub trait CanQueryCosmosTokenAddressOnStarknet: HasAsyncErrorType + HasDenomType
         prefixed_denom: &PrefixedDenom,
     ) -> Result<Option<Self::Denom>, Self::Error>;
 }
+
+#[cgp_component {
+    provider: CosmosTokenDenomOnStarknetQuerier,
+}]
+#[async_trait]
+pub trait CanQueryCosmosTokenDenomOnStarknet: HasAsyncErrorType + HasDenomType {
+    /// Looks up the prefixed denom of the Cosmos token represented by the given ERC20
+    /// token on Starknet. Returns `None` when the token was not created by the ICS20
+    /// contract.
+    async fn query_cosmos_token_denom_on_starknet(
+        &self,
+        token_address: &Self::Denom,
+    ) -> Result<Option<String>, Self::Error>;
+}
@@ -1,7 +1,10 @@
 use hermes_prelude::*;
+use starknet_block_verifier::AsyncEndpoint;
 use ureq::Agent;
 use url::Url;
 
+use crate::impls::StarknetAttestationConfig;
+
 #[cgp_getter {
     name: ReqwestClientGetterComponent,
     provider: ReqwestClientGetter,
@@ -25,6 +28,15 @@ pub trait HasFeederGatewayUrl {
     fn feeder_gateway_url(&self) -> &Url;
 }
 
+#[cgp_getter {
+    name: FeederGatewayEndpointGetterComponent,
+    provider: FeederGatewayEndpointGetter,
+}]
+pub trait HasFeederGatewayEndpoint {
+    /// Feeder gateway client of the chain, shared by all the feeder gateway requests.
+    fn feeder_gateway_endpoint(&self) -> &AsyncEndpoint;
+}
+
 #[cgp_getter {
     name: Ed25519AttestatorAddressesGetterComponent,
     provider: Ed25519AttestatorAddressesGetter,
@@ -32,3 +44,20 @@ pub trait HasFeederGatewayUrl {
 pub trait HasEd25519AttestatorAddresses {
     fn ed25519_attestator_addresses(&self) -> &Option<Vec<String>>;
 }
+
+#[cgp_getter {
+    name: AttestatorHttpClientGetterComponent,
+    provider: AttestatorHttpClientGetter,
+}]
+pub trait HasAttestatorHttpClient {
+    /// HTTP client shared by the requests to the Ed25519 attestators.
+    fn attestator_http_client(&self) -> &reqwest::Client;
+}
+
+#[cgp_component {
+    name: AttestationConfigGetterComponent,
+    provider: AttestationConfigGetter,
+}]
+pub trait HasAttestationConfig {
+    fn attestation_config(&self) -> StarknetAttestationConfig;
+}
@@ -0,0 +1,11 @@
+use hermes_prelude::*;
+
+use crate::types::StarknetSignerPool;
+
+#[cgp_getter {
+    name: StarknetSignerPoolGetterComponent,
+    provider: StarknetSignerPoolGetter,
+}]
+pub trait HasStarknetSignerPool {
+    fn signer_pool(&self) -> &StarknetSignerPool;
+}
@@ -18,6 +18,7 @@ use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::host::types::identifiers::ChainId;
 use ibc::primitives::proto::Any;
 use ibc_proto::ics23::{InnerSpec, LeafOp, ProofSpec};
+use serde::{Deserialize, Serialize};
 use starknet_crypto::Felt;
 
 use crate::types::Height;
@@ -36,6 +37,8 @@ pub struct CometClientState {
     pub upgrade_path: Vec<String>,
     pub attestator_keys: Vec<Felt>,
     pub attestator_quorum_percentage: usize,
+    pub attestation_mode: AttestationMode,
+    pub attestator_key_epoch: u64,
 }
 
 #[derive(Clone, Debug, HasFields)]
@@ -45,6 +48,19 @@ pub enum ClientStatus {
     Frozen(Height),
 }
 
+/// Attestations accepted by the Comet client, in the order of the Cairo enum.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Serialize, Deserialize, HasFields)]
+#[serde(rename_all = "snake_case")]
+pub enum AttestationMode {
+    /// Attestations of the Ed25519 signatures alone, for the Comet clients deployed
+    /// before domain-bound attestations. Domain-bound attestations are accepted too.
+    Legacy,
+    /// Attestations bound to the counterparty chain ID, the client ID, the attestator
+    /// key epoch and an expiry height. Legacy attestations are rejected.
+    #[default]
+    Domain,
+}
+
 pub struct UseCometClientState;
 
 delegate_components! {
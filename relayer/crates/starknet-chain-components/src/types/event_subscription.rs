@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use starknet::core::types::Felt;
+
+use crate::types::StarknetEvent;
+
+/// Number of blocks below the latest head for which subscribed events are kept.
+pub const MAX_SUBSCRIBED_BLOCKS: u64 = 1000;
+
+/// Number of new heads that must be received at or above a block before its subscribed
+/// events are served.
+///
+/// The events and the new heads are delivered by separate subscriptions, without any
+/// ordering guarantee between them, so the events of a block may still arrive after the
+/// head of the next block.
+pub const SUBSCRIBED_BLOCK_DEPTH: u64 = 2;
+
+/// Events of the IBC core contract received from a Starknet WebSocket subscription.
+///
+/// The subscription only answers for the blocks that it has fully observed since it
+/// was last (re)established. For any other block, the caller should fall back to
+/// polling the JSON-RPC API.
+#[derive(Clone, Default)]
+pub struct StarknetEventSubscription {
+    pub state: Arc<Mutex<StarknetEventSubscriptionState>>,
+}
+
+#[derive(Default)]
+pub struct StarknetEventSubscriptionState {
+    /// The first height at which all events are known to have been received.
+    pub live_from: Option<u64>,
+    /// The height of the latest new head received.
+    pub latest_height: Option<u64>,
+    pub blocks: BTreeMap<u64, SubscribedBlock>,
+}
+
+#[derive(Default)]
+pub struct SubscribedBlock {
+    pub block_hash: Option<Felt>,
+    pub events: Vec<StarknetEvent>,
+}
+
+impl StarknetEventSubscription {
+    /// Returns the events of the block at the given height, if the block is covered by
+    /// the subscription.
+    ///
+    /// A block is only considered complete once the latest head is `SUBSCRIBED_BLOCK_DEPTH`
+    /// blocks above it, as the events of a block may be delivered after its own head.
+    pub fn block_events(&self, height: u64) -> Option<Vec<StarknetEvent>> {
+        let state = self.state.lock().unwrap();
+
+        let live_from = state.live_from?;
+        let latest_height = state.latest_height?;
+
+        if height < live_from || height + SUBSCRIBED_BLOCK_DEPTH > latest_height {
+            return None;
+        }
+
+        let events = state
+            .blocks
+            .get(&height)
+            .map(|block| block.events.clone())
+            .unwrap_or_default();
+
+        Some(events)
+    }
+
+    /// Returns whether the subscription currently covers any block.
+    pub fn is_live(&self) -> bool {
+        let state = self.state.lock().unwrap();
+
+        matches!(
+            (state.live_from, state.latest_height),
+            (Some(live_from), Some(latest_height))
+                if live_from + SUBSCRIBED_BLOCK_DEPTH <= latest_height
+        )
+    }
+
+    /// Discards all received events, e.g. when the connection is dropped.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = Default::default();
+    }
+
+    pub fn on_new_head(&self, height: u64, block_hash: Felt, parent_hash: Felt) {
+        let mut state = self.state.lock().unwrap();
+
+        let parent_mismatch = height > 0
+            && state
+                .blocks
+                .get(&(height - 1))
+                .and_then(|block| block.block_hash)
+                .is_some_and(|hash| hash != parent_hash);
+
+        let head_replaced = state
+            .latest_height
+            .is_some_and(|latest_height| height <= latest_height);
+
+        if parent_mismatch || head_replaced {
+            // the chain was reorganized; none of the blocks received so far can be trusted
+            *state = Default::default();
+        }
+
+        // events of the first head may have been emitted before the subscription started
+        if state.live_from.is_none() {
+            state.live_from = Some(height + 1);
+        }
+
+        let block = state.blocks.entry(height).or_default();
+
+        if block.block_hash.is_some_and(|hash| hash != block_hash) {
+            // events received for a block that was not included in the chain
+            block.events.clear();
+        }
+
+        block.block_hash = Some(block_hash);
+
+        state.latest_height = Some(height);
+
+        let min_height = height.saturating_sub(MAX_SUBSCRIBED_BLOCKS);
+        state.blocks = state.blocks.split_off(&min_height);
+    }
+
+    pub fn on_event(&self, height: u64, block_hash: Felt, event: StarknetEvent) {
+        let mut state = self.state.lock().unwrap();
+
+        let head_received = state
+            .latest_height
+            .is_some_and(|latest_height| height <= latest_height);
+
+        let block = state.blocks.entry(height).or_default();
+
+        match block.block_hash {
+            Some(hash) if hash != block_hash => {
+                if head_received {
+                    // event of a block that was already replaced by its new head
+                    return;
+                }
+
+                // event of a newer version of a block that has not received its head yet
+                block.block_hash = Some(block_hash);
+                block.events = vec![event];
+            }
+            _ => {
+                block.block_hash = Some(block_hash);
+                block.events.push(event);
+            }
+        }
+    }
+
+    /// Handles a reorg notification, which reverts the blocks from `start_height` onward.
+    pub fn on_reorg(&self, start_height: u64) {
+        let mut state = self.state.lock().unwrap();
+
+        state.blocks.split_off(&start_height);
+
+        // the reverted heights are not received again, so they are left to polling
+        state.live_from = None;
+        state.latest_height = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::StarknetEventFields;
+
+    fn event(data: u64) -> StarknetEvent {
+        StarknetEvent {
+            fields: Arc::new(StarknetEventFields {
+                contract_address: Felt::ONE.into(),
+                class_hash: None,
+                selector: None,
+                keys: Vec::new(),
+                data: vec![Felt::from(data)],
+            }),
+        }
+    }
+
+    fn hash(height: u64) -> Felt {
+        Felt::from(height + 100)
+    }
+
+    fn event_data(events: Option<Vec<StarknetEvent>>) -> Option<Vec<Felt>> {
+        events.map(|events| events.iter().map(|event| event.data[0]).collect())
+    }
+
+    #[test]
+    fn test_subscription_covers_complete_blocks() {
+        let subscription = StarknetEventSubscription::default();
+
+        subscription.on_event(10, hash(10), event(1));
+        subscription.on_new_head(10, hash(10), hash(9));
+
+        // the first head is not covered, as its events may have been missed
+        assert!(subscription.block_events(10).is_none());
+
+        subscription.on_event(11, hash(11), event(2));
+        subscription.on_new_head(11, hash(11), hash(10));
+        subscription.on_new_head(12, hash(12), hash(11));
+
+        // the events of a block may still be delivered after the next head
+        assert!(!subscription.is_live());
+        assert!(subscription.block_events(11).is_none());
+
+        subscription.on_event(11, hash(11), event(3));
+        subscription.on_new_head(13, hash(13), hash(12));
+
+        assert!(subscription.is_live());
+        assert_eq!(
+            event_data(subscription.block_events(11)),
+            Some(vec![Felt::from(2), Felt::from(3)])
+        );
+        assert!(subscription.block_events(12).is_none());
+
+        subscription.on_new_head(14, hash(14), hash(13));
+
+        assert_eq!(event_data(subscription.block_events(12)), Some(vec![]));
+    }
+
+    #[test]
+    fn test_subscription_reorg() {
+        let subscription = StarknetEventSubscription::default();
+
+        subscription.on_new_head(10, hash(10), hash(9));
+        subscription.on_new_head(11, hash(11), hash(10));
+        subscription.on_new_head(12, hash(12), hash(11));
+        subscription.on_new_head(13, hash(13), hash(12));
+
+        assert!(subscription.block_events(11).is_some());
+
+        // a head whose parent does not match the previous block resets the subscription
+        subscription.on_new_head(14, hash(14), Felt::ZERO);
+
+        assert!(!subscription.is_live());
+        assert!(subscription.block_events(11).is_none());
+
+        subscription.on_new_head(15, hash(15), hash(14));
+        subscription.on_new_head(16, hash(16), hash(15));
+        subscription.on_new_head(17, hash(17), hash(16));
+
+        assert!(subscription.block_events(15).is_some());
+
+        subscription.on_reorg(15);
+
+        assert!(subscription.block_events(15).is_none());
+    }
+}
@@ -0,0 +1,544 @@
+use core::future::Future;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use prometheus::core::Collector;
+use prometheus::proto::MetricType;
+use prometheus::{
+    CounterVec, Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use serde_json::{json, Map, Value};
+use starknet::core::types::{FeePayment, Felt, PriceUnit};
+
+const LATENCY_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+const SIZE_BUCKETS: &[f64] = &[1_000.0, 10_000.0, 100_000.0, 1_000_000.0, 10_000_000.0];
+
+/// Prometheus metrics of a Starknet chain, shared by all the clones of the chain context.
+///
+/// All the metrics carry the `chain_id` label of the chain, so that the metrics of
+/// several chains can be registered in the same [`StarknetTelemetry`] registry.
+#[derive(Clone, Debug)]
+pub struct StarknetMetrics {
+    pub chain_id: String,
+    pub tx_submitted: IntCounterVec,
+    pub tx_reverted: IntCounterVec,
+    pub fee_spent: CounterVec,
+    pub rpc_request_duration: HistogramVec,
+    pub rpc_errors: IntCounterVec,
+    pub rpc_response_size: HistogramVec,
+    pub feeder_request_duration: HistogramVec,
+    pub feeder_errors: IntCounterVec,
+    pub attestator_request_duration: HistogramVec,
+    pub attestator_requests: IntCounterVec,
+    pub chain_height: IntGauge,
+    pub client_update_lag: IntGauge,
+    pub pending_packets: IntGaugeVec,
+    /// Whether the last request to each upstream service succeeded.
+    pub last_outcomes: Arc<Mutex<BTreeMap<&'static str, RequestOutcome>>>,
+}
+
+#[derive(Debug)]
+pub struct RequestOutcome {
+    pub success: bool,
+    pub at: Instant,
+}
+
+/// Registry of the metrics of all the Starknet chains of the relayer, served by the
+/// telemetry server.
+#[derive(Clone, Debug, Default)]
+pub struct StarknetTelemetry {
+    pub registry: Registry,
+    pub chains: Arc<Mutex<BTreeMap<String, StarknetMetrics>>>,
+}
+
+impl StarknetMetrics {
+    pub fn new(chain_id: &str) -> Self {
+        let opts = |name: &str, help: &str| Opts::new(name, help).const_label("chain_id", chain_id);
+
+        let histogram_opts = |name: &str, help: &str, buckets: &[f64]| {
+            HistogramOpts::from(opts(name, help)).buckets(buckets.to_vec())
+        };
+
+        Self {
+            chain_id: chain_id.to_string(),
+            tx_submitted: IntCounterVec::new(
+                opts(
+                    "starknet_tx_submitted_total",
+                    "Transactions submitted by each relayer signer.",
+                ),
+                &["signer"],
+            )
+            .unwrap(),
+            tx_reverted: IntCounterVec::new(
+                opts(
+                    "starknet_tx_reverted_total",
+                    "Submitted transactions of each relayer signer that were reverted.",
+                ),
+                &["signer"],
+            )
+            .unwrap(),
+            fee_spent: CounterVec::new(
+                opts(
+                    "starknet_fee_spent_total",
+                    "Actual fee paid by each relayer signer, in the smallest unit of the fee token.",
+                ),
+                &["signer", "unit"],
+            )
+            .unwrap(),
+            rpc_request_duration: HistogramVec::new(
+                histogram_opts(
+                    "starknet_rpc_request_duration_seconds",
+                    "Latency of the JSON-RPC requests to the Starknet node.",
+                    LATENCY_BUCKETS,
+                ),
+                &["method"],
+            )
+            .unwrap(),
+            rpc_errors: IntCounterVec::new(
+                opts(
+                    "starknet_rpc_errors_total",
+                    "Failed JSON-RPC requests to the Starknet node.",
+                ),
+                &["method"],
+            )
+            .unwrap(),
+            rpc_response_size: HistogramVec::new(
+                histogram_opts(
+                    "starknet_rpc_response_size_bytes",
+                    "Size of the JSON-RPC responses of the Starknet node, such as the storage proofs.",
+                    SIZE_BUCKETS,
+                ),
+                &["method"],
+            )
+            .unwrap(),
+            feeder_request_duration: HistogramVec::new(
+                histogram_opts(
+                    "starknet_feeder_request_duration_seconds",
+                    "Latency of the requests to the Starknet feeder gateway.",
+                    LATENCY_BUCKETS,
+                ),
+                &["endpoint"],
+            )
+            .unwrap(),
+            feeder_errors: IntCounterVec::new(
+                opts(
+                    "starknet_feeder_errors_total",
+                    "Failed requests to the Starknet feeder gateway.",
+                ),
+                &["endpoint"],
+            )
+            .unwrap(),
+            attestator_request_duration: HistogramVec::new(
+                histogram_opts(
+                    "starknet_attestator_request_duration_seconds",
+                    "Latency of the attestation requests to each attestator.",
+                    LATENCY_BUCKETS,
+                ),
+                &["attestator"],
+            )
+            .unwrap(),
+            attestator_requests: IntCounterVec::new(
+                opts(
+                    "starknet_attestator_requests_total",
+                    "Attestation requests to each attestator, by result.",
+                ),
+                &["attestator", "result"],
+            )
+            .unwrap(),
+            chain_height: IntGauge::with_opts(opts(
+                "starknet_chain_height",
+                "Latest block height of the Starknet chain.",
+            ))
+            .unwrap(),
+            client_update_lag: IntGauge::with_opts(opts(
+                "starknet_client_update_lag_blocks",
+                "Target height minus the trusted height of the latest update of a Starknet client.",
+            ))
+            .unwrap(),
+            pending_packets: IntGaugeVec::new(
+                opts(
+                    "starknet_pending_packets",
+                    "Packets sent from Starknet whose commitment is not cleared yet.",
+                ),
+                &["port_id", "channel_id"],
+            )
+            .unwrap(),
+            last_outcomes: Default::default(),
+        }
+    }
+
+    /// Registers all the metrics of the chain in the given registry.
+    pub fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        let collectors: [Box<dyn Collector>; 13] = [
+            Box::new(self.tx_submitted.clone()),
+            Box::new(self.tx_reverted.clone()),
+            Box::new(self.fee_spent.clone()),
+            Box::new(self.rpc_request_duration.clone()),
+            Box::new(self.rpc_errors.clone()),
+            Box::new(self.rpc_response_size.clone()),
+            Box::new(self.feeder_request_duration.clone()),
+            Box::new(self.feeder_errors.clone()),
+            Box::new(self.attestator_request_duration.clone()),
+            Box::new(self.attestator_requests.clone()),
+            Box::new(self.chain_height.clone()),
+            Box::new(self.client_update_lag.clone()),
+            Box::new(self.pending_packets.clone()),
+        ];
+
+        for collector in collectors {
+            registry.register(collector)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn record_tx_submitted(&self, signer: &Felt) {
+        self.tx_submitted
+            .with_label_values(&[&signer.to_fixed_hex_string()])
+            .inc();
+    }
+
+    pub fn record_tx_reverted(&self, signer: &Felt) {
+        self.tx_reverted
+            .with_label_values(&[&signer.to_fixed_hex_string()])
+            .inc();
+    }
+
+    pub fn record_fee_spent(&self, signer: &Felt, fee: &FeePayment) {
+        let unit = match fee.unit {
+            PriceUnit::Wei => "wei",
+            PriceUnit::Fri => "fri",
+        };
+
+        self.fee_spent
+            .with_label_values(&[&signer.to_fixed_hex_string(), unit])
+            .inc_by(felt_to_f64(&fee.amount));
+    }
+
+    pub fn record_rpc_request(&self, method: &str, duration: Duration, success: bool) {
+        self.rpc_request_duration
+            .with_label_values(&[method])
+            .observe(duration.as_secs_f64());
+
+        if !success {
+            self.rpc_errors.with_label_values(&[method]).inc();
+        }
+
+        self.record_outcome("rpc", success);
+    }
+
+    pub fn record_rpc_response_size(&self, method: &str, size: usize) {
+        self.rpc_response_size
+            .with_label_values(&[method])
+            .observe(size as f64);
+    }
+
+    pub fn record_feeder_request(&self, endpoint: &str, duration: Duration, success: bool) {
+        self.feeder_request_duration
+            .with_label_values(&[endpoint])
+            .observe(duration.as_secs_f64());
+
+        if !success {
+            self.feeder_errors.with_label_values(&[endpoint]).inc();
+        }
+
+        self.record_outcome("feeder_gateway", success);
+    }
+
+    pub fn record_attestation(&self, attestator: &str, duration: Duration, success: bool) {
+        self.attestator_request_duration
+            .with_label_values(&[attestator])
+            .observe(duration.as_secs_f64());
+
+        let result = if success { "success" } else { "failure" };
+
+        self.attestator_requests
+            .with_label_values(&[attestator, result])
+            .inc();
+    }
+
+    pub fn set_chain_height(&self, height: u64) {
+        self.chain_height.set(height as i64);
+    }
+
+    /// Records the number of Starknet blocks that a Starknet client on the counterparty
+    /// chain is behind when it is updated, i.e. the target height of the update minus
+    /// the trusted height of the client.
+    pub fn set_client_update_lag(&self, lag: u64) {
+        self.client_update_lag.set(lag as i64);
+    }
+
+    pub fn set_pending_packets(&self, port_id: &str, channel_id: &str, count: usize) {
+        self.pending_packets
+            .with_label_values(&[port_id, channel_id])
+            .set(count as i64);
+    }
+
+    pub async fn time_feeder_request<T, E>(
+        &self,
+        endpoint: &str,
+        request: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        let (result, duration) = time_request(request).await;
+
+        self.record_feeder_request(endpoint, duration, result.is_ok());
+
+        result
+    }
+
+    /// Returns the health status of the chain.
+    ///
+    /// The chain is reported as degraded if the last request to any of its upstream
+    /// services failed.
+    pub fn health_report(&self) -> (bool, Value) {
+        let last_outcomes = self.last_outcomes.lock().unwrap();
+
+        let failing: Vec<&str> = last_outcomes
+            .iter()
+            .filter(|(_, outcome)| !outcome.success)
+            .map(|(service, _)| *service)
+            .collect();
+
+        let healthy = failing.is_empty();
+
+        let services: Map<String, Value> = last_outcomes
+            .iter()
+            .map(|(service, outcome)| {
+                (
+                    service.to_string(),
+                    json!({
+                        "last_request_succeeded": outcome.success,
+                        "seconds_since_last_request": outcome.at.elapsed().as_secs(),
+                    }),
+                )
+            })
+            .collect();
+
+        let report = json!({
+            "status": if healthy { "ok" } else { "degraded" },
+            "failing_services": failing,
+            "services": services,
+        });
+
+        (healthy, report)
+    }
+
+    fn record_outcome(&self, service: &'static str, success: bool) {
+        self.last_outcomes.lock().unwrap().insert(
+            service,
+            RequestOutcome {
+                success,
+                at: Instant::now(),
+            },
+        );
+    }
+}
+
+impl StarknetTelemetry {
+    /// Returns the metrics of the given chain, registering them on first use.
+    ///
+    /// The returned flag is set when the metrics were just registered, so that the
+    /// caller can start monitoring the chain once.
+    pub fn chain_metrics(
+        &self,
+        chain_id: &str,
+    ) -> Result<(StarknetMetrics, bool), prometheus::Error> {
+        let mut chains = self.chains.lock().unwrap();
+
+        if let Some(metrics) = chains.get(chain_id) {
+            return Ok((metrics.clone(), false));
+        }
+
+        let metrics = StarknetMetrics::new(chain_id);
+
+        metrics.register(&self.registry)?;
+
+        chains.insert(chain_id.to_string(), metrics.clone());
+
+        Ok((metrics, true))
+    }
+
+    /// Encodes the metrics of all the chains in the Prometheus text exposition format.
+    pub fn encode_prometheus(&self) -> Result<String, prometheus::Error> {
+        let mut buffer = Vec::new();
+
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+
+    /// Returns the health status of all the chains together with all the metrics as JSON.
+    ///
+    /// The relayer is reported as degraded if any of its chains is degraded.
+    pub fn health_report(&self) -> (bool, Value) {
+        let mut healthy = true;
+
+        let chains: Map<String, Value> = self
+            .chains
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(chain_id, metrics)| {
+                let (chain_healthy, report) = metrics.health_report();
+
+                healthy &= chain_healthy;
+
+                (chain_id.clone(), report)
+            })
+            .collect();
+
+        let metrics: Map<String, Value> = self
+            .registry
+            .gather()
+            .iter()
+            .map(|family| {
+                let series = family
+                    .get_metric()
+                    .iter()
+                    .map(|metric| {
+                        let labels: Map<String, Value> = metric
+                            .get_label()
+                            .iter()
+                            .map(|label| (label.get_name().to_string(), label.get_value().into()))
+                            .collect();
+
+                        match family.get_field_type() {
+                            MetricType::COUNTER => json!({
+                                "labels": labels,
+                                "value": metric.get_counter().get_value(),
+                            }),
+                            MetricType::GAUGE => json!({
+                                "labels": labels,
+                                "value": metric.get_gauge().get_value(),
+                            }),
+                            _ => json!({
+                                "labels": labels,
+                                "sum": metric.get_histogram().get_sample_sum(),
+                                "count": metric.get_histogram().get_sample_count(),
+                            }),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                (family.get_name().to_string(), Value::from(series))
+            })
+            .collect();
+
+        let report = json!({
+            "status": if healthy { "ok" } else { "degraded" },
+            "chains": chains,
+            "metrics": metrics,
+        });
+
+        (healthy, report)
+    }
+}
+
+pub async fn time_request<T>(request: impl Future<Output = T>) -> (T, Duration) {
+    let start = Instant::now();
+    let output = request.await;
+
+    (output, start.elapsed())
+}
+
+fn felt_to_f64(value: &Felt) -> f64 {
+    value
+        .to_bytes_be()
+        .iter()
+        .fold(0.0, |acc, byte| acc * 256.0 + f64::from(*byte))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_prometheus() {
+        let telemetry = StarknetTelemetry::default();
+
+        let (metrics, registered) = telemetry.chain_metrics("SN_SEPOLIA").unwrap();
+
+        assert!(registered);
+
+        metrics.record_tx_submitted(&Felt::ONE);
+        metrics.record_tx_submitted(&Felt::ONE);
+        metrics.record_rpc_request("starknet_call", Duration::from_millis(200), true);
+        metrics.record_rpc_request("starknet_call", Duration::from_secs(60), false);
+        metrics.set_pending_packets("transfer", "channel-0", 3);
+
+        let (other_metrics, _) = telemetry.chain_metrics("SN_MAIN").unwrap();
+
+        other_metrics.set_chain_height(42);
+
+        let output = telemetry.encode_prometheus().unwrap();
+
+        let signer = Felt::ONE.to_fixed_hex_string();
+
+        assert!(output.contains("# TYPE starknet_tx_submitted_total counter\n"));
+        assert!(output.contains(&format!(
+            "starknet_tx_submitted_total{{chain_id=\"SN_SEPOLIA\",signer=\"{signer}\"}} 2\n"
+        )));
+        assert!(output.contains(
+            "starknet_rpc_request_duration_seconds_bucket{chain_id=\"SN_SEPOLIA\",method=\"starknet_call\",le=\"0.1\"} 0\n"
+        ));
+        assert!(output.contains(
+            "starknet_rpc_request_duration_seconds_bucket{chain_id=\"SN_SEPOLIA\",method=\"starknet_call\",le=\"0.25\"} 1\n"
+        ));
+        assert!(output.contains(
+            "starknet_rpc_request_duration_seconds_bucket{chain_id=\"SN_SEPOLIA\",method=\"starknet_call\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(output.contains(
+            "starknet_rpc_errors_total{chain_id=\"SN_SEPOLIA\",method=\"starknet_call\"} 1\n"
+        ));
+        assert!(output.contains(
+            "starknet_pending_packets{chain_id=\"SN_SEPOLIA\",channel_id=\"channel-0\",port_id=\"transfer\"} 3\n"
+        ));
+        assert!(output.contains("starknet_chain_height{chain_id=\"SN_MAIN\"} 42\n"));
+        assert!(output.contains("starknet_chain_height{chain_id=\"SN_SEPOLIA\"} 0\n"));
+        assert_eq!(
+            output
+                .matches("# TYPE starknet_chain_height gauge\n")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_health_report() {
+        let telemetry = StarknetTelemetry::default();
+
+        let (metrics, _) = telemetry.chain_metrics("SN_SEPOLIA").unwrap();
+
+        metrics.record_feeder_request("get_block", Duration::from_millis(100), true);
+        metrics.set_client_update_lag(42);
+
+        let (healthy, report) = telemetry.health_report();
+
+        assert!(healthy);
+        assert_eq!(report["status"], "ok");
+        assert_eq!(
+            report["metrics"]["starknet_client_update_lag_blocks"][0]["value"],
+            42.0
+        );
+
+        metrics.record_rpc_request("starknet_blockNumber", Duration::from_millis(100), false);
+
+        let (healthy, report) = telemetry.health_report();
+
+        assert!(!healthy);
+        assert_eq!(
+            report["chains"]["SN_SEPOLIA"]["failing_services"],
+            json!(["rpc"])
+        );
+
+        // registering the same chain again returns the shared metrics
+        let (same_metrics, registered) = telemetry.chain_metrics("SN_SEPOLIA").unwrap();
+
+        assert!(!registered);
+        assert!(!same_metrics.health_report().0);
+    }
+}
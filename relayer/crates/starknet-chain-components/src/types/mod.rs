@@ -28,6 +28,9 @@ pub use cosmos::*;
 mod event;
 pub use event::*;
 
+mod event_subscription;
+pub use event_subscription::*;
+
 mod events;
 pub use events::*;
 
@@ -40,12 +43,24 @@ pub use message_responses::*;
 mod messages;
 pub use messages::*;
 
+mod metrics;
+pub use metrics::*;
+
 mod payloads;
 pub use payloads::*;
 
 mod register;
 pub use register::*;
 
+mod rpc_transport;
+pub use rpc_transport::*;
+
+mod signer;
+pub use signer::*;
+
+mod signer_pool;
+pub use signer_pool::*;
+
 mod status;
 pub use status::*;
 
@@ -1,7 +1,10 @@
+use core::time::Duration;
+
 use hermes_prelude::*;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ChainId;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use ibc_client_starknet_types::{StarknetClientState, StarknetConsensusState};
 use starknet_v14::core::types::StorageProof;
 
@@ -14,16 +17,41 @@ pub struct StarknetCreateClientPayload {
     pub consensus_state: StarknetConsensusState,
     pub sequencer_public_key: Vec<u8>,
     pub ibc_contract_address: Vec<u8>,
+    pub trusting_period: Duration,
+    pub max_clock_drift: Duration,
+    pub max_consensus_states: u64,
 }
 
 #[derive(Clone, Debug)]
 pub struct StarknetCreateClientPayloadOptions {
     pub wasm_code_hash: [u8; 32],
+    pub trusting_period: Duration,
+    pub max_clock_drift: Duration,
+    pub max_consensus_states: u64,
+}
+
+impl StarknetCreateClientPayloadOptions {
+    pub const DEFAULT_TRUSTING_PERIOD: Duration = Duration::from_secs(14 * 24 * 3600);
+
+    pub const DEFAULT_MAX_CLOCK_DRIFT: Duration = Duration::from_secs(60);
+
+    pub const DEFAULT_MAX_CONSENSUS_STATES: u64 = 1000;
+
+    pub fn new(wasm_code_hash: [u8; 32]) -> Self {
+        Self {
+            wasm_code_hash,
+            trusting_period: Self::DEFAULT_TRUSTING_PERIOD,
+            max_clock_drift: Self::DEFAULT_MAX_CLOCK_DRIFT,
+            max_consensus_states: Self::DEFAULT_MAX_CONSENSUS_STATES,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct StarknetUpdateClientPayload {
     pub header: StarknetHeader,
+    /// Rotation to the sequencer public key signing `header`, submitted before it.
+    pub sequencer_key_rotation: Option<StarknetSequencerKeyRotation>,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,81 @@
+use std::time::Instant;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use starknet::providers::jsonrpc::{
+    HttpTransport, HttpTransportError, JsonRpcMethod, JsonRpcResponse, JsonRpcTransport,
+};
+use starknet::providers::{JsonRpcClient, ProviderRequestData};
+
+use crate::types::StarknetMetrics;
+
+pub type StarknetRpcClient = JsonRpcClient<StarknetRpcTransport>;
+
+/// HTTP transport of the Starknet JSON-RPC client that records the latency and the
+/// errors of each request in the chain metrics.
+#[derive(Debug)]
+pub struct StarknetRpcTransport {
+    pub transport: HttpTransport,
+    pub metrics: StarknetMetrics,
+}
+
+impl StarknetRpcTransport {
+    pub fn new(transport: HttpTransport, metrics: StarknetMetrics) -> Self {
+        Self { transport, metrics }
+    }
+}
+
+#[async_trait]
+impl JsonRpcTransport for StarknetRpcTransport {
+    type Error = HttpTransportError;
+
+    async fn send_request<P, R>(
+        &self,
+        method: JsonRpcMethod,
+        params: P,
+    ) -> Result<JsonRpcResponse<R>, Self::Error>
+    where
+        P: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let method_name = serde_json::to_value(method)
+            .ok()
+            .and_then(|value| value.as_str().map(String::from))
+            .unwrap_or_else(|| format!("{method:?}"));
+
+        let start = Instant::now();
+
+        let result = self.transport.send_request(method, params).await;
+
+        let success = matches!(result, Ok(JsonRpcResponse::Success { .. }));
+
+        self.metrics
+            .record_rpc_request(&method_name, start.elapsed(), success);
+
+        result
+    }
+
+    async fn send_requests<R>(
+        &self,
+        requests: R,
+    ) -> Result<Vec<JsonRpcResponse<serde_json::Value>>, Self::Error>
+    where
+        R: AsRef<[ProviderRequestData]> + Send + Sync,
+    {
+        let start = Instant::now();
+
+        let result = self.transport.send_requests(requests).await;
+
+        let success = result.as_ref().is_ok_and(|responses| {
+            responses
+                .iter()
+                .all(|response| matches!(response, JsonRpcResponse::Success { .. }))
+        });
+
+        self.metrics
+            .record_rpc_request("batch", start.elapsed(), success);
+
+        result
+    }
+}
@@ -0,0 +1,313 @@
+use core::fmt::Display;
+use core::time::Duration;
+use std::env::var;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use starknet::core::crypto::Signature;
+use starknet::core::types::Felt;
+use starknet::signers::{
+    local_wallet, Infallible, LocalWallet, Signer, SignerInteractivityContext, VerifyingKey,
+};
+
+/// A service that signs transaction hashes on behalf of a wallet, so that the signing
+/// key never has to be present on the relayer host.
+///
+/// The signer receives a `POST` request at `url` with the JSON body
+/// `{ "account_address": "0x..", "public_key": "0x..", "hash": "0x.." }`, and must
+/// respond with `{ "signature": ["0x<r>", "0x<s>"] }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarknetRemoteSignerConfig {
+    pub url: String,
+
+    /// Name of the environment variable containing the bearer token sent to the signer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token_env: Option<String>,
+
+    #[serde(default = "default_remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+/// The signer used by a Starknet account, which is either a signing key held in memory,
+/// or a remote signing service.
+#[derive(Debug, Clone)]
+pub enum StarknetSigner {
+    Local(LocalWallet),
+    Remote(StarknetRemoteSigner),
+}
+
+#[derive(Debug, Clone)]
+pub struct StarknetRemoteSigner {
+    pub client: reqwest::Client,
+    pub config: StarknetRemoteSignerConfig,
+    pub account_address: Felt,
+    pub public_key: VerifyingKey,
+}
+
+#[derive(Debug)]
+pub enum StarknetSignError {
+    Local(local_wallet::SignError),
+    Request(reqwest::Error),
+    MissingAuthToken(String),
+    InvalidSignature(String),
+}
+
+#[derive(Serialize)]
+struct RemoteSignRequest {
+    account_address: Felt,
+    public_key: Felt,
+    hash: Felt,
+}
+
+#[derive(Deserialize)]
+struct RemoteSignResponse {
+    signature: Vec<Felt>,
+}
+
+fn default_remote_signer_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+impl StarknetRemoteSigner {
+    pub fn new(
+        config: StarknetRemoteSignerConfig,
+        client: reqwest::Client,
+        account_address: Felt,
+        public_key: Felt,
+    ) -> Self {
+        Self {
+            client,
+            config,
+            account_address,
+            public_key: VerifyingKey::from_scalar(public_key),
+        }
+    }
+
+    pub async fn sign_hash(&self, hash: &Felt) -> Result<Signature, StarknetSignError> {
+        let mut request = self
+            .client
+            .post(&self.config.url)
+            .timeout(self.config.timeout)
+            .json(&RemoteSignRequest {
+                account_address: self.account_address,
+                public_key: self.public_key.scalar(),
+                hash: *hash,
+            });
+
+        if let Some(auth_token_env) = &self.config.auth_token_env {
+            // read on every request, so that the token can be rotated without a restart
+            let auth_token = var(auth_token_env)
+                .map_err(|_| StarknetSignError::MissingAuthToken(auth_token_env.clone()))?;
+
+            request = request.bearer_auth(auth_token);
+        }
+
+        let response: RemoteSignResponse = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(StarknetSignError::Request)?
+            .json()
+            .await
+            .map_err(StarknetSignError::Request)?;
+
+        let [r, s] = response.signature[..] else {
+            return Err(StarknetSignError::InvalidSignature(format!(
+                "expected a signature of 2 elements, got {}",
+                response.signature.len()
+            )));
+        };
+
+        let signature = Signature { r, s };
+
+        // a signature by any other key would only be rejected on chain, after paying for it
+        if !self.public_key.verify(hash, &signature).unwrap_or(false) {
+            return Err(StarknetSignError::InvalidSignature(format!(
+                "signature returned by {} does not match public key {}",
+                self.config.url,
+                self.public_key.scalar()
+            )));
+        }
+
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Signer for StarknetSigner {
+    type GetPublicKeyError = Infallible;
+
+    type SignError = StarknetSignError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Infallible> {
+        match self {
+            Self::Local(wallet) => wallet.get_public_key().await,
+            Self::Remote(signer) => Ok(signer.public_key.clone()),
+        }
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, StarknetSignError> {
+        match self {
+            Self::Local(wallet) => wallet
+                .sign_hash(hash)
+                .await
+                .map_err(StarknetSignError::Local),
+            Self::Remote(signer) => signer.sign_hash(hash).await,
+        }
+    }
+
+    fn is_interactive(&self, _context: SignerInteractivityContext<'_>) -> bool {
+        false
+    }
+}
+
+impl Display for StarknetSignError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Local(e) => write!(f, "failed to sign with local wallet: {e}"),
+            Self::Request(e) => write!(f, "request to remote signer failed: {e}"),
+            Self::MissingAuthToken(env) => {
+                write!(
+                    f,
+                    "remote signer auth token not found in environment variable `{env}`"
+                )
+            }
+            Self::InvalidSignature(message) => {
+                write!(f, "invalid signature from remote signer: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StarknetSignError {}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use serde_json::{json, Value};
+    use starknet::signers::SigningKey;
+
+    use super::*;
+
+    /// Serves a single signing request on a local port, responding with the signature
+    /// produced by `sign`, and returns the URL of the mock signer.
+    fn spawn_mock_signer(sign: impl FnOnce(Value) -> Value + Send + 'static) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/sign", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut content_length = 0;
+            let mut authorization = None;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                let line = line.trim_end();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some((name, value)) = line.split_once(": ") {
+                    match name.to_ascii_lowercase().as_str() {
+                        "content-length" => content_length = value.parse().unwrap(),
+                        "authorization" => authorization = Some(value.to_string()),
+                        _ => {}
+                    }
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut request: Value = serde_json::from_slice(&body).unwrap();
+            request["authorization"] = json!(authorization);
+
+            let response = sign(request).to_string();
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+        });
+
+        url
+    }
+
+    fn remote_signer(url: String, signing_key: &SigningKey) -> StarknetSigner {
+        StarknetSigner::Remote(StarknetRemoteSigner::new(
+            StarknetRemoteSignerConfig {
+                url,
+                auth_token_env: None,
+                timeout: default_remote_signer_timeout(),
+            },
+            reqwest::Client::new(),
+            Felt::from(0x1234u64),
+            signing_key.verifying_key().scalar(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(0xabcdu64));
+        let hash = Felt::from(0x5678u64);
+
+        let url = spawn_mock_signer({
+            let signing_key = signing_key.clone();
+
+            move |request| {
+                assert_eq!(request["account_address"], json!(Felt::from(0x1234u64)));
+                assert_eq!(request["authorization"], Value::Null);
+
+                let hash: Felt = serde_json::from_value(request["hash"].clone()).unwrap();
+                let signature = signing_key.sign(&hash).unwrap();
+
+                json!({ "signature": [signature.r, signature.s] })
+            }
+        });
+
+        let signer = remote_signer(url, &signing_key);
+
+        let signature = signer.sign_hash(&hash).await.unwrap();
+        let expected_signature = signing_key.sign(&hash).unwrap();
+
+        assert_eq!(
+            (signature.r, signature.s),
+            (expected_signature.r, expected_signature.s)
+        );
+
+        assert_eq!(
+            signer.get_public_key().await.unwrap().scalar(),
+            signing_key.verifying_key().scalar()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_rejects_wrong_key() {
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(0xabcdu64));
+        let other_key = SigningKey::from_secret_scalar(Felt::from(0xdcbau64));
+
+        let url = spawn_mock_signer(move |request| {
+            let hash: Felt = serde_json::from_value(request["hash"].clone()).unwrap();
+            let signature = other_key.sign(&hash).unwrap();
+
+            json!({ "signature": [signature.r, signature.s] })
+        });
+
+        let signer = remote_signer(url, &signing_key);
+
+        assert!(matches!(
+            signer.sign_hash(&Felt::from(0x5678u64)).await,
+            Err(StarknetSignError::InvalidSignature(_))
+        ));
+    }
+}
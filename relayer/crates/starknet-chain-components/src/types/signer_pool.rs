@@ -0,0 +1,180 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use starknet::core::types::Felt;
+
+use crate::impls::{SignerSelectionStrategy, StarknetSignerPoolConfig};
+
+/// Tracks the relayer signers of a Starknet chain, so that batches of messages are
+/// spread across them.
+///
+/// The nonce of each signer is allocated separately, so that the transactions of a
+/// signer do not have to wait for the transactions of the others.
+#[derive(Clone)]
+pub struct StarknetSignerPool {
+    pub config: StarknetSignerPoolConfig,
+    pub slots: Arc<Vec<SignerSlot>>,
+    pub next_index: Arc<Mutex<usize>>,
+}
+
+pub struct SignerSlot {
+    /// The nonce expected for the next transaction of the signer, if known.
+    ///
+    /// The lock is held while a transaction of the signer is being submitted.
+    pub next_nonce: futures::lock::Mutex<Option<Felt>>,
+    pub status: Mutex<SignerStatus>,
+}
+
+#[derive(Default)]
+pub struct SignerStatus {
+    /// Number of batches assigned to the signer that are not completed yet.
+    pub pending: usize,
+    pub balance_checked_at: Option<Instant>,
+    pub below_min_balance: bool,
+}
+
+/// A signer selected by the pool. The signer is counted as pending until this is dropped.
+pub struct SignerLease<'a> {
+    pub pool: &'a StarknetSignerPool,
+    pub index: usize,
+}
+
+impl StarknetSignerPool {
+    pub fn new(signer_count: usize, config: StarknetSignerPoolConfig) -> Self {
+        let slots = (0..signer_count)
+            .map(|_| SignerSlot {
+                next_nonce: futures::lock::Mutex::new(None),
+                status: Mutex::new(SignerStatus::default()),
+            })
+            .collect();
+
+        Self {
+            config,
+            slots: Arc::new(slots),
+            next_index: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// Returns the signers whose balance has not been checked within the configured interval.
+    pub fn signers_due_for_balance_check(&self) -> Vec<usize> {
+        if self.config.min_balance.is_none() {
+            return Vec::new();
+        }
+
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| {
+                slot.status
+                    .lock()
+                    .unwrap()
+                    .balance_checked_at
+                    .map_or(true, |checked_at| {
+                        checked_at.elapsed() >= self.config.balance_check_interval
+                    })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub fn update_balance_status(&self, index: usize, below_min_balance: bool) {
+        let mut status = self.slots[index].status.lock().unwrap();
+
+        status.balance_checked_at = Some(Instant::now());
+        status.below_min_balance = below_min_balance;
+    }
+
+    /// Selects the signer of the next batch, skipping the signers with a balance below
+    /// the minimum. Returns `None` if there is no such signer.
+    pub fn acquire_signer(&self) -> Option<SignerLease<'_>> {
+        let mut next_index = self.next_index.lock().unwrap();
+
+        let signer_count = self.slots.len();
+
+        let mut eligible = (0..signer_count)
+            .map(|offset| (*next_index + offset) % signer_count)
+            .filter(|index| !self.slots[*index].status.lock().unwrap().below_min_balance);
+
+        let index = match self.config.strategy {
+            SignerSelectionStrategy::RoundRobin => eligible.next(),
+            // ties are broken in round-robin order
+            SignerSelectionStrategy::LeastPending => {
+                eligible.min_by_key(|index| self.slots[*index].status.lock().unwrap().pending)
+            }
+        }?;
+
+        *next_index = (index + 1) % signer_count;
+
+        self.slots[index].status.lock().unwrap().pending += 1;
+
+        Some(SignerLease { pool: self, index })
+    }
+}
+
+impl Drop for SignerLease<'_> {
+    fn drop(&mut self) {
+        self.pool.slots[self.index].status.lock().unwrap().pending -= 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pool(strategy: SignerSelectionStrategy) -> StarknetSignerPool {
+        StarknetSignerPool::new(
+            3,
+            StarknetSignerPoolConfig {
+                strategy,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_round_robin_selection() {
+        let pool = pool(SignerSelectionStrategy::RoundRobin);
+
+        let indices: Vec<_> = (0..4)
+            .map(|_| pool.acquire_signer().unwrap().index)
+            .collect();
+
+        assert_eq!(indices, vec![0, 1, 2, 0]);
+
+        pool.update_balance_status(2, true);
+
+        let indices: Vec<_> = (0..3)
+            .map(|_| pool.acquire_signer().unwrap().index)
+            .collect();
+
+        assert_eq!(indices, vec![1, 0, 1]);
+
+        pool.update_balance_status(0, true);
+        pool.update_balance_status(1, true);
+
+        assert!(pool.acquire_signer().is_none());
+    }
+
+    #[test]
+    fn test_least_pending_selection() {
+        let pool = pool(SignerSelectionStrategy::LeastPending);
+
+        let lease_a = pool.acquire_signer().unwrap();
+        let lease_b = pool.acquire_signer().unwrap();
+
+        assert_eq!((lease_a.index, lease_b.index), (0, 1));
+
+        drop(lease_a);
+
+        // signer 0 is idle again, while signer 1 is still pending
+        let lease_c = pool.acquire_signer().unwrap();
+        let lease_d = pool.acquire_signer().unwrap();
+
+        assert_eq!((lease_c.index, lease_d.index), (2, 0));
+
+        let lease_e = pool.acquire_signer().unwrap();
+
+        assert_eq!(lease_e.index, 1);
+        assert_eq!(pool.slots[1].status.lock().unwrap().pending, 2);
+    }
+}
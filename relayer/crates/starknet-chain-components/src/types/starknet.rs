@@ -53,6 +53,11 @@ impl TryFrom<CairoStarknetClientState> for StarknetClientState {
             sequencer_public_key: sequencer_public_key.to_bytes_be().to_vec(),
             ibc_contract_address: ibc_contract_address.to_bytes_be().to_vec(),
             is_frozen,
+            // client-chosen parameters are not stored on Starknet;
+            // the light client keeps its own values on upgrade.
+            trusting_period: 0,
+            max_clock_drift: 0,
+            max_consensus_states: 0,
         })
     }
 }
@@ -77,6 +82,7 @@ impl From<StarknetClientState> for CairoStarknetClientState {
             sequencer_public_key,
             ibc_contract_address,
             is_frozen,
+            ..
         } = value;
 
         Self {
@@ -1,5 +1,5 @@
 use starknet::core::types::{
-    ExecutionResources, ExecutionResult, RevertedInvocation, TransactionReceipt,
+    ExecutionResources, ExecutionResult, FeePayment, RevertedInvocation, TransactionReceipt,
     TransactionReceiptWithBlockInfo, TransactionTrace,
 };
 
@@ -69,4 +69,14 @@ impl TxResponse {
             TransactionReceipt::DeployAccount(receipt) => &receipt.execution_resources,
         }
     }
+
+    pub fn actual_fee(&self) -> &FeePayment {
+        match &self.receipt.receipt {
+            TransactionReceipt::Invoke(receipt) => &receipt.actual_fee,
+            TransactionReceipt::L1Handler(receipt) => &receipt.actual_fee,
+            TransactionReceipt::Declare(receipt) => &receipt.actual_fee,
+            TransactionReceipt::Deploy(receipt) => &receipt.actual_fee,
+            TransactionReceipt::DeployAccount(receipt) => &receipt.actual_fee,
+        }
+    }
 }
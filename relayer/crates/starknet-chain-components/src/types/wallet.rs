@@ -1,14 +1,78 @@
+use std::env::var;
+use std::path::{Path, PathBuf};
+
 use serde::{Deserialize, Serialize};
 use starknet::core::types::Felt;
-use starknet::signers::SigningKey;
+use starknet::signers::{LocalWallet, SigningKey};
 
 use crate::impls::StarknetAddress;
+use crate::types::{StarknetRemoteSigner, StarknetRemoteSignerConfig, StarknetSigner};
+
+pub const DEFAULT_KEYSTORE_PASSWORD_ENV: &str = "STARKNET_KEYSTORE_PASSWORD";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StarknetWallet {
     pub account_address: StarknetAddress,
-    pub signing_key: Felt,
     pub public_key: Felt,
+    #[serde(flatten)]
+    pub signer: StarknetWalletSigner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum StarknetWalletSigner {
+    Local {
+        signing_key: Felt,
+    },
+    Remote {
+        remote_signer: StarknetRemoteSignerConfig,
+        /// Shared by all the signers built from the wallet, so that they reuse the
+        /// connections to the remote signer.
+        #[serde(skip)]
+        client: reqwest::Client,
+    },
+}
+
+/// The content of a wallet file. Exactly one of `signing_key`, `keystore` or
+/// `remote_signer` must be set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarknetWalletConfig {
+    pub account_address: StarknetAddress,
+
+    /// Required with a remote signer. Otherwise, it is checked against the signing key
+    /// if present.
+    #[serde(default)]
+    pub public_key: Option<Felt>,
+
+    /// Plaintext signing key. Only meant for local development, and rejected
+    /// unless `allow_plaintext_key` is set.
+    #[serde(default)]
+    pub signing_key: Option<Felt>,
+
+    #[serde(default)]
+    pub allow_plaintext_key: bool,
+
+    #[serde(default)]
+    pub keystore: Option<StarknetKeystoreConfig>,
+
+    #[serde(default)]
+    pub remote_signer: Option<StarknetRemoteSignerConfig>,
+}
+
+/// An encrypted JSON keystore, such as one created with `starkli signer keystore`.
+/// The keystore is only decrypted in memory when the wallet is loaded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarknetKeystoreConfig {
+    /// Relative paths are resolved against the directory of the wallet file.
+    pub path: PathBuf,
+
+    /// Name of the environment variable containing the keystore password.
+    #[serde(default = "default_keystore_password_env")]
+    pub password_env: String,
+}
+
+fn default_keystore_password_env() -> String {
+    DEFAULT_KEYSTORE_PASSWORD_ENV.to_string()
 }
 
 impl StarknetWallet {
@@ -19,8 +83,249 @@ impl StarknetWallet {
 
         Self {
             account_address: account_address.into(),
-            signing_key,
             public_key,
+            signer: StarknetWalletSigner::Local { signing_key },
+        }
+    }
+
+    /// The signing key of the wallet, unless signing is done by a remote signer.
+    pub fn local_signing_key(&self) -> Option<&Felt> {
+        match &self.signer {
+            StarknetWalletSigner::Local { signing_key } => Some(signing_key),
+            StarknetWalletSigner::Remote { .. } => None,
         }
     }
+
+    pub fn build_signer(&self) -> StarknetSigner {
+        match &self.signer {
+            StarknetWalletSigner::Local { signing_key } => StarknetSigner::Local(
+                LocalWallet::from_signing_key(SigningKey::from_secret_scalar(*signing_key)),
+            ),
+            StarknetWalletSigner::Remote {
+                remote_signer,
+                client,
+            } => StarknetSigner::Remote(StarknetRemoteSigner::new(
+                remote_signer.clone(),
+                client.clone(),
+                *self.account_address,
+                self.public_key,
+            )),
+        }
+    }
+
+    /// The content of the wallet file of the wallet. A local signing key is written in
+    /// plaintext, so this is only meant for the wallets of local development chains.
+    pub fn to_config(&self) -> StarknetWalletConfig {
+        let (signing_key, remote_signer) = match &self.signer {
+            StarknetWalletSigner::Local { signing_key } => (Some(*signing_key), None),
+            StarknetWalletSigner::Remote { remote_signer, .. } => {
+                (None, Some(remote_signer.clone()))
+            }
+        };
+
+        StarknetWalletConfig {
+            account_address: self.account_address,
+            public_key: Some(self.public_key),
+            allow_plaintext_key: signing_key.is_some(),
+            signing_key,
+            keystore: None,
+            remote_signer,
+        }
+    }
+}
+
+impl StarknetWalletConfig {
+    /// Loads the wallet, decrypting its keystore if there is one with the password
+    /// read from the configured environment variable.
+    pub fn load_wallet(self, wallet_dir: &Path) -> Result<StarknetWallet, String> {
+        let password = self
+            .keystore
+            .as_ref()
+            .map(|keystore| {
+                var(&keystore.password_env).map_err(|_| {
+                    format!(
+                        "keystore password not found in environment variable `{}`",
+                        keystore.password_env
+                    )
+                })
+            })
+            .transpose()?;
+
+        self.load_wallet_with_password(wallet_dir, password.as_deref())
+    }
+
+    /// Loads the wallet, decrypting its keystore if there is one with `password`.
+    pub fn load_wallet_with_password(
+        self,
+        wallet_dir: &Path,
+        password: Option<&str>,
+    ) -> Result<StarknetWallet, String> {
+        let signing_key = match (self.signing_key, self.keystore, self.remote_signer) {
+            (Some(signing_key), None, None) => {
+                if !self.allow_plaintext_key {
+                    return Err(
+                        "plaintext `signing_key` is only accepted with `allow_plaintext_key = true`, use `keystore` or `remote_signer` instead"
+                            .to_string(),
+                    );
+                }
+
+                SigningKey::from_secret_scalar(signing_key)
+            }
+            (None, Some(keystore), None) => {
+                let password = password.ok_or("a password is required to decrypt the keystore")?;
+
+                let path = wallet_dir.join(&keystore.path);
+
+                SigningKey::from_keystore(&path, password)
+                    .map_err(|e| format!("failed to decrypt keystore at {}: {e}", path.display()))?
+            }
+            (None, None, Some(remote_signer)) => {
+                let public_key = self
+                    .public_key
+                    .ok_or("`public_key` must be set when using a remote signer")?;
+
+                return Ok(StarknetWallet {
+                    account_address: self.account_address,
+                    public_key,
+                    signer: StarknetWalletSigner::Remote {
+                        remote_signer,
+                        client: reqwest::Client::new(),
+                    },
+                });
+            }
+            _ => {
+                return Err(
+                    "exactly one of `signing_key`, `keystore` or `remote_signer` must be set"
+                        .to_string(),
+                )
+            }
+        };
+
+        let public_key = signing_key.verifying_key().scalar();
+
+        if let Some(expected_public_key) = self.public_key {
+            if expected_public_key != public_key {
+                return Err(format!(
+                    "public key {expected_public_key} does not match the signing key, which has public key {public_key}"
+                ));
+            }
+        }
+
+        Ok(StarknetWallet {
+            account_address: self.account_address,
+            public_key,
+            signer: StarknetWalletSigner::Local {
+                signing_key: signing_key.secret_scalar(),
+            },
+        })
+    }
+
+    pub fn is_plaintext(&self) -> bool {
+        self.signing_key.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load_keystore_wallet() {
+        let wallet_dir =
+            std::env::temp_dir().join(format!("starknet-wallet-{}", std::process::id()));
+        std::fs::create_dir_all(&wallet_dir).unwrap();
+
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(0xabcdu64));
+
+        signing_key
+            .save_as_keystore(wallet_dir.join("keystore.json"), "password")
+            .unwrap();
+
+        let config: StarknetWalletConfig = toml::from_str(
+            r#"
+                account_address = "0x1234"
+                keystore = { path = "keystore.json" }
+            "#,
+        )
+        .unwrap();
+
+        let wallet = config
+            .clone()
+            .load_wallet_with_password(&wallet_dir, Some("password"))
+            .unwrap();
+
+        assert_eq!(wallet.local_signing_key(), Some(&Felt::from(0xabcdu64)));
+        assert_eq!(wallet.public_key, signing_key.verifying_key().scalar());
+
+        assert!(config
+            .clone()
+            .load_wallet_with_password(&wallet_dir, Some("wrong password"))
+            .is_err());
+
+        assert!(config.load_wallet_with_password(&wallet_dir, None).is_err());
+
+        std::fs::remove_dir_all(&wallet_dir).unwrap();
+    }
+
+    #[test]
+    fn test_plaintext_key_must_be_allowed() {
+        let config: StarknetWalletConfig = toml::from_str(
+            r#"
+                account_address = "0x1234"
+                signing_key = "0xabcd"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.clone().load_wallet(Path::new(".")).is_err());
+
+        let config = StarknetWalletConfig {
+            allow_plaintext_key: true,
+            ..config
+        };
+
+        let wallet = config.load_wallet(Path::new(".")).unwrap();
+
+        assert_eq!(wallet.local_signing_key(), Some(&Felt::from(0xabcdu64)));
+
+        // the wallet files written for local chains can be loaded back
+        let wallet_str = toml::to_string(&wallet.to_config()).unwrap();
+        let config: StarknetWalletConfig = toml::from_str(&wallet_str).unwrap();
+
+        assert!(config.allow_plaintext_key);
+        assert_eq!(
+            config.load_wallet(Path::new(".")).unwrap().public_key,
+            wallet.public_key
+        );
+    }
+
+    #[test]
+    fn test_load_wallet_requires_single_signer() {
+        let config: StarknetWalletConfig = toml::from_str(
+            r#"
+                account_address = "0x1234"
+                public_key = "0x5678"
+                signing_key = "0xabcd"
+                allow_plaintext_key = true
+                remote_signer = { url = "http://localhost:8080/sign" }
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.load_wallet(Path::new(".")).is_err());
+
+        let config: StarknetWalletConfig = toml::from_str(
+            r#"
+                account_address = "0x1234"
+                public_key = "0x5678"
+                remote_signer = { url = "http://localhost:8080/sign" }
+            "#,
+        )
+        .unwrap();
+
+        let wallet = config.load_wallet(Path::new(".")).unwrap();
+
+        assert!(wallet.local_signing_key().is_none());
+        assert_eq!(wallet.public_key, Felt::from(0x5678u64));
+    }
 }
@@ -20,13 +20,10 @@ use hermes_core::encoding_components::traits::{
 };
 use hermes_core::encoding_components::types::AsBytes;
 use hermes_core::logging_components::traits::LoggerComponent;
-use hermes_core::relayer_components::transaction::impls::{
-    GetGlobalNonceMutex, GetGlobalSignerMutex, SignerWithIndexGetter,
-};
+use hermes_core::relayer_components::transaction::impls::GetGlobalNonceMutex;
 use hermes_core::relayer_components::transaction::traits::{
     BatchConfigGetter, BatchConfigGetterComponent, ClientRefreshRateGetterComponent,
     DefaultSignerGetterComponent, NonceAllocationMutexGetterComponent, NonceQuerierComponent,
-    SignerGetterComponent, SignerMutexGetterComponent,
 };
 use hermes_core::runtime_components::traits::{
     RuntimeGetterComponent, RuntimeTypeProviderComponent,
@@ -41,26 +38,37 @@ use hermes_starknet_chain_components::components::{
     StarknetChainComponents, StarknetToCosmosComponents,
 };
 use hermes_starknet_chain_components::impls::{
-    GetStarknetClientRefreshRate, QueryStarknetStorageProof, SendJsonRpcRequestWithReqwest,
-    StarknetAddress, StarknetChainConfig, VerifyStarknetMerkleProof, VerifyStarknetStorageProof,
+    GetStarknetAttestationConfig, GetStarknetClientRefreshRate, GetStarknetEventChunkSize,
+    GetStarknetFeeConfig, GetStarknetPacketFilterConfig, QueryStarknetStorageProof,
+    SendJsonRpcRequestWithReqwest, StarknetAddress, StarknetChainConfig, VerifyStarknetMerkleProof,
+    VerifyStarknetStorageProof,
 };
 use hermes_starknet_chain_components::traits::{
-    AccountFromSignerBuilderComponent, ContractCallerComponent, ContractDeclarerComponent,
-    ContractDeployerComponent, ContractInvokerComponent, Ed25519AttestatorAddressesGetterComponent,
+    AccountFromSignerBuilderComponent, AttestationConfigGetterComponent,
+    AttestatorHttpClientGetterComponent, BatchedCommitmentProofQuerierComponent,
+    BlockEventsInRangeQuerierComponent, ChannelsQuerierComponent, ContractCallerComponent,
+    ContractDeclarerComponent, ContractDeployerComponent, ContractInvokerComponent,
+    CosmosTokenDenomOnStarknetQuerierComponent, Ed25519AttestatorAddressesGetterComponent,
+    EventChunkSizeGetterComponent, FeeConfigGetterComponent, FeederGatewayEndpointGetterComponent,
     FeederGatewayUrlGetterComponent, InvokeContractMessageBuilderComponent,
     JsonRpcRequestSenderComponent, JsonRpcUrlGetterComponent, MerkleProofTypeProviderComponent,
-    ReqwestClientGetterComponent, StarknetAccountTypeProviderComponent,
-    StarknetClientGetterComponent, StarknetClientTypeProviderComponent,
-    StarknetMerkleProofVerifierComponent, StarknetStorageProofVerifierComponent,
+    PacketFilterConfigGetterComponent, PacketSequencesQuerierComponent,
+    ReqwestClientGetterComponent, SendPacketEventsQuerierComponent,
+    StarknetAccountTypeProviderComponent, StarknetClientGetterComponent,
+    StarknetClientTypeProviderComponent, StarknetEventSubscriptionGetterComponent,
+    StarknetMerkleProofVerifierComponent, StarknetMetricsGetterComponent,
+    StarknetSignerPoolGetterComponent, StarknetStorageProofVerifierComponent,
     StorageKeyTypeProviderComponent, StorageProofQuerierComponent,
     StorageProofTypeProviderComponent,
 };
-use hermes_starknet_chain_components::types::StarknetWallet;
+use hermes_starknet_chain_components::types::{
+    StarknetEventSubscription, StarknetMetrics, StarknetRpcClient, StarknetSignerPool,
+    StarknetWallet,
+};
 use ibc::core::host::types::identifiers::ChainId;
 use indexmap::IndexMap;
 use starknet::core::types::Felt;
-use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
+use starknet_block_verifier::AsyncEndpoint;
 use starknet_v14::core::types::{MerkleNode, StorageProof};
 use ureq::Agent;
 use url::Url;
@@ -80,10 +88,11 @@ pub struct StarknetChainFields {
     pub runtime: HermesRuntime,
     pub chain_id: ChainId,
     pub chain_config: StarknetChainConfig,
-    pub starknet_client: Arc<JsonRpcClient<HttpTransport>>,
+    pub starknet_client: Arc<StarknetRpcClient>,
     pub rpc_client: Agent,
     pub json_rpc_url: Url,
     pub feeder_gateway_url: Url,
+    pub feeder_gateway_endpoint: AsyncEndpoint,
     pub ibc_client_contract_address: OnceLock<StarknetAddress>,
     pub ibc_core_contract_address: OnceLock<StarknetAddress>,
     pub ibc_ics20_contract_address: OnceLock<StarknetAddress>,
@@ -93,8 +102,11 @@ pub struct StarknetChainFields {
     pub nonce_mutex: Arc<Mutex<()>>,
     pub signers: Vec<StarknetWallet>,
     pub client_refresh_rate: Option<Duration>,
-    pub signer_mutex: Arc<Mutex<usize>>,
     pub ed25519_attestator_addresses: Option<Vec<String>>,
+    pub attestator_http_client: reqwest::Client,
+    pub event_subscription: Option<StarknetEventSubscription>,
+    pub signer_pool: StarknetSignerPool,
+    pub metrics: StarknetMetrics,
 }
 
 impl Deref for StarknetChain {
@@ -135,6 +147,20 @@ delegate_components! {
             UseField<symbol!("rpc_client")>,
         FeederGatewayUrlGetterComponent:
             UseField<symbol!("feeder_gateway_url")>,
+        FeederGatewayEndpointGetterComponent:
+            UseField<symbol!("feeder_gateway_endpoint")>,
+        PacketFilterConfigGetterComponent:
+            GetStarknetPacketFilterConfig,
+        EventChunkSizeGetterComponent:
+            GetStarknetEventChunkSize,
+        FeeConfigGetterComponent:
+            GetStarknetFeeConfig,
+        StarknetEventSubscriptionGetterComponent:
+            UseField<symbol!("event_subscription")>,
+        StarknetSignerPoolGetterComponent:
+            UseField<symbol!("signer_pool")>,
+        StarknetMetricsGetterComponent:
+            UseField<symbol!("metrics")>,
         JsonRpcUrlGetterComponent:
             UseField<symbol!("json_rpc_url")>,
         LoggerComponent:
@@ -146,12 +172,12 @@ delegate_components! {
             WithField<symbol!("starknet_client")>,
         Ed25519AttestatorAddressesGetterComponent:
             UseField<symbol!("ed25519_attestator_addresses")>,
+        AttestatorHttpClientGetterComponent:
+            UseField<symbol!("attestator_http_client")>,
+        AttestationConfigGetterComponent:
+            GetStarknetAttestationConfig,
         DefaultSignerGetterComponent:
             GetFirstSignerAsDefault<symbol!("signers")>,
-        SignerMutexGetterComponent:
-            GetGlobalSignerMutex<symbol!("signer_mutex"), symbol!("signers")>,
-        SignerGetterComponent:
-            SignerWithIndexGetter<symbol!("signers")>,
         NonceAllocationMutexGetterComponent:
             GetGlobalNonceMutex<symbol!("nonce_mutex")>,
         ClientRefreshRateGetterComponent:
@@ -225,6 +251,12 @@ check_components! {
         NonceQuerierComponent,
         ChainStatusQuerierComponent,
         StorageProofQuerierComponent,
+        BatchedCommitmentProofQuerierComponent,
+        BlockEventsInRangeQuerierComponent,
+        ChannelsQuerierComponent,
+        CosmosTokenDenomOnStarknetQuerierComponent,
+        PacketSequencesQuerierComponent,
+        SendPacketEventsQuerierComponent,
         StarknetMerkleProofVerifierComponent,
         StarknetStorageProofVerifierComponent,
     }
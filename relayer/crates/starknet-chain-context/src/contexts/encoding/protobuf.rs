@@ -17,6 +17,7 @@ use ibc::clients::wasm_types::client_message::ClientMessage;
 use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::primitives::Timestamp;
 use ibc_client_starknet_types::header::StarknetHeader;
+use ibc_client_starknet_types::key_rotation::StarknetSequencerKeyRotation;
 use ibc_client_starknet_types::misbehaviour::StarknetMisbehaviour;
 
 use crate::impls::HandleStarknetChainError;
@@ -45,6 +46,7 @@ pub trait CanUseStarknetProtobufEncoding:
     + CanConvertBothWays<WasmStarknetClientState, Any>
     + CanConvertBothWays<WasmStarknetConsensusState, Any>
     + CanConvertBothWays<StarknetHeader, Any>
+    + CanConvertBothWays<StarknetSequencerKeyRotation, Any>
     + CanEncodeAndDecodeMut<ViaProtobuf, Timestamp>
     + CanEncodeAndDecodeMut<ViaProtobuf, CommitmentRoot>
 {
@@ -8,20 +8,17 @@ use hermes_starknet_chain_components::traits::{
     AccountFromSignerBuilder, AccountFromSignerBuilderComponent, HasStarknetAccountType,
     HasStarknetClient,
 };
-use hermes_starknet_chain_components::types::StarknetWallet;
+use hermes_starknet_chain_components::types::{StarknetRpcClient, StarknetWallet};
 use ibc::core::host::types::identifiers::ChainId;
 use starknet::accounts::{ExecutionEncoding, SingleOwnerAccount};
 use starknet::core::types::Felt;
-use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
-use starknet::signers::{LocalWallet, SigningKey};
 
 use crate::types::StarknetAccount;
 
 #[cgp_new_provider(AccountFromSignerBuilderComponent)]
 impl<Chain> AccountFromSignerBuilder<Chain> for BuildStarknetAccount
 where
-    Chain: HasStarknetClient<Client = Arc<JsonRpcClient<HttpTransport>>>
+    Chain: HasStarknetClient<Client = Arc<StarknetRpcClient>>
         + HasChainId<ChainId = ChainId>
         + HasStarknetAccountType<Account = StarknetAccount>
         + HasSignerType<Signer = StarknetWallet>,
@@ -29,7 +26,7 @@ where
     fn build_account_from_signer(chain: &Chain, signer: &StarknetWallet) -> StarknetAccount {
         SingleOwnerAccount::new(
             chain.provider().clone(),
-            LocalWallet::from_signing_key(SigningKey::from_secret_scalar(signer.signing_key)),
+            signer.build_signer(),
             *signer.account_address,
             Felt::from_str(chain.chain_id().as_str()).unwrap(),
             ExecutionEncoding::New,
@@ -31,9 +31,9 @@ use hermes_cosmos::runtime::types::error::TokioRuntimeError;
 use hermes_prelude::*;
 use hermes_starknet_chain_components::impls::{
     ConsensusStateNotFound, ContractAddressNotFound, RaiseAccountError, RaiseProviderError,
-    RaiseStarknetError, UnexpectedTransactionTraceType,
+    RaiseStarknetError, StarknetFeeError, UnexpectedTransactionTraceType,
 };
-use hermes_starknet_chain_components::types::UnknownEvent;
+use hermes_starknet_chain_components::types::{StarknetSignError, UnknownEvent};
 use ibc::core::channel::types::error::ChannelError;
 use ibc::core::client::types::error::ClientError;
 use ibc::core::host::types::error::{DecodingError, IdentifierError};
@@ -43,13 +43,13 @@ use starknet::accounts::{single_owner, AccountError};
 use starknet::core::types::contract::{ComputeClassHashError, JsonError};
 use starknet::core::types::{RevertedInvocation, StarknetError};
 use starknet::providers::ProviderError;
-use starknet::signers::local_wallet;
+use starknet_block_verifier::FeederError;
 use tendermint_proto::Error as TendermintProtoError;
 use url::ParseError;
 
 pub struct HandleStarknetChainError;
 
-pub type SignError = single_owner::SignError<local_wallet::SignError>;
+pub type SignError = single_owner::SignError<StarknetSignError>;
 
 delegate_components! {
     HandleStarknetChainError {
@@ -67,6 +67,8 @@ delegate_components! {
             serde_json::error::Error,
             toml::ser::Error,
             ureq::Error,
+            reqwest::Error,
+            FeederError,
             JsonError,
             EncodeError,
             DecodeError,
@@ -99,6 +101,7 @@ delegate_components! {
             ContractAddressNotFound,
             EmptyMessageResponse,
             ConsensusStateNotFound,
+            StarknetFeeError,
             MissingSendPacketEventError,
             <'a> UnknownEvent<'a>,
             <'a, Chain: HasAddressType + HasAmountType> EventualAmountTimeoutError<'a, Chain>,
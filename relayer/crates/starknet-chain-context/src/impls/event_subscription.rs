@@ -0,0 +1,132 @@
+use core::time::Duration;
+
+use async_tungstenite::tokio::connect_async;
+use async_tungstenite::tungstenite::Message;
+use eyre::{eyre, Error};
+use futures::{SinkExt, StreamExt};
+use hermes_starknet_chain_components::types::{StarknetEvent, StarknetEventSubscription};
+use serde_json::{json, Value};
+use starknet::core::types::{EmittedEvent, Felt};
+
+/// Keeps a WebSocket subscription to the events emitted by the IBC core contract and to the
+/// new heads of the chain, reconnecting after `reconnect_delay` whenever it is dropped.
+///
+/// While disconnected, the subscription is reset so that block events are queried by polling.
+pub async fn run_starknet_event_subscription(
+    url: String,
+    ibc_core_contract_address: Felt,
+    reconnect_delay: Duration,
+    subscription: StarknetEventSubscription,
+) {
+    loop {
+        match subscribe_starknet_events(&url, &ibc_core_contract_address, &subscription).await {
+            Ok(()) => {
+                tracing::warn!("Starknet event subscription at {url} was closed, reconnecting");
+            }
+            Err(e) => {
+                tracing::warn!("Starknet event subscription at {url} failed, reconnecting: {e}");
+            }
+        }
+
+        subscription.reset();
+
+        tokio::time::sleep(reconnect_delay).await;
+    }
+}
+
+async fn subscribe_starknet_events(
+    url: &str,
+    ibc_core_contract_address: &Felt,
+    subscription: &StarknetEventSubscription,
+) -> Result<(), Error> {
+    let (mut socket, _) = connect_async(url).await?;
+
+    socket
+        .send(Message::Text(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": "starknet_subscribeEvents",
+                "params": {
+                    "from_address": ibc_core_contract_address,
+                },
+            })
+            .to_string(),
+        ))
+        .await?;
+
+    socket
+        .send(Message::Text(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "starknet_subscribeNewHeads",
+                "params": {},
+            })
+            .to_string(),
+        ))
+        .await?;
+
+    tracing::info!("subscribed to Starknet events at {url}");
+
+    while let Some(message) = socket.next().await {
+        match message? {
+            Message::Text(text) => {
+                handle_subscription_message(subscription, serde_json::from_str(&text)?)?
+            }
+            Message::Ping(payload) => socket.send(Message::Pong(payload)).await?,
+            Message::Close(_) => return Ok(()),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_subscription_message(
+    subscription: &StarknetEventSubscription,
+    message: Value,
+) -> Result<(), Error> {
+    if let Some(error) = message.get("error") {
+        return Err(eyre!("subscription request failed: {error}"));
+    }
+
+    let Some(method) = message.get("method").and_then(Value::as_str) else {
+        // responses to the subscription requests only carry the subscription IDs
+        return Ok(());
+    };
+
+    let result = message
+        .get("params")
+        .and_then(|params| params.get("result"))
+        .cloned()
+        .ok_or_else(|| eyre!("missing result in `{method}` notification"))?;
+
+    match method {
+        "starknet_subscriptionEvents" => {
+            let event: EmittedEvent = serde_json::from_value(result)?;
+
+            // events of pending blocks are received again once their block is accepted
+            if let (Some(height), Some(block_hash)) = (event.block_number, event.block_hash) {
+                subscription.on_event(height, block_hash, StarknetEvent::from(event));
+            }
+        }
+        "starknet_subscriptionNewHeads" => {
+            let height = serde_json::from_value(result["block_number"].clone())?;
+            let block_hash = serde_json::from_value(result["block_hash"].clone())?;
+            let parent_hash = serde_json::from_value(result["parent_hash"].clone())?;
+
+            subscription.on_new_head(height, block_hash, parent_hash);
+        }
+        "starknet_subscriptionReorg" => {
+            let start_height = serde_json::from_value(result["starting_block_number"].clone())?;
+
+            tracing::warn!("Starknet chain reorganized from height {start_height}");
+
+            subscription.on_reorg(start_height);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
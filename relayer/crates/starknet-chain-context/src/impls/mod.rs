@@ -3,3 +3,9 @@ pub use build_account::*;
 
 mod error;
 pub use error::*;
+
+mod event_subscription;
+pub use event_subscription::*;
+
+mod telemetry;
+pub use telemetry::*;
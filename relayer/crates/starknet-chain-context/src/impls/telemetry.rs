@@ -0,0 +1,92 @@
+use core::time::Duration;
+use std::net::SocketAddr;
+
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use hermes_core::chain_components::traits::CanQueryChainHeight;
+use hermes_starknet_chain_components::traits::CanQueryPacketSequences;
+use hermes_starknet_chain_components::types::StarknetTelemetry;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use tokio::net::TcpListener;
+
+use crate::contexts::StarknetChain;
+
+/// Serves the metrics of all the Starknet chains over HTTP, at `/metrics` in the
+/// Prometheus text format and at `/health` as JSON. `/health` responds with `503` when
+/// any of the chains is degraded.
+pub async fn run_starknet_telemetry_server(listen_addr: SocketAddr, telemetry: StarknetTelemetry) {
+    let listener = match TcpListener::bind(listen_addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::warn!("failed to start Starknet telemetry server at {listen_addr}: {e}");
+            return;
+        }
+    };
+
+    tracing::info!("serving Starknet metrics at http://{listen_addr}/metrics");
+
+    let router = Router::new()
+        .route("/metrics", get(serve_metrics))
+        .route("/health", get(serve_health))
+        .with_state(telemetry);
+
+    if let Err(e) = axum::serve(listener, router).await {
+        tracing::warn!("Starknet telemetry server at {listen_addr} stopped: {e}");
+    }
+}
+
+/// Periodically queries the chain height and the number of packets pending on each of the
+/// given channels, so that they are up to date in the metrics even when nothing is relayed.
+pub async fn run_starknet_telemetry_monitor(
+    chain: StarknetChain,
+    channels: Vec<(PortId, ChannelId)>,
+    refresh_interval: Duration,
+) {
+    loop {
+        match chain.query_chain_height().await {
+            Ok(height) => chain.metrics.set_chain_height(height),
+            Err(e) => tracing::warn!("failed to query Starknet chain height: {e}"),
+        }
+
+        for (port_id, channel_id) in channels.iter() {
+            match chain
+                .query_packet_commitment_sequences(port_id, channel_id)
+                .await
+            {
+                Ok(sequences) => chain.metrics.set_pending_packets(
+                    port_id.as_str(),
+                    channel_id.as_str(),
+                    sequences.len(),
+                ),
+                Err(e) => tracing::warn!(
+                    "failed to query pending packets of {port_id}/{channel_id} on Starknet: {e}"
+                ),
+            }
+        }
+
+        tokio::time::sleep(refresh_interval).await;
+    }
+}
+
+async fn serve_metrics(State(telemetry): State<StarknetTelemetry>) -> Response {
+    match telemetry.encode_prometheus() {
+        Ok(body) => ([(CONTENT_TYPE, prometheus::TEXT_FORMAT)], body).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn serve_health(State(telemetry): State<StarknetTelemetry>) -> impl IntoResponse {
+    let (healthy, report) = telemetry.health_report();
+
+    let status = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(report))
+}
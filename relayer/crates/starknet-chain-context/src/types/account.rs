@@ -1,8 +1,6 @@
 use std::sync::Arc;
 
+use hermes_starknet_chain_components::types::{StarknetRpcClient, StarknetSigner};
 use starknet::accounts::SingleOwnerAccount;
-use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
-use starknet::signers::LocalWallet;
 
-pub type StarknetAccount = SingleOwnerAccount<Arc<JsonRpcClient<HttpTransport>>, LocalWallet>;
+pub type StarknetAccount = SingleOwnerAccount<Arc<StarknetRpcClient>, StarknetSigner>;
@@ -140,9 +140,9 @@ fn main() -> Result<(), Error> {
                 DestinationTarget,
                 &cosmos_chain,
                 &starknet_chain,
-                &StarknetCreateClientPayloadOptions {
-                    wasm_code_hash: <[u8; 32]>::from_hex(WASM_CODE_HASH_HEX).expect("valid hex"),
-                },
+                &StarknetCreateClientPayloadOptions::new(
+                    <[u8; 32]>::from_hex(WASM_CODE_HASH_HEX).expect("valid hex"),
+                ),
                 &(),
             )
             .await?
@@ -2,7 +2,8 @@ use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerC
 use hermes_prelude::*;
 
 use crate::commands::{
-    BootstrapSubCommand, CreateSubCommand, QuerySubCommand, StartRelayerArgs, UpdateSubCommand,
+    BootstrapSubCommand, ClearSubCommand, CreateSubCommand, QuerySubCommand, RecoverSubCommand,
+    StartRelayerArgs, UpdateSubCommand, UpgradeSubCommand,
 };
 
 #[derive(Debug, clap::Subcommand)]
@@ -20,6 +21,15 @@ pub enum AllSubCommands {
 
     #[clap(subcommand)]
     Update(UpdateSubCommand),
+
+    #[clap(subcommand)]
+    Upgrade(UpgradeSubCommand),
+
+    #[clap(subcommand)]
+    Recover(RecoverSubCommand),
+
+    #[clap(subcommand)]
+    Clear(ClearSubCommand),
 }
 
 pub struct RunAllSubCommand;
@@ -31,6 +41,9 @@ where
         + CanRunCommand<QuerySubCommand>
         + CanRunCommand<CreateSubCommand>
         + CanRunCommand<UpdateSubCommand>
+        + CanRunCommand<UpgradeSubCommand>
+        + CanRunCommand<RecoverSubCommand>
+        + CanRunCommand<ClearSubCommand>
         + CanRunCommand<StartRelayerArgs>,
 {
     async fn run_command(
@@ -43,6 +56,9 @@ where
             AllSubCommands::Query(args) => app.run_command(args).await,
             AllSubCommands::Create(args) => app.run_command(args).await,
             AllSubCommands::Update(args) => app.run_command(args).await,
+            AllSubCommands::Upgrade(args) => app.run_command(args).await,
+            AllSubCommands::Recover(args) => app.run_command(args).await,
+            AllSubCommands::Clear(args) => app.run_command(args).await,
         }
     }
 }
@@ -0,0 +1,24 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::ClearPacketsArgs;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ClearSubCommand {
+    Packets(ClearPacketsArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, ClearSubCommand> for RunClearSubCommand
+where
+    App: CanRunCommand<ClearPacketsArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &ClearSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            ClearSubCommand::Packets(args) => app.run_command(args).await,
+        }
+    }
+}
@@ -4,14 +4,23 @@ pub use all::*;
 mod bootstrap;
 pub use bootstrap::*;
 
+mod clear;
+pub use clear::*;
+
 mod create;
 pub use create::*;
 
 mod query;
 pub use query::*;
 
+mod recover;
+pub use recover::*;
+
 mod start;
 pub use start::*;
 
 mod update;
 pub use update::*;
+
+mod upgrade;
+pub use upgrade::*;
@@ -0,0 +1,24 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::QueryChannelEndArgs;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum QueryChannelSubCommand {
+    End(QueryChannelEndArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, QueryChannelSubCommand> for RunQueryChannelSubCommand
+where
+    App: CanRunCommand<QueryChannelEndArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &QueryChannelSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            QueryChannelSubCommand::End(args) => app.run_command(args).await,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::QueryConnectionEndArgs;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum QueryConnectionSubCommand {
+    End(QueryConnectionEndArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, QueryConnectionSubCommand> for RunQueryConnectionSubCommand
+where
+    App: CanRunCommand<QueryConnectionEndArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &QueryConnectionSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            QueryConnectionSubCommand::End(args) => app.run_command(args).await,
+        }
+    }
+}
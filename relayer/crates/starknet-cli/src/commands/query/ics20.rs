@@ -0,0 +1,26 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::{QueryIcs20DenomArgs, QueryIcs20TokenAddressArgs};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum QueryIcs20SubCommand {
+    TokenAddress(QueryIcs20TokenAddressArgs),
+    Denom(QueryIcs20DenomArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, QueryIcs20SubCommand> for RunQueryIcs20SubCommand
+where
+    App: CanRunCommand<QueryIcs20TokenAddressArgs> + CanRunCommand<QueryIcs20DenomArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &QueryIcs20SubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            QueryIcs20SubCommand::TokenAddress(args) => app.run_command(args).await,
+            QueryIcs20SubCommand::Denom(args) => app.run_command(args).await,
+        }
+    }
+}
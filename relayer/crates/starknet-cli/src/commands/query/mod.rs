@@ -1,2 +1,14 @@
+mod channel;
+pub use channel::*;
+
+mod connection;
+pub use connection::*;
+
+mod ics20;
+pub use ics20::*;
+
+mod packet;
+pub use packet::*;
+
 mod subcommand;
 pub use subcommand::*;
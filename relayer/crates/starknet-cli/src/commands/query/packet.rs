@@ -0,0 +1,35 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::{
+    QueryPacketAckArgs, QueryPacketCommitmentArgs, QueryPacketReceiptArgs, QueryPendingPacketsArgs,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum QueryPacketSubCommand {
+    Pending(QueryPendingPacketsArgs),
+    Commitment(QueryPacketCommitmentArgs),
+    Ack(QueryPacketAckArgs),
+    Receipt(QueryPacketReceiptArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, QueryPacketSubCommand> for RunQueryPacketSubCommand
+where
+    App: CanRunCommand<QueryPendingPacketsArgs>
+        + CanRunCommand<QueryPacketCommitmentArgs>
+        + CanRunCommand<QueryPacketAckArgs>
+        + CanRunCommand<QueryPacketReceiptArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &QueryPacketSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            QueryPacketSubCommand::Pending(args) => app.run_command(args).await,
+            QueryPacketSubCommand::Commitment(args) => app.run_command(args).await,
+            QueryPacketSubCommand::Ack(args) => app.run_command(args).await,
+            QueryPacketSubCommand::Receipt(args) => app.run_command(args).await,
+        }
+    }
+}
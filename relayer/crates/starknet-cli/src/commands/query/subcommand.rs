@@ -4,12 +4,33 @@ use hermes_cli_components::impls::{
 use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
 use hermes_prelude::*;
 
+use crate::commands::{
+    QueryChannelSubCommand, QueryConnectionSubCommand, QueryIcs20SubCommand, QueryPacketSubCommand,
+};
+use crate::impls::{QueryAttestatorsArgs, QueryChannelsArgs};
+
 #[derive(Debug, clap::Subcommand)]
 pub enum QuerySubCommand {
     ClientState(QueryClientStateArgs),
     ConsensusState(QueryConsensusStateArgs),
     ChainStatus(QueryChainStatusArgs),
     Balance(QueryBalanceArgs),
+
+    #[clap(subcommand)]
+    Connection(QueryConnectionSubCommand),
+
+    #[clap(subcommand)]
+    Channel(QueryChannelSubCommand),
+
+    Channels(QueryChannelsArgs),
+
+    #[clap(subcommand)]
+    Packet(QueryPacketSubCommand),
+
+    #[clap(subcommand)]
+    Ics20(QueryIcs20SubCommand),
+
+    Attestators(QueryAttestatorsArgs),
 }
 
 pub struct RunQuerySubCommand;
@@ -20,7 +41,13 @@ where
     App: CanRunCommand<QueryClientStateArgs>
         + CanRunCommand<QueryConsensusStateArgs>
         + CanRunCommand<QueryChainStatusArgs>
-        + CanRunCommand<QueryBalanceArgs>,
+        + CanRunCommand<QueryBalanceArgs>
+        + CanRunCommand<QueryConnectionSubCommand>
+        + CanRunCommand<QueryChannelSubCommand>
+        + CanRunCommand<QueryChannelsArgs>
+        + CanRunCommand<QueryPacketSubCommand>
+        + CanRunCommand<QueryIcs20SubCommand>
+        + CanRunCommand<QueryAttestatorsArgs>,
 {
     async fn run_command(
         app: &App,
@@ -31,6 +58,12 @@ where
             QuerySubCommand::ConsensusState(args) => app.run_command(args).await,
             QuerySubCommand::ChainStatus(args) => app.run_command(args).await,
             QuerySubCommand::Balance(args) => app.run_command(args).await,
+            QuerySubCommand::Connection(args) => app.run_command(args).await,
+            QuerySubCommand::Channel(args) => app.run_command(args).await,
+            QuerySubCommand::Channels(args) => app.run_command(args).await,
+            QuerySubCommand::Packet(args) => app.run_command(args).await,
+            QuerySubCommand::Ics20(args) => app.run_command(args).await,
+            QuerySubCommand::Attestators(args) => app.run_command(args).await,
         }
     }
 }
@@ -0,0 +1,2 @@
+mod subcommand;
+pub use subcommand::*;
@@ -0,0 +1,24 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::RecoverClientArgs;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum RecoverSubCommand {
+    Client(RecoverClientArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, RecoverSubCommand> for RunRecoverSubCommand
+where
+    App: CanRunCommand<RecoverClientArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &RecoverSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            RecoverSubCommand::Client(args) => app.run_command(args).await,
+        }
+    }
+}
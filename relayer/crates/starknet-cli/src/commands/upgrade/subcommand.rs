@@ -0,0 +1,24 @@
+use hermes_cli_components::traits::{CanRunCommand, CommandRunner, CommandRunnerComponent};
+use hermes_prelude::*;
+
+use crate::impls::UpgradeClientArgs;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum UpgradeSubCommand {
+    Client(UpgradeClientArgs),
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl<App> CommandRunner<App, UpgradeSubCommand> for RunUpgradeSubCommand
+where
+    App: CanRunCommand<UpgradeClientArgs>,
+{
+    async fn run_command(
+        app: &App,
+        subcommand: &UpgradeSubCommand,
+    ) -> Result<App::Output, App::Error> {
+        match subcommand {
+            UpgradeSubCommand::Client(args) => app.run_command(args).await,
+        }
+    }
+}
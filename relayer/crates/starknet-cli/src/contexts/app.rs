@@ -49,13 +49,25 @@ use ibc::core::host::types::identifiers::{ChainId, ClientId as CosmosClientId, P
 use toml::to_string_pretty;
 
 use crate::commands::{
-    AllSubCommands, BootstrapSubCommand, CreateSubCommand, QuerySubCommand, RunAllSubCommand,
-    RunBootstrapSubCommand, RunCreateSubCommand, RunQuerySubCommand, RunUpdateSubCommand,
-    StartRelayerArgs, UpdateSubCommand,
+    AllSubCommands, BootstrapSubCommand, ClearSubCommand, CreateSubCommand, QueryChannelSubCommand,
+    QueryConnectionSubCommand, QueryIcs20SubCommand, QueryPacketSubCommand, QuerySubCommand,
+    RecoverSubCommand, RunAllSubCommand, RunBootstrapSubCommand, RunClearSubCommand,
+    RunCreateSubCommand, RunQueryChannelSubCommand, RunQueryConnectionSubCommand,
+    RunQueryIcs20SubCommand, RunQueryPacketSubCommand, RunQuerySubCommand, RunRecoverSubCommand,
+    RunUpdateSubCommand, RunUpgradeSubCommand, StartRelayerArgs, UpdateSubCommand,
+    UpgradeSubCommand,
 };
 use crate::impls::{
-    BootstrapOsmosisChainArgs, BootstrapStarknetChainArgs, CreateStarknetClientArgs,
-    LoadOsmosisBootstrap, LoadStarknetBootstrap, LoadStarknetBuilder, ProvideCliError,
+    BootstrapOsmosisChainArgs, BootstrapStarknetChainArgs, ClearPacketsArgs,
+    CreateStarknetClientArgs, LoadOsmosisBootstrap, LoadStarknetBootstrap, LoadStarknetBuilder,
+    ProvideCliError, QueryAttestatorsArgs, QueryChannelEndArgs, QueryChannelsArgs,
+    QueryConnectionEndArgs, QueryIcs20DenomArgs, QueryIcs20TokenAddressArgs, QueryPacketAckArgs,
+    QueryPacketCommitmentArgs, QueryPacketReceiptArgs, QueryPendingPacketsArgs, RecoverClientArgs,
+    RunClearPacketsCommand, RunQueryAttestatorsCommand, RunQueryChannelEndCommand,
+    RunQueryChannelsCommand, RunQueryConnectionEndCommand, RunQueryIcs20DenomCommand,
+    RunQueryIcs20TokenAddressCommand, RunQueryPacketAckCommand, RunQueryPacketCommitmentCommand,
+    RunQueryPacketReceiptCommand, RunQueryPendingPacketsCommand, RunRecoverClientCommand,
+    RunUpgradeClientCommand, UpgradeClientArgs,
 };
 
 #[cgp_context(StarknetAppComponents)]
@@ -189,6 +201,20 @@ delegate_components! {
         QueryConsensusStateArgs: RunQueryConsensusStateCommand,
         QueryChainStatusArgs: RunQueryChainStatusCommand,
         QueryBalanceArgs: RunQueryBalanceCommand,
+        QueryConnectionSubCommand: RunQueryConnectionSubCommand,
+        QueryConnectionEndArgs: RunQueryConnectionEndCommand,
+        QueryChannelSubCommand: RunQueryChannelSubCommand,
+        QueryChannelEndArgs: RunQueryChannelEndCommand,
+        QueryChannelsArgs: RunQueryChannelsCommand,
+        QueryPacketSubCommand: RunQueryPacketSubCommand,
+        QueryPendingPacketsArgs: RunQueryPendingPacketsCommand,
+        QueryPacketCommitmentArgs: RunQueryPacketCommitmentCommand,
+        QueryPacketAckArgs: RunQueryPacketAckCommand,
+        QueryPacketReceiptArgs: RunQueryPacketReceiptCommand,
+        QueryIcs20SubCommand: RunQueryIcs20SubCommand,
+        QueryIcs20TokenAddressArgs: RunQueryIcs20TokenAddressCommand,
+        QueryIcs20DenomArgs: RunQueryIcs20DenomCommand,
+        QueryAttestatorsArgs: RunQueryAttestatorsCommand,
 
         CreateSubCommand: RunCreateSubCommand,
         UpdateSubCommand: RunUpdateSubCommand,
@@ -200,6 +226,15 @@ delegate_components! {
         CreateConnectionArgs: RunCreateConnectionCommand,
         CreateChannelArgs: RunCreateChannelCommand,
 
+        UpgradeSubCommand: RunUpgradeSubCommand,
+        UpgradeClientArgs: RunUpgradeClientCommand,
+
+        RecoverSubCommand: RunRecoverSubCommand,
+        RecoverClientArgs: RunRecoverClientCommand,
+
+        ClearSubCommand: RunClearSubCommand,
+        ClearPacketsArgs: RunClearPacketsCommand,
+
         BootstrapStarknetChainArgs: RunBootstrapChainCommand<StarknetChain, UpdateStarknetConfig>,
         BootstrapOsmosisChainArgs: RunBootstrapChainCommand<CosmosChain, UpdateStarknetConfig>,
     }
@@ -271,8 +306,9 @@ impl ConfigUpdater<StarknetChainDriver, StarknetRelayerConfig> for UpdateStarkne
                 chain_driver.node_config.rpc_addr,
                 chain_driver.node_config.rpc_port + 1
             ),
-            relayer_wallet_1: relayer_wallet_path_1,
-            relayer_wallet_2: relayer_wallet_path_2,
+            relayer_wallets: vec![relayer_wallet_path_1, relayer_wallet_path_2],
+            relayer_wallet_1: None,
+            relayer_wallet_2: None,
             poll_interval: chain_driver.chain.poll_interval,
             block_time: chain_driver.chain.block_time,
             contract_addresses,
@@ -284,7 +320,15 @@ impl ConfigUpdater<StarknetChainDriver, StarknetRelayerConfig> for UpdateStarkne
                 max_delay: Duration::from_secs(1),
                 sleep_time: Duration::from_millis(100),
             }),
+            feeder_gateway_config: None,
             ed25519_attestator_addresses: chain_driver.chain.ed25519_attestator_addresses.clone(),
+            attestation: None,
+            packet_filter: None,
+            event_chunk_size: None,
+            event_source: None,
+            fee_config: None,
+            signer_pool: None,
+            telemetry: None,
         };
 
         let chain_config_str = to_string_pretty(&chain_config)?;
@@ -329,6 +373,20 @@ check_components! {
             QuerySubCommand,
             QueryClientStateArgs,
             QueryBalanceArgs,
+            QueryConnectionSubCommand,
+            QueryConnectionEndArgs,
+            QueryChannelSubCommand,
+            QueryChannelEndArgs,
+            QueryChannelsArgs,
+            QueryPacketSubCommand,
+            QueryPendingPacketsArgs,
+            QueryPacketCommitmentArgs,
+            QueryPacketAckArgs,
+            QueryPacketReceiptArgs,
+            QueryIcs20SubCommand,
+            QueryIcs20TokenAddressArgs,
+            QueryIcs20DenomArgs,
+            QueryAttestatorsArgs,
             CreateSubCommand,
             UpdateSubCommand,
             UpdateClientArgs,
@@ -337,6 +395,12 @@ check_components! {
             StartRelayerArgs,
             CreateConnectionArgs,
             CreateChannelArgs,
+            UpgradeSubCommand,
+            UpgradeClientArgs,
+            RecoverSubCommand,
+            RecoverClientArgs,
+            ClearSubCommand,
+            ClearPacketsArgs,
         ],
     }
 }
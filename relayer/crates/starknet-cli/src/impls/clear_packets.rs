@@ -0,0 +1,373 @@
+use core::marker::PhantomData;
+use core::ops::RangeInclusive;
+use std::sync::Arc;
+
+use eyre::eyre;
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::{
+    CanBuildPacketFromSendPacket, CanExtractFromEvent, CanQueryChainHeight, HasSendPacketEvent,
+};
+use hermes_core::relayer_components::relay::traits::CanRelayPacket;
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_cosmos_core::chain_components::traits::HasRpcClient;
+use hermes_cosmos_core::tendermint_rpc::query::Query;
+use hermes_cosmos_core::tendermint_rpc::{Client, Order};
+use hermes_prelude::*;
+use hermes_starknet_chain_components::traits::{
+    CanQueryBlockEventsInRange, CanQuerySendPacketEvents,
+};
+use hermes_starknet_chain_context::contexts::StarknetChain;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, ClientId, PortId, Sequence};
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct ClearPacketsArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "starknet-chain-id",
+        required = true,
+        value_name = "STARKNET_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub starknet_chain_id: String,
+
+    /// Identifier of Starknet client
+    #[clap(
+        long = "starknet-client-id",
+        required = true,
+        value_name = "STARKNET_CLIENT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub starknet_client_id: String,
+
+    /// Identifier of Cosmos chain
+    #[clap(
+        long = "cosmos-chain-id",
+        required = true,
+        value_name = "COSMOS_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_chain_id: String,
+
+    /// Identifier of Cosmos client
+    #[clap(
+        long = "cosmos-client-id",
+        required = true,
+        value_name = "COSMOS_CLIENT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_client_id: String,
+
+    /// Identifier of the chain that sent the packets, either the Starknet or the Cosmos chain
+    #[clap(
+        long = "src-chain-id",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub src_chain_id: String,
+
+    /// Port of the channel on the chain that sent the packets
+    #[clap(
+        long = "src-port-id",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub src_port_id: String,
+
+    /// Identifier of the channel on the chain that sent the packets
+    #[clap(
+        long = "src-channel-id",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub src_channel_id: String,
+
+    /// Sequences of the packets to relay, either a single sequence or an inclusive
+    /// range such as `5..10`
+    #[clap(
+        long = "sequences",
+        required = true,
+        value_name = "SEQUENCES",
+        help_heading = "REQUIRED",
+        value_parser = parse_sequence_range
+    )]
+    pub sequences: RangeInclusive<u64>,
+
+    /// Starknet height from which to scan the blocks for the packets sent from Starknet.
+    /// Without it, the packets are looked up by sequence across the whole chain history
+    #[clap(long = "starknet-start-height", required = false)]
+    pub starknet_start_height: Option<u64>,
+
+    /// Last Starknet height to scan for the packets sent from Starknet. Defaults to the
+    /// latest height
+    #[clap(
+        long = "starknet-end-height",
+        required = false,
+        requires = "starknet_start_height"
+    )]
+    pub starknet_end_height: Option<u64>,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, ClearPacketsArgs> for RunClearPacketsCommand {
+    async fn run_command(app: &StarknetApp, args: &ClearPacketsArgs) -> Result<(), HermesError> {
+        let starknet_chain_id: ChainId = args.starknet_chain_id.parse()?;
+        let starknet_client_id: ClientId = args.starknet_client_id.parse()?;
+        let cosmos_chain_id: ChainId = args.cosmos_chain_id.parse()?;
+        let cosmos_client_id: ClientId = args.cosmos_client_id.parse()?;
+        let src_chain_id: ChainId = args.src_chain_id.parse()?;
+        let port_id: PortId = args.src_port_id.parse()?;
+        let channel_id: ChannelId = args.src_channel_id.parse()?;
+
+        let sequences: Vec<Sequence> = args.sequences.clone().map(Sequence::from).collect();
+
+        let builder = app.load_builder().await?;
+
+        let starknet_chain = builder.build_chain(&starknet_chain_id).await?;
+        let cosmos_chain = builder.cosmos_builder.build_chain(&cosmos_chain_id).await?;
+
+        let failed_count = if src_chain_id == starknet_chain_id {
+            let packets = match args.starknet_start_height {
+                Some(start_height) => {
+                    let end_height = match args.starknet_end_height {
+                        Some(end_height) => end_height,
+                        None => starknet_chain.query_chain_height().await?,
+                    };
+
+                    scan_starknet_send_packets(
+                        &starknet_chain,
+                        &port_id,
+                        &channel_id,
+                        &sequences,
+                        &(start_height..=end_height),
+                    )
+                    .await?
+                }
+                None => {
+                    query_starknet_send_packets(&starknet_chain, &port_id, &channel_id, &sequences)
+                        .await?
+                }
+            };
+
+            let relay = builder.build_starknet_to_cosmos_relay(
+                starknet_chain,
+                cosmos_chain,
+                &starknet_client_id,
+                &cosmos_client_id,
+                None,
+                None,
+            );
+
+            relay_packets(&relay, &sequences, packets).await
+        } else if src_chain_id == cosmos_chain_id {
+            let packets =
+                query_cosmos_send_packets(&cosmos_chain, &port_id, &channel_id, &sequences).await?;
+
+            let relay = builder.build_cosmos_to_starknet_relay(
+                cosmos_chain,
+                starknet_chain,
+                &cosmos_client_id,
+                &starknet_client_id,
+                None,
+                None,
+            );
+
+            relay_packets(&relay, &sequences, packets).await
+        } else {
+            return Err(StarknetApp::raise_error(format!(
+                "source chain {src_chain_id} is neither {starknet_chain_id} nor {cosmos_chain_id}"
+            )));
+        };
+
+        if failed_count > 0 {
+            return Err(StarknetApp::raise_error(format!(
+                "failed to relay {failed_count} packet(s) from {src_chain_id}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Relays each of the packets once, and returns the number of packets that failed
+/// to be relayed. The sequences without a matching packet are reported as not found.
+async fn relay_packets<Relay>(relay: &Relay, sequences: &[Sequence], packets: Vec<Packet>) -> usize
+where
+    Relay: CanRelayPacket<Packet = Packet>,
+{
+    for sequence in sequences {
+        if !packets.iter().any(|packet| &packet.seq_on_a == sequence) {
+            tracing::warn!("no packet found with sequence {sequence}, skipping");
+        }
+    }
+
+    let mut failed_count = 0;
+
+    for packet in packets.iter() {
+        match relay.relay_packet(packet).await {
+            Ok(()) => tracing::info!("relayed packet with sequence {}", packet.seq_on_a),
+            Err(e) => {
+                tracing::error!(
+                    "failed to relay packet with sequence {}: {e:?}",
+                    packet.seq_on_a
+                );
+                failed_count += 1;
+            }
+        }
+    }
+
+    failed_count
+}
+
+async fn query_starknet_send_packets(
+    starknet_chain: &StarknetChain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: &[Sequence],
+) -> Result<Vec<Packet>, HermesError> {
+    let events = starknet_chain
+        .query_send_packet_events(port_id, channel_id, sequences)
+        .await?;
+
+    let mut packets = Vec::new();
+
+    for event in events.iter() {
+        let packet =
+            CanBuildPacketFromSendPacket::<CosmosChain>::build_packet_from_send_packet_event(
+                starknet_chain,
+                event,
+            )
+            .await?;
+
+        packets.push(packet);
+    }
+
+    Ok(packets)
+}
+
+/// Scans the events of the Starknet blocks in the height range for the packets with
+/// the given sequences sent on the channel.
+async fn scan_starknet_send_packets(
+    starknet_chain: &StarknetChain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: &[Sequence],
+    heights: &RangeInclusive<u64>,
+) -> Result<Vec<Packet>, HermesError> {
+    let block_events = starknet_chain
+        .query_block_events_in_range(heights.start(), heights.end())
+        .await?;
+
+    let mut packets = Vec::new();
+
+    for (_, events) in block_events.iter() {
+        for event in events.iter() {
+            let Some(send_packet_event) = starknet_chain.try_extract_from_event(
+                PhantomData::<<StarknetChain as HasSendPacketEvent<CosmosChain>>::SendPacketEvent>,
+                event,
+            ) else {
+                continue;
+            };
+
+            if &send_packet_event.port_id_on_a != port_id
+                || &send_packet_event.channel_id_on_a != channel_id
+                || !sequences.contains(&send_packet_event.sequence_on_a)
+            {
+                continue;
+            }
+
+            let packet =
+                CanBuildPacketFromSendPacket::<CosmosChain>::build_packet_from_send_packet_event(
+                    starknet_chain,
+                    &send_packet_event,
+                )
+                .await?;
+
+            packets.push(packet);
+        }
+    }
+
+    Ok(packets)
+}
+
+/// Searches the Cosmos transactions for the packets with the given sequences sent
+/// on the channel, using the `send_packet` events indexed by the node.
+async fn query_cosmos_send_packets(
+    cosmos_chain: &CosmosChain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: &[Sequence],
+) -> Result<Vec<Packet>, HermesError> {
+    let mut packets = Vec::new();
+
+    for sequence in sequences {
+        let query = Query::eq("send_packet.packet_src_channel", channel_id.to_string())
+            .and_eq("send_packet.packet_src_port", port_id.to_string())
+            .and_eq("send_packet.packet_sequence", sequence.value().to_string());
+
+        let response = cosmos_chain
+            .rpc_client()
+            .tx_search(query, false, 1, 10, Order::Ascending)
+            .await
+            .map_err(|e| eyre!("failed to search for the packet with sequence {sequence}: {e}"))?;
+
+        'search: for tx in response.txs.iter() {
+            for event in tx.tx_result.events.iter() {
+                let Some(send_packet_event) = cosmos_chain.try_extract_from_event(
+                    PhantomData::<
+                        <CosmosChain as HasSendPacketEvent<StarknetChain>>::SendPacketEvent,
+                    >,
+                    &Arc::new(event.clone()),
+                ) else {
+                    continue;
+                };
+
+                let packet =
+                    CanBuildPacketFromSendPacket::<StarknetChain>::build_packet_from_send_packet_event(
+                        cosmos_chain,
+                        &send_packet_event,
+                    )
+                    .await?;
+
+                if &packet.port_id_on_a == port_id
+                    && &packet.chan_id_on_a == channel_id
+                    && &packet.seq_on_a == sequence
+                {
+                    packets.push(packet);
+                    break 'search;
+                }
+            }
+        }
+    }
+
+    Ok(packets)
+}
+
+fn parse_sequence_range(input: &str) -> Result<RangeInclusive<u64>, String> {
+    let (start, end) = match input.split_once("..") {
+        Some((start, end)) => (start, end),
+        None => (input, input),
+    };
+
+    let start = start
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| format!("invalid start sequence `{start}`: {e}"))?;
+
+    let end = end
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| format!("invalid end sequence `{end}`: {e}"))?;
+
+    if start == 0 || start > end {
+        return Err(format!("invalid sequence range `{input}`"));
+    }
+
+    Ok(start..=end)
+}
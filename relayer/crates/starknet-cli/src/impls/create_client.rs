@@ -36,6 +36,18 @@ pub struct CreateStarknetClientArgs {
 
     #[clap(long = "wasm-code-hash")]
     pub wasm_code_hash: String,
+
+    /// Trusting period of the client. Defaults to 14 days
+    #[clap(long = "trusting-period", required = false)]
+    pub trusting_period: Option<humantime::Duration>,
+
+    /// Maximum clock drift allowed for Starknet block timestamps. Defaults to 60 seconds
+    #[clap(long = "max-clock-drift", required = false)]
+    pub max_clock_drift: Option<humantime::Duration>,
+
+    /// Maximum number of consensus states kept by the client, 0 for no limit. Defaults to 1000
+    #[clap(long = "max-consensus-states", required = false)]
+    pub max_consensus_states: Option<u64>,
 }
 
 #[cgp_provider(CreateClientOptionsParserComponent)]
@@ -84,6 +96,20 @@ impl CreateClientOptionsParser<StarknetApp, CreateStarknetClientArgs, Index<1>,
     ) -> Result<((), StarknetCreateClientPayloadOptions), HermesError> {
         let wasm_code_hash = <[u8; 32]>::from_hex(&args.wasm_code_hash)?;
 
-        Ok(((), StarknetCreateClientPayloadOptions { wasm_code_hash }))
+        let mut options = StarknetCreateClientPayloadOptions::new(wasm_code_hash);
+
+        if let Some(trusting_period) = args.trusting_period {
+            options.trusting_period = trusting_period.into();
+        }
+
+        if let Some(max_clock_drift) = args.max_clock_drift {
+            options.max_clock_drift = max_clock_drift.into();
+        }
+
+        if let Some(max_consensus_states) = args.max_consensus_states {
+            options.max_consensus_states = max_consensus_states;
+        }
+
+        Ok(((), options))
     }
 }
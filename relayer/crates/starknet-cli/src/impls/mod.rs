@@ -4,8 +4,32 @@ pub use bootstrap::*;
 mod build;
 pub use build::*;
 
+mod clear_packets;
+pub use clear_packets::*;
+
 mod create_client;
 pub use create_client::*;
 
 mod error;
 pub use error::*;
+
+mod query_attestators;
+pub use query_attestators::*;
+
+mod query_channels;
+pub use query_channels::*;
+
+mod query_connection;
+pub use query_connection::*;
+
+mod query_ics20;
+pub use query_ics20::*;
+
+mod query_packets;
+pub use query_packets::*;
+
+mod recover_client;
+pub use recover_client::*;
+
+mod upgrade_client;
+pub use upgrade_client::*;
@@ -0,0 +1,116 @@
+use core::marker::PhantomData;
+
+use attestator::AsyncAttestatorClient;
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::CanQueryClientStateWithLatestHeight;
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::traits::HasEd25519AttestatorAddresses;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryAttestatorsArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Identifier of the Comet client on Starknet, whose trusted attestator keys are
+    /// matched against the keys published by the attestators
+    #[clap(long = "client-id", required = false, value_name = "CLIENT_ID")]
+    pub client_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttestatorOutput {
+    pub address: String,
+    pub public_key: Option<String>,
+    pub next_public_key: Option<String>,
+    pub trusted: Option<bool>,
+    pub next_trusted: Option<bool>,
+    pub error: Option<String>,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryAttestatorsArgs> for RunQueryAttestatorsCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryAttestatorsArgs,
+    ) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        let attestator_addresses = chain
+            .ed25519_attestator_addresses()
+            .clone()
+            .ok_or_else(|| StarknetApp::raise_error("No Ed25519 attestators"))?;
+
+        let trusted_keys = match &args.client_id {
+            Some(client_id) => {
+                let client_id: ClientId = client_id.parse()?;
+
+                let client_state =
+                    CanQueryClientStateWithLatestHeight::<CosmosChain>::query_client_state_with_latest_height(
+                        &chain,
+                        PhantomData,
+                        &client_id,
+                    )
+                    .await?;
+
+                Some(client_state.attestator_keys)
+            }
+            None => None,
+        };
+
+        let http_client = reqwest::Client::new();
+
+        let mut output = Vec::with_capacity(attestator_addresses.len());
+
+        for address in attestator_addresses {
+            let result = AsyncAttestatorClient::new(&http_client, &address)
+                .get_keys()
+                .await;
+
+            let attestator = match result {
+                Ok(keys) => AttestatorOutput {
+                    public_key: Some(keys.public_key.to_hex_string()),
+                    next_public_key: keys.next_public_key.map(|key| key.to_hex_string()),
+                    trusted: trusted_keys
+                        .as_ref()
+                        .map(|trusted_keys| trusted_keys.contains(&keys.public_key)),
+                    next_trusted: trusted_keys.as_ref().and_then(|trusted_keys| {
+                        keys.next_public_key
+                            .map(|next_public_key| trusted_keys.contains(&next_public_key))
+                    }),
+                    error: None,
+                    address,
+                },
+                Err(e) => AttestatorOutput {
+                    public_key: None,
+                    next_public_key: None,
+                    trusted: None,
+                    next_trusted: None,
+                    error: Some(e.to_string()),
+                    address,
+                },
+            };
+
+            output.push(attestator);
+        }
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::{CanQueryChainHeight, CanQueryChannelEnd};
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::traits::CanQueryChannels;
+use hermes_starknet_chain_context::contexts::StarknetChain;
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, PortId};
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryChannelEndArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Port of the channel on Starknet
+    #[clap(
+        long = "port-id",
+        required = true,
+        value_name = "PORT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub port_id: String,
+
+    /// Identifier of the channel on Starknet
+    #[clap(
+        long = "channel-id",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub channel_id: String,
+
+    /// Starknet height at which to query the channel. Defaults to the latest height
+    #[clap(long = "height", required = false)]
+    pub height: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryChannelsArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChannelEndOutput {
+    pub port_id: String,
+    pub channel_id: String,
+    pub state: String,
+    pub ordering: String,
+    pub connection_hops: Vec<String>,
+    pub version: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+}
+
+impl ChannelEndOutput {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId, channel_end: &ChannelEnd) -> Self {
+        Self {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            state: format!("{:?}", channel_end.state),
+            ordering: format!("{:?}", channel_end.ordering),
+            connection_hops: channel_end
+                .connection_hops
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            version: channel_end.version.to_string(),
+            counterparty_port_id: channel_end.remote.port_id.to_string(),
+            counterparty_channel_id: channel_end
+                .remote
+                .channel_id
+                .as_ref()
+                .map(ToString::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChannelsOutput {
+    pub height: u64,
+    pub channels: Vec<ChannelEndOutput>,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryChannelEndArgs> for RunQueryChannelEndCommand {
+    async fn run_command(app: &StarknetApp, args: &QueryChannelEndArgs) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+        let port_id: PortId = args.port_id.parse()?;
+        let channel_id: ChannelId = args.channel_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        let height = match args.height {
+            Some(height) => height,
+            None => chain.query_chain_height().await?,
+        };
+
+        let channel_end = query_channel_end(&chain, &port_id, &channel_id, height).await?;
+
+        let output = ChannelEndOutput::new(&port_id, &channel_id, &channel_end);
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryChannelsArgs> for RunQueryChannelsCommand {
+    async fn run_command(app: &StarknetApp, args: &QueryChannelsArgs) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        // The channels are listed before the height is queried, so that the channel
+        // ends of all the listed channels exist at that height.
+        let channel_ids = chain.query_channels().await?;
+
+        let height = chain.query_chain_height().await?;
+
+        let mut channels = Vec::with_capacity(channel_ids.len());
+
+        for (port_id, channel_id) in channel_ids.iter() {
+            let channel_end = query_channel_end(&chain, port_id, channel_id, height).await?;
+
+            channels.push(ChannelEndOutput::new(port_id, channel_id, &channel_end));
+        }
+
+        let output = ChannelsOutput { height, channels };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
+
+async fn query_channel_end(
+    chain: &StarknetChain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    height: u64,
+) -> Result<ChannelEnd, HermesError> {
+    CanQueryChannelEnd::<CosmosChain>::query_channel_end(chain, channel_id, port_id, &height).await
+}
@@ -0,0 +1,88 @@
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::{CanQueryChainHeight, CanQueryConnectionEnd};
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_prelude::*;
+use ibc::core::host::types::identifiers::{ChainId, ConnectionId};
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryConnectionEndArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Identifier of the connection on Starknet
+    #[clap(
+        long = "connection-id",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub connection_id: String,
+
+    /// Starknet height at which to query the connection. Defaults to the latest height
+    #[clap(long = "height", required = false)]
+    pub height: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConnectionEndOutput {
+    pub connection_id: String,
+    pub height: u64,
+    pub state: String,
+    pub client_id: String,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: Option<String>,
+    pub delay_period_secs: u64,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryConnectionEndArgs> for RunQueryConnectionEndCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryConnectionEndArgs,
+    ) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+        let connection_id: ConnectionId = args.connection_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        let height = match args.height {
+            Some(height) => height,
+            None => chain.query_chain_height().await?,
+        };
+
+        let connection_end = CanQueryConnectionEnd::<CosmosChain>::query_connection_end(
+            &chain,
+            &connection_id,
+            &height,
+        )
+        .await?;
+
+        let counterparty = connection_end.counterparty();
+
+        let output = ConnectionEndOutput {
+            connection_id: connection_id.to_string(),
+            height,
+            state: format!("{:?}", connection_end.state()),
+            client_id: connection_end.client_id().to_string(),
+            counterparty_client_id: counterparty.client_id().to_string(),
+            counterparty_connection_id: counterparty.connection_id().map(ToString::to_string),
+            delay_period_secs: connection_end.delay_period().as_secs(),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_cosmos::error::HermesError;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::impls::{GetCosmosTokenAddressOnStarknet, StarknetAddress};
+use hermes_starknet_chain_components::traits::{
+    CanQueryCosmosTokenDenomOnStarknet, CosmosTokenAddressOnStarknetQuerier,
+};
+use hermes_starknet_chain_components::types::PrefixedDenom;
+use ibc::core::host::types::identifiers::ChainId;
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryIcs20TokenAddressArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Prefixed denom of the Cosmos token, such as `transfer/channel-0/uosmo`
+    #[clap(value_name = "PREFIXED_DENOM")]
+    pub denom: String,
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryIcs20DenomArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Address of the ERC20 token on Starknet
+    #[clap(value_name = "TOKEN_ADDRESS")]
+    pub token_address: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Ics20TokenOutput {
+    pub denom: Option<String>,
+    pub token_address: Option<String>,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryIcs20TokenAddressArgs> for RunQueryIcs20TokenAddressCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryIcs20TokenAddressArgs,
+    ) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+        let denom: PrefixedDenom = args.denom.parse().map_err(StarknetApp::raise_error)?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        // The chain queries the token address with `GetOrCreateCosmosTokenAddressOnStarknet`,
+        // which deploys the token when it is missing. The lookup is called directly so
+        // that the query never submits a transaction.
+        let token_address =
+            GetCosmosTokenAddressOnStarknet::query_cosmos_token_address_on_starknet(&chain, &denom)
+                .await?;
+
+        let output = Ics20TokenOutput {
+            denom: Some(denom.to_string()),
+            token_address: token_address.map(|address| address.to_string()),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryIcs20DenomArgs> for RunQueryIcs20DenomCommand {
+    async fn run_command(app: &StarknetApp, args: &QueryIcs20DenomArgs) -> Result<(), HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+
+        let token_address: StarknetAddress = args.token_address.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        let denom = chain
+            .query_cosmos_token_denom_on_starknet(&token_address)
+            .await?;
+
+        let output = Ics20TokenOutput {
+            denom,
+            token_address: Some(token_address.to_string()),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::BTreeSet;
+
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::{
+    CanQueryChainHeight, CanQueryChannelEnd, CanQueryPacketAckCommitment, CanQueryPacketCommitment,
+    CanQueryPacketCommitments, CanQueryPacketIsReceived, CanQueryPacketReceipt,
+};
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::traits::CanQueryPacketSequences;
+use hermes_starknet_chain_components::types::StarknetCommitmentProof;
+use hermes_starknet_chain_context::contexts::StarknetChain;
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, PortId, Sequence};
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryPendingPacketsArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "starknet-chain-id",
+        required = true,
+        value_name = "STARKNET_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub starknet_chain_id: String,
+
+    /// Identifier of Cosmos chain
+    #[clap(
+        long = "cosmos-chain-id",
+        required = true,
+        value_name = "COSMOS_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_chain_id: String,
+
+    /// Port of the channel on Starknet
+    #[clap(
+        long = "port-id",
+        required = true,
+        value_name = "PORT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub port_id: String,
+
+    /// Identifier of the channel on Starknet
+    #[clap(
+        long = "channel-id",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub channel_id: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PendingPackets {
+    /// Sequences of the packets sent that are not received by the counterparty
+    pub unreceived_packets: Vec<u64>,
+    /// Sequences of the packets received by the counterparty whose acknowledgement
+    /// is not relayed back to the sender yet
+    pub unreceived_acks: Vec<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PendingPacketsOutput {
+    pub starknet_to_cosmos: PendingPackets,
+    pub cosmos_to_starknet: PendingPackets,
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryPendingPacketsArgs> for RunQueryPendingPacketsCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryPendingPacketsArgs,
+    ) -> Result<(), HermesError> {
+        let starknet_chain_id: ChainId = args.starknet_chain_id.parse()?;
+        let cosmos_chain_id: ChainId = args.cosmos_chain_id.parse()?;
+        let port_id: PortId = args.port_id.parse()?;
+        let channel_id: ChannelId = args.channel_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let starknet_chain = builder.build_chain(&starknet_chain_id).await?;
+        let cosmos_chain = builder.cosmos_builder.build_chain(&cosmos_chain_id).await?;
+
+        let channel_end = CanQueryChannelEnd::<CosmosChain>::query_channel_end(
+            &starknet_chain,
+            &channel_id,
+            &port_id,
+            &starknet_chain.query_chain_height().await?,
+        )
+        .await?;
+
+        let counterparty_port_id = channel_end.remote.port_id.clone();
+
+        let counterparty_channel_id = channel_end.remote.channel_id.clone().ok_or_else(|| {
+            StarknetApp::raise_error(format!(
+                "channel {port_id}/{channel_id} on Starknet has no counterparty channel"
+            ))
+        })?;
+
+        // Packets sent from Starknet keep their commitment until the acknowledgement
+        // or the timeout is relayed back, so the ones already received on Cosmos only
+        // wait for their acknowledgement.
+        let mut starknet_to_cosmos = PendingPackets::default();
+
+        let starknet_sequences = starknet_chain
+            .query_packet_commitment_sequences(&port_id, &channel_id)
+            .await?;
+
+        for sequence in starknet_sequences.iter() {
+            let is_received = CanQueryPacketIsReceived::<StarknetChain>::query_packet_is_received(
+                &cosmos_chain,
+                &counterparty_port_id,
+                &counterparty_channel_id,
+                sequence,
+            )
+            .await?;
+
+            if is_received {
+                starknet_to_cosmos.unreceived_acks.push(sequence.value());
+            } else {
+                starknet_to_cosmos.unreceived_packets.push(sequence.value());
+            }
+        }
+
+        let mut cosmos_to_starknet = PendingPackets::default();
+
+        let (cosmos_sequences, _) =
+            CanQueryPacketCommitments::<StarknetChain>::query_packet_commitments(
+                &cosmos_chain,
+                &counterparty_channel_id,
+                &counterparty_port_id,
+            )
+            .await?;
+
+        let unreceived_on_starknet: BTreeSet<u64> = starknet_chain
+            .query_unreceived_packet_sequences(&port_id, &channel_id, &cosmos_sequences)
+            .await?
+            .iter()
+            .map(|sequence| sequence.value())
+            .collect();
+
+        for sequence in cosmos_sequences.iter() {
+            if unreceived_on_starknet.contains(&sequence.value()) {
+                cosmos_to_starknet.unreceived_packets.push(sequence.value());
+            } else {
+                cosmos_to_starknet.unreceived_acks.push(sequence.value());
+            }
+        }
+
+        for pending in [&mut starknet_to_cosmos, &mut cosmos_to_starknet] {
+            pending.unreceived_packets.sort_unstable();
+            pending.unreceived_acks.sort_unstable();
+        }
+
+        let output = PendingPacketsOutput {
+            starknet_to_cosmos,
+            cosmos_to_starknet,
+        };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryPacketArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "chain-id",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub chain_id: String,
+
+    /// Port of the channel on Starknet
+    #[clap(
+        long = "port-id",
+        required = true,
+        value_name = "PORT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub port_id: String,
+
+    /// Identifier of the channel on Starknet
+    #[clap(
+        long = "channel-id",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub channel_id: String,
+
+    /// Sequence of the packet
+    #[clap(
+        long = "sequence",
+        required = true,
+        value_name = "SEQUENCE",
+        help_heading = "REQUIRED"
+    )]
+    pub sequence: u64,
+
+    /// Starknet height at which to query the packet. Defaults to the latest height
+    #[clap(long = "height", required = false)]
+    pub height: Option<u64>,
+
+    /// Include the storage proof of the queried value in the output
+    #[clap(long = "include-proof")]
+    pub include_proof: bool,
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryPacketCommitmentArgs {
+    #[clap(flatten)]
+    pub packet: QueryPacketArgs,
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryPacketAckArgs {
+    #[clap(flatten)]
+    pub packet: QueryPacketArgs,
+}
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct QueryPacketReceiptArgs {
+    #[clap(flatten)]
+    pub packet: QueryPacketArgs,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PacketProofOutput {
+    pub proof_height: u64,
+    pub storage_proof: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PacketQueryOutput<Value> {
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+    pub height: u64,
+    #[serde(flatten)]
+    pub value: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<PacketProofOutput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PacketCommitmentValue {
+    /// Hex-encoded packet commitment, absent when the packet is not committed
+    pub commitment: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PacketAckValue {
+    /// Hex-encoded acknowledgement commitment
+    pub acknowledgement: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PacketReceiptValue {
+    pub received: bool,
+}
+
+/// The Starknet chain, packet and height shared by the packet state queries.
+struct PacketQuery {
+    chain: StarknetChain,
+    port_id: PortId,
+    channel_id: ChannelId,
+    sequence: Sequence,
+    height: u64,
+}
+
+impl PacketQuery {
+    async fn load(app: &StarknetApp, args: &QueryPacketArgs) -> Result<Self, HermesError> {
+        let chain_id: ChainId = args.chain_id.parse()?;
+        let port_id: PortId = args.port_id.parse()?;
+        let channel_id: ChannelId = args.channel_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let chain = builder.build_chain(&chain_id).await?;
+
+        let height = match args.height {
+            Some(height) => height,
+            None => chain.query_chain_height().await?,
+        };
+
+        Ok(Self {
+            chain,
+            port_id,
+            channel_id,
+            sequence: Sequence::from(args.sequence),
+            height,
+        })
+    }
+
+    fn output<Value: Serialize>(
+        &self,
+        args: &QueryPacketArgs,
+        value: Value,
+        proof: StarknetCommitmentProof,
+    ) -> Result<String, HermesError> {
+        let proof = if args.include_proof {
+            Some(PacketProofOutput {
+                proof_height: proof.proof_height,
+                storage_proof: serde_json::from_slice(&proof.proof_bytes)?,
+            })
+        } else {
+            None
+        };
+
+        let output = PacketQueryOutput {
+            port_id: self.port_id.to_string(),
+            channel_id: self.channel_id.to_string(),
+            sequence: self.sequence.value(),
+            height: self.height,
+            value,
+            proof,
+        };
+
+        Ok(serde_json::to_string_pretty(&output)?)
+    }
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryPacketCommitmentArgs> for RunQueryPacketCommitmentCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryPacketCommitmentArgs,
+    ) -> Result<(), HermesError> {
+        let query = PacketQuery::load(app, &args.packet).await?;
+
+        let (commitment, proof) = CanQueryPacketCommitment::<CosmosChain>::query_packet_commitment(
+            &query.chain,
+            &query.channel_id,
+            &query.port_id,
+            &query.sequence,
+            &query.height,
+        )
+        .await?;
+
+        let value = PacketCommitmentValue {
+            commitment: commitment.map(hex::encode),
+        };
+
+        println!("{}", query.output(&args.packet, value, proof)?);
+
+        Ok(())
+    }
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryPacketAckArgs> for RunQueryPacketAckCommand {
+    async fn run_command(app: &StarknetApp, args: &QueryPacketAckArgs) -> Result<(), HermesError> {
+        let query = PacketQuery::load(app, &args.packet).await?;
+
+        let (acknowledgement, proof) =
+            CanQueryPacketAckCommitment::<CosmosChain>::query_packet_ack_commitment_with_proof(
+                &query.chain,
+                &query.channel_id,
+                &query.port_id,
+                &query.sequence,
+                &query.height,
+            )
+            .await?;
+
+        let value = PacketAckValue {
+            acknowledgement: hex::encode(acknowledgement),
+        };
+
+        println!("{}", query.output(&args.packet, value, proof)?);
+
+        Ok(())
+    }
+}
+
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, QueryPacketReceiptArgs> for RunQueryPacketReceiptCommand {
+    async fn run_command(
+        app: &StarknetApp,
+        args: &QueryPacketReceiptArgs,
+    ) -> Result<(), HermesError> {
+        let query = PacketQuery::load(app, &args.packet).await?;
+
+        let (receipt, proof) = CanQueryPacketReceipt::<CosmosChain>::query_packet_receipt(
+            &query.chain,
+            &query.channel_id,
+            &query.port_id,
+            &query.sequence,
+            &query.height,
+        )
+        .await?;
+
+        let value = PacketReceiptValue {
+            received: receipt.is_some(),
+        };
+
+        println!("{}", query.output(&args.packet, value, proof)?);
+
+        Ok(())
+    }
+}
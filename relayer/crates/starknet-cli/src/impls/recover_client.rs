@@ -0,0 +1,154 @@
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::traits::{CanRecoverClient, CanSendSingleMessage};
+use hermes_core::relayer_components::relay::traits::{CanCreateClient, DestinationTarget};
+use hermes_cosmos::chain_components::impls::CosmosRecoverClientPayload;
+use hermes_cosmos::error::HermesError;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::types::StarknetCreateClientPayloadOptions;
+use hermes_starknet_chain_context::contexts::StarknetChain;
+use hermes_starknet_relayer::contexts::StarknetToCosmosRelay;
+use hex::FromHex;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use serde::Serialize;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct RecoverClientArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "starknet-chain-id",
+        required = true,
+        value_name = "STARKNET_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub starknet_chain_id: String,
+
+    /// Identifier of Cosmos chain
+    #[clap(
+        long = "cosmos-chain-id",
+        required = true,
+        value_name = "COSMOS_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_chain_id: String,
+
+    /// Identifier of the expired or frozen Starknet client on Cosmos to recover
+    #[clap(
+        long = "cosmos-client-id",
+        required = true,
+        value_name = "COSMOS_CLIENT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_client_id: String,
+
+    /// Code hash of the Starknet Wasm light client used by the substitute client
+    #[clap(
+        long = "wasm-code-hash",
+        required = true,
+        value_name = "WASM_CODE_HASH",
+        help_heading = "REQUIRED"
+    )]
+    pub wasm_code_hash: String,
+
+    /// Amount deposited with the governance proposal
+    #[clap(
+        long = "deposit-amount",
+        required = true,
+        value_name = "DEPOSIT_AMOUNT",
+        help_heading = "REQUIRED"
+    )]
+    pub deposit_amount: u128,
+
+    /// Denom of the deposit of the governance proposal
+    #[clap(
+        long = "deposit-denom",
+        required = true,
+        value_name = "DEPOSIT_DENOM",
+        help_heading = "REQUIRED"
+    )]
+    pub deposit_denom: String,
+
+    /// Trusting period of the substitute client. Defaults to 14 days
+    #[clap(long = "trusting-period", required = false)]
+    pub trusting_period: Option<humantime::Duration>,
+
+    /// Maximum clock drift allowed for Starknet block timestamps by the substitute client.
+    /// Defaults to 60 seconds
+    #[clap(long = "max-clock-drift", required = false)]
+    pub max_clock_drift: Option<humantime::Duration>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecoverClientOutput {
+    pub subject_client_id: String,
+    pub substitute_client_id: String,
+}
+
+/// Creates a substitute Starknet client on Cosmos and submits the governance proposal
+/// that recovers the subject client with the substitute client state.
+///
+/// The subject client is only recovered once the proposal passes, which is left to
+/// the governance process of the Cosmos chain.
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, RecoverClientArgs> for RunRecoverClientCommand {
+    async fn run_command(app: &StarknetApp, args: &RecoverClientArgs) -> Result<(), HermesError> {
+        let starknet_chain_id: ChainId = args.starknet_chain_id.parse()?;
+        let cosmos_chain_id: ChainId = args.cosmos_chain_id.parse()?;
+        let subject_client_id: ClientId = args.cosmos_client_id.parse()?;
+
+        let wasm_code_hash = <[u8; 32]>::from_hex(&args.wasm_code_hash)?;
+
+        let mut create_client_options = StarknetCreateClientPayloadOptions::new(wasm_code_hash);
+
+        if let Some(trusting_period) = args.trusting_period {
+            create_client_options.trusting_period = trusting_period.into();
+        }
+
+        if let Some(max_clock_drift) = args.max_clock_drift {
+            create_client_options.max_clock_drift = max_clock_drift.into();
+        }
+
+        let builder = app.load_builder().await?;
+
+        let starknet_chain = builder.build_chain(&starknet_chain_id).await?;
+        let cosmos_chain = builder.cosmos_builder.build_chain(&cosmos_chain_id).await?;
+
+        let substitute_client_id = StarknetToCosmosRelay::create_client(
+            DestinationTarget,
+            &cosmos_chain,
+            &starknet_chain,
+            &create_client_options,
+            &(),
+        )
+        .await?;
+
+        tracing::info!("created substitute client {substitute_client_id} on {cosmos_chain_id}");
+
+        let recover_client_payload = CosmosRecoverClientPayload {
+            deposit_amount: args.deposit_amount,
+            deposit_denom: args.deposit_denom.clone(),
+        };
+
+        let proposal_message = CanRecoverClient::<StarknetChain>::recover_client_message(
+            &cosmos_chain,
+            &subject_client_id,
+            &substitute_client_id,
+            &recover_client_payload,
+        )
+        .await;
+
+        cosmos_chain.send_message(proposal_message).await?;
+
+        tracing::info!("submitted governance proposal to recover client {subject_client_id}");
+
+        let output = RecoverClientOutput {
+            subject_client_id: subject_client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use core::marker::PhantomData;
+
+use hermes_cairo_encoding_components::strategy::ViaCairo;
+use hermes_cairo_encoding_components::types::as_felt::AsFelt;
+use hermes_cli_components::traits::{CanLoadBuilder, CommandRunner, CommandRunnerComponent};
+use hermes_core::chain_components::impls::CanWaitChainReachHeight;
+use hermes_core::chain_components::traits::{
+    CanBuildClientUpgradePayload, CanBuildUpdateClientMessage, CanBuildUpdateClientPayload,
+    CanQueryClientStateWithLatestHeight, CanSendMessages, CanSendSingleMessage, CanUpgradeClient,
+};
+use hermes_core::encoding_components::traits::{CanDecode, HasEncodedType, HasEncoding};
+use hermes_cosmos::error::HermesError;
+use hermes_cosmos::relayer::contexts::CosmosChain;
+use hermes_prelude::*;
+use hermes_starknet_chain_components::traits::{
+    CanCallContract, CanQueryContractAddress, HasBlobType, HasSelectorType,
+};
+use hermes_starknet_chain_context::contexts::StarknetChain;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use starknet::core::types::Felt;
+use starknet::macros::selector;
+
+use crate::contexts::StarknetApp;
+
+#[derive(Debug, clap::Parser, HasField)]
+pub struct UpgradeClientArgs {
+    /// Identifier of Starknet chain
+    #[clap(
+        long = "starknet-chain-id",
+        required = true,
+        value_name = "STARKNET_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub starknet_chain_id: String,
+
+    /// Identifier of Cosmos chain
+    #[clap(
+        long = "cosmos-chain-id",
+        required = true,
+        value_name = "COSMOS_CHAIN_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_chain_id: String,
+
+    /// Identifier of the Starknet client on Cosmos to upgrade
+    #[clap(
+        long = "cosmos-client-id",
+        required = true,
+        value_name = "COSMOS_CLIENT_ID",
+        help_heading = "REQUIRED"
+    )]
+    pub cosmos_client_id: String,
+}
+
+/// Upgrades the Starknet client on Cosmos to the client and consensus states scheduled
+/// on the Starknet IBC core contract.
+///
+/// The client is first updated to the scheduled final height, as the Starknet light
+/// client only accepts an upgrade when its latest height is exactly the final height.
+/// The upgraded states are then proven against the consensus state at that height.
+#[cgp_new_provider(CommandRunnerComponent)]
+impl CommandRunner<StarknetApp, UpgradeClientArgs> for RunUpgradeClientCommand {
+    async fn run_command(app: &StarknetApp, args: &UpgradeClientArgs) -> Result<(), HermesError> {
+        let starknet_chain_id: ChainId = args.starknet_chain_id.parse()?;
+        let cosmos_chain_id: ChainId = args.cosmos_chain_id.parse()?;
+        let cosmos_client_id: ClientId = args.cosmos_client_id.parse()?;
+
+        let builder = app.load_builder().await?;
+
+        let starknet_chain = builder.build_chain(&starknet_chain_id).await?;
+        let cosmos_chain = builder.cosmos_builder.build_chain(&cosmos_chain_id).await?;
+
+        let final_height = query_final_height(&starknet_chain).await?;
+
+        if final_height == 0 {
+            return Err(StarknetApp::raise_error(
+                "no client upgrade is scheduled on the Starknet IBC core contract",
+            ));
+        }
+
+        let client_state = cosmos_chain
+            .query_client_state_with_latest_height(PhantomData::<StarknetChain>, &cosmos_client_id)
+            .await?;
+
+        let client_height = client_state.client_state.latest_height.revision_height();
+
+        if client_height > final_height {
+            return Err(StarknetApp::raise_error(format!(
+                "client {cosmos_client_id} is already at height {client_height}, \
+                past the final height {final_height}; it can no longer be upgraded"
+            )));
+        }
+
+        tracing::info!("waiting for Starknet to reach the final height {final_height}");
+
+        starknet_chain
+            .wait_chain_reach_height(&final_height)
+            .await?;
+
+        if client_height < final_height {
+            let update_payload =
+                CanBuildUpdateClientPayload::<CosmosChain>::build_update_client_payload(
+                    &starknet_chain,
+                    &client_height,
+                    &final_height,
+                    client_state,
+                )
+                .await?;
+
+            let update_messages =
+                CanBuildUpdateClientMessage::<StarknetChain>::build_update_client_message(
+                    &cosmos_chain,
+                    &cosmos_client_id,
+                    update_payload,
+                )
+                .await?;
+
+            cosmos_chain.send_messages(update_messages).await?;
+
+            tracing::info!("updated client {cosmos_client_id} to the final height {final_height}");
+        }
+
+        let upgrade_payload = CanBuildClientUpgradePayload::<CosmosChain>::upgrade_client_payload(
+            &starknet_chain,
+            &final_height,
+        )
+        .await?;
+
+        let upgrade_message = CanUpgradeClient::<StarknetChain>::upgrade_client_message(
+            &cosmos_chain,
+            &cosmos_client_id,
+            &upgrade_payload,
+        )
+        .await?;
+
+        cosmos_chain.send_message(upgrade_message).await?;
+
+        let client_state = cosmos_chain
+            .query_client_state_with_latest_height(PhantomData::<StarknetChain>, &cosmos_client_id)
+            .await?;
+
+        tracing::info!(
+            "upgraded client {cosmos_client_id} to height {}",
+            client_state.client_state.latest_height
+        );
+
+        Ok(())
+    }
+}
+
+/// Queries the final height scheduled for the client upgrade on the Starknet IBC
+/// core contract, which is `0` when no upgrade is scheduled.
+async fn query_final_height<Chain, Encoding>(chain: &Chain) -> Result<u64, Chain::Error>
+where
+    Chain: CanQueryContractAddress<symbol!("ibc_core_contract_address")>
+        + CanCallContract
+        + HasSelectorType<Selector = Felt>
+        + HasBlobType<Blob = Vec<Felt>>
+        + HasEncoding<AsFelt, Encoding = Encoding>
+        + CanRaiseAsyncError<Encoding::Error>,
+    Encoding: CanDecode<ViaCairo, u64> + HasEncodedType<Encoded = Vec<Felt>>,
+{
+    let contract_address = chain.query_contract_address(PhantomData).await?;
+
+    let output = chain
+        .call_contract(
+            &contract_address,
+            &selector!("get_final_height"),
+            &vec![],
+            None,
+        )
+        .await?;
+
+    chain.encoding().decode(&output).map_err(Chain::raise_error)
+}
@@ -79,9 +79,9 @@ impl StarknetTestSetup {
             starknet_bootstrap,
             osmosis_bootstrap,
             starknet_builder,
-            starknet_create_client_payload_options: StarknetCreateClientPayloadOptions {
+            starknet_create_client_payload_options: StarknetCreateClientPayloadOptions::new(
                 wasm_code_hash,
-            },
+            ),
             port_id: PortId::transfer(),
             init_channel_options: Default::default(),
             init_connection_options: Default::default(),
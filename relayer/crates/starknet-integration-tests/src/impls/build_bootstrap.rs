@@ -22,12 +22,15 @@ use hermes_prelude::*;
 use hermes_starknet_chain_components::impls::{
     StarknetChainConfig, StarknetContractAddresses, StarknetContractClasses,
 };
-use hermes_starknet_chain_components::types::StarknetWallet;
+use hermes_starknet_chain_components::types::{
+    StarknetMetrics, StarknetRpcTransport, StarknetSignerPool, StarknetWallet,
+};
 use hermes_starknet_chain_context::contexts::{StarknetChain, StarknetChainFields};
 use hermes_starknet_test_components::types::{StarknetGenesisConfig, StarknetNodeConfig};
 use ibc::core::host::types::error::IdentifierError;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use starknet_block_verifier::AsyncEndpoint;
 use tokio::process::Child;
 use url::{ParseError, Url};
 
@@ -70,7 +73,8 @@ where
             .map_err(Bootstrap::raise_error)?;
 
         for (name, wallet) in wallets.iter() {
-            let wallet_str = toml::to_string_pretty(wallet).map_err(Bootstrap::raise_error)?;
+            let wallet_str =
+                toml::to_string_pretty(&wallet.to_config()).map_err(Bootstrap::raise_error)?;
             let wallet_path = chain_store_dir.join(format!("wallets/{name}.toml"));
 
             runtime
@@ -105,8 +109,7 @@ where
         ))
         .map_err(Bootstrap::raise_error)?;
 
-        let starknet_client =
-            Arc::new(JsonRpcClient::new(HttpTransport::new(json_rpc_url.clone())));
+        let devnet_client = JsonRpcClient::new(HttpTransport::new(json_rpc_url.clone()));
 
         let rpc_client = ureq::agent();
 
@@ -117,22 +120,36 @@ where
         ))
         .map_err(Bootstrap::raise_error)?;
 
+        let feeder_gateway_endpoint = AsyncEndpoint::new(feeder_gateway_url.as_str())
+            .expect("Failed to build the feeder gateway client");
+
         // Wait for the chain to be ready.
         for _ in 0..10 {
-            match starknet_client.block_number().await {
+            match devnet_client.block_number().await {
                 Ok(_) => break,
                 Err(_) => runtime.sleep(core::time::Duration::from_secs(1)).await,
             }
         }
 
-        let chain_id = starknet_client
+        let chain_id = devnet_client
             .chain_id()
             .await
             .map_err(Bootstrap::raise_error)?;
 
+        let metrics = StarknetMetrics::new(&chain_id.to_string());
+
+        let starknet_client = Arc::new(JsonRpcClient::new(StarknetRpcTransport::new(
+            HttpTransport::new(json_rpc_url.clone()),
+            metrics.clone(),
+        )));
+
+        let relayer_signing_key = relayer_wallet_1
+            .local_signing_key()
+            .ok_or_else(|| Bootstrap::raise_error("expect relayer wallet to have a signing key"))?;
+
         let proof_signer = Secp256k1KeyPair::from_mnemonic(
             bip39::Mnemonic::from_entropy(
-                &relayer_wallet_1.signing_key.to_bytes_be(),
+                &relayer_signing_key.to_bytes_be(),
                 bip39::Language::English,
             )
             .expect("valid mnemonic")
@@ -191,9 +208,11 @@ where
                 node_config.rpc_addr,
                 node_config.rpc_port + 1
             ),
-            relayer_wallet_1: relayer_wallet_path_1,
-            relayer_wallet_2: relayer_wallet_path_2,
+            relayer_wallets: vec![relayer_wallet_path_1, relayer_wallet_path_2],
+            relayer_wallet_1: None,
+            relayer_wallet_2: None,
             ed25519_attestator_addresses,
+            attestation: None,
             poll_interval,
             block_time,
             contract_addresses,
@@ -205,6 +224,13 @@ where
                 max_delay: Duration::from_secs(1),
                 sleep_time: Duration::from_millis(100),
             }),
+            feeder_gateway_config: None,
+            packet_filter: None,
+            event_chunk_size: None,
+            event_source: None,
+            fee_config: None,
+            signer_pool: None,
+            telemetry: None,
         };
 
         let chain = StarknetChain {
@@ -215,11 +241,13 @@ where
                     .parse()
                     .map_err(Bootstrap::raise_error)?,
                 ed25519_attestator_addresses: chain_config.ed25519_attestator_addresses.clone(),
+                attestator_http_client: Default::default(),
                 chain_config,
                 starknet_client,
                 rpc_client,
                 json_rpc_url,
                 feeder_gateway_url,
+                feeder_gateway_endpoint,
                 ibc_client_contract_address: OnceLock::new(),
                 ibc_core_contract_address: OnceLock::new(),
                 ibc_ics20_contract_address: OnceLock::new(),
@@ -229,7 +257,9 @@ where
                 nonce_mutex: Arc::new(Mutex::new(())),
                 signers: vec![relayer_wallet_1.clone(), relayer_wallet_2.clone()],
                 client_refresh_rate,
-                signer_mutex: Arc::new(Mutex::new(0)),
+                event_subscription: None,
+                signer_pool: StarknetSignerPool::new(2, Default::default()),
+                metrics,
             }),
         };
 
@@ -15,10 +15,14 @@ use hermes_cosmos::integration_tests::impls::copy_dir_recursive;
 use hermes_cosmos::relayer::contexts::{CosmosBuilder, CosmosChain};
 use hermes_cosmos::test_components::bootstrap::traits::CanStartChainFullNodes;
 use hermes_prelude::*;
+use hermes_starknet_chain_components::types::{
+    StarknetMetrics, StarknetRpcTransport, StarknetSignerPool,
+};
 use hermes_starknet_chain_context::contexts::{StarknetChain, StarknetChainFields};
 use hermes_starknet_test_components::traits::CanStartChainForkedFullNodes;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
+use starknet_block_verifier::AsyncEndpoint;
 use tendermint_rpc::{HttpClient, Url};
 
 use crate::contexts::{
@@ -458,8 +462,11 @@ async fn fork_starknet_chain(
         .set_port(Some(current_port + 20))
         .expect("Failed to set port");
 
-    let forked_starknet_rpc_client = Arc::new(JsonRpcClient::new(HttpTransport::new(
-        forked_json_rpc_url.clone(),
+    let forked_metrics = StarknetMetrics::new(driver.starknet_chain_driver.chain.chain_id.as_str());
+
+    let forked_starknet_rpc_client = Arc::new(JsonRpcClient::new(StarknetRpcTransport::new(
+        HttpTransport::new(forked_json_rpc_url.clone()),
+        forked_metrics.clone(),
     )));
 
     let rpc_client = ureq::agent();
@@ -476,6 +483,17 @@ async fn fork_starknet_chain(
         .set_port(Some(current_port + 20))
         .expect("Failed to set port");
 
+    let forked_feeder_gateway_endpoint = AsyncEndpoint::with_config(
+        forked_feeder_gateway_url.as_str(),
+        driver
+            .starknet_chain_driver
+            .chain
+            .feeder_gateway_endpoint
+            .config
+            .clone(),
+    )
+    .expect("Failed to build the feeder gateway client");
+
     let forked_starknet_chain = StarknetChain {
         fields: Arc::new(StarknetChainFields {
             runtime: runtime.clone(),
@@ -485,6 +503,7 @@ async fn fork_starknet_chain(
             rpc_client,
             json_rpc_url: forked_json_rpc_url,
             feeder_gateway_url: forked_feeder_gateway_url,
+            feeder_gateway_endpoint: forked_feeder_gateway_endpoint,
             ibc_client_contract_address: driver
                 .starknet_chain_driver
                 .chain
@@ -506,12 +525,27 @@ async fn fork_starknet_chain(
             nonce_mutex: Arc::new(Mutex::new(())),
             signers: driver.starknet_chain_driver.chain.signers.clone(),
             client_refresh_rate: driver.starknet_chain_driver.chain.client_refresh_rate,
-            signer_mutex: Arc::new(Mutex::new(0)),
             ed25519_attestator_addresses: driver
                 .starknet_chain_driver
                 .chain
                 .ed25519_attestator_addresses
                 .clone(),
+            attestator_http_client: driver
+                .starknet_chain_driver
+                .chain
+                .attestator_http_client
+                .clone(),
+            event_subscription: None,
+            signer_pool: StarknetSignerPool::new(
+                driver.starknet_chain_driver.chain.signers.len(),
+                driver
+                    .starknet_chain_driver
+                    .chain
+                    .signer_pool
+                    .config
+                    .clone(),
+            ),
+            metrics: forked_metrics,
         }),
     };
 
@@ -10,10 +10,14 @@ use hermes_cosmos::error::HermesError;
 use hermes_cosmos::integration_tests::impls::copy_dir_recursive;
 use hermes_cosmos::test_components::bootstrap::traits::CanStartChainFullNodes;
 use hermes_prelude::*;
+use hermes_starknet_chain_components::types::{
+    StarknetMetrics, StarknetRpcTransport, StarknetSignerPool,
+};
 use hermes_starknet_chain_context::contexts::{StarknetChain, StarknetChainFields};
 use hermes_starknet_test_components::traits::CanStartChainForkedFullNodes;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
+use starknet_block_verifier::AsyncEndpoint;
 use starknet_crypto::Felt;
 
 use crate::contexts::{StarknetBootstrap, StarknetBootstrapFields, StarknetChainDriver};
@@ -161,8 +165,11 @@ impl FullNodeResumer<StarknetChainDriver> for StarknetFullNodeHandler {
             .set_port(Some(current_port + 20))
             .expect("Failed to set port");
 
-        let forked_starknet_rpc_client = Arc::new(JsonRpcClient::new(HttpTransport::new(
-            forked_json_rpc_url.clone(),
+        let forked_metrics = StarknetMetrics::new(chain_driver.chain.chain_id.as_str());
+
+        let forked_starknet_rpc_client = Arc::new(JsonRpcClient::new(StarknetRpcTransport::new(
+            HttpTransport::new(forked_json_rpc_url.clone()),
+            forked_metrics.clone(),
         )));
 
         let rpc_client = ureq::agent();
@@ -175,6 +182,12 @@ impl FullNodeResumer<StarknetChainDriver> for StarknetFullNodeHandler {
             .set_port(Some(current_port + 20))
             .expect("Failed to set port");
 
+        let forked_feeder_gateway_endpoint = AsyncEndpoint::with_config(
+            forked_feeder_gateway_url.as_str(),
+            chain_driver.chain.feeder_gateway_endpoint.config.clone(),
+        )
+        .expect("Failed to build the feeder gateway client");
+
         let forked_starknet_chain = StarknetChain {
             fields: Arc::new(StarknetChainFields {
                 runtime: runtime.clone(),
@@ -184,6 +197,7 @@ impl FullNodeResumer<StarknetChainDriver> for StarknetFullNodeHandler {
                 rpc_client,
                 json_rpc_url: forked_json_rpc_url,
                 feeder_gateway_url: forked_feeder_gateway_url,
+                feeder_gateway_endpoint: forked_feeder_gateway_endpoint,
                 ibc_client_contract_address: chain_driver.chain.ibc_client_contract_address.clone(),
                 ibc_core_contract_address: chain_driver.chain.ibc_core_contract_address.clone(),
                 ibc_ics20_contract_address: chain_driver.chain.ibc_ics20_contract_address.clone(),
@@ -193,11 +207,17 @@ impl FullNodeResumer<StarknetChainDriver> for StarknetFullNodeHandler {
                 nonce_mutex: Arc::new(Mutex::new(())),
                 signers: chain_driver.chain.signers.clone(),
                 client_refresh_rate: chain_driver.chain.client_refresh_rate,
-                signer_mutex: Arc::new(Mutex::new(0)),
                 ed25519_attestator_addresses: chain_driver
                     .chain
                     .ed25519_attestator_addresses
                     .clone(),
+                attestator_http_client: chain_driver.chain.attestator_http_client.clone(),
+                event_subscription: None,
+                signer_pool: StarknetSignerPool::new(
+                    chain_driver.chain.signers.len(),
+                    chain_driver.chain.signer_pool.config.clone(),
+                ),
+                metrics: forked_metrics,
             }),
         };
 
@@ -0,0 +1,164 @@
+use core::time::Duration;
+use std::env::var;
+
+use hermes_core::chain_components::traits::{CanQueryChainHeight, CanSendSingleMessage};
+use hermes_core::encoding_components::traits::CanEncode;
+use hermes_core::runtime_components::traits::{CanReadFileAsString, CanSleep};
+use hermes_core::test_components::bootstrap::traits::CanBootstrapChain;
+use hermes_cosmos::error::types::Error;
+use hermes_cosmos::integration_tests::init::init_test_runtime;
+use hermes_starknet_chain_components::impls::StarknetMessage;
+use hermes_starknet_chain_components::traits::{
+    CanDeclareContract, CanDeployContract, HasStarknetClient,
+};
+use hermes_starknet_chain_components::types::{
+    DeployErc20TokenMessage, StarknetEvent, StarknetEventSubscription,
+};
+use hermes_starknet_chain_context::contexts::StarknetCairoEncoding;
+use hermes_starknet_chain_context::impls::run_starknet_event_subscription;
+use starknet::core::types::{BlockId, EventFilter, U256};
+use starknet::macros::selector;
+use starknet::providers::Provider;
+use tracing::info;
+
+use crate::utils::{create_test_uid, init_starknet_bootstrap};
+
+/// Checks that the events received over the WebSocket subscription of a local devnet match
+/// the events queried by polling. The WebSocket URL defaults to the JSON-RPC URL with the
+/// `ws` scheme, and can be overridden with `STARKNET_WS_URL`.
+#[test]
+fn test_starknet_event_subscription() -> Result<(), Error> {
+    let runtime = init_test_runtime();
+
+    runtime.runtime.clone().block_on(async move {
+        let test_uid = create_test_uid().await?;
+
+        let bootstrap = init_starknet_bootstrap(&runtime, test_uid).await?;
+
+        let chain_driver = bootstrap.bootstrap_chain("starknet").await?;
+
+        let chain = &chain_driver.chain;
+
+        let erc20_class_hash = {
+            let contract_path = var("ERC20_CONTRACT")?;
+
+            let contract_str = runtime.read_file_as_string(&contract_path.into()).await?;
+
+            let contract = serde_json::from_str(&contract_str)?;
+
+            chain.declare_contract(&contract).await?
+        };
+
+        let token_address = {
+            let deploy_message = DeployErc20TokenMessage {
+                name: "token".into(),
+                symbol: "token".into(),
+                decimals: 18,
+                owner: chain_driver.relayer_wallet_1.account_address,
+            };
+
+            let calldata = StarknetCairoEncoding.encode(&deploy_message)?;
+
+            chain
+                .deploy_contract(&erc20_class_hash, false, &calldata)
+                .await?
+        };
+
+        let ws_url = var("STARKNET_WS_URL").unwrap_or_else(|_| {
+            let mut ws_url = chain.json_rpc_url.clone();
+            ws_url.set_scheme("ws").unwrap();
+            ws_url.to_string()
+        });
+
+        info!("subscribing to the events of {token_address:?} at {ws_url}");
+
+        let subscription = StarknetEventSubscription::default();
+
+        runtime.runtime.spawn(run_starknet_event_subscription(
+            ws_url,
+            *token_address,
+            Duration::from_secs(1),
+            subscription.clone(),
+        ));
+
+        for _ in 0..60 {
+            if subscription.is_live() {
+                break;
+            }
+
+            runtime.sleep(Duration::from_secs(1)).await;
+        }
+
+        assert!(
+            subscription.is_live(),
+            "event subscription did not become live"
+        );
+
+        let start_height = chain.query_chain_height().await?;
+
+        let calldata = StarknetCairoEncoding.encode(&U256::from(1000u32))?;
+
+        chain
+            .send_message(StarknetMessage::new(
+                *token_address,
+                selector!("mint"),
+                calldata,
+            ))
+            .await?;
+
+        let end_height = chain.query_chain_height().await?;
+
+        let mut subscribed_event_count = 0;
+
+        for height in start_height..=end_height {
+            let mut subscribed_events = None;
+
+            for _ in 0..60 {
+                subscribed_events = subscription.block_events(height);
+
+                if subscribed_events.is_some() {
+                    break;
+                }
+
+                runtime.sleep(Duration::from_secs(1)).await;
+            }
+
+            let subscribed_events = subscribed_events
+                .unwrap_or_else(|| panic!("block {height} was not covered by the subscription"));
+
+            let polled_events = chain
+                .provider()
+                .get_events(
+                    EventFilter {
+                        from_block: Some(BlockId::Number(height)),
+                        to_block: Some(BlockId::Number(height)),
+                        address: Some(*token_address),
+                        keys: None,
+                    },
+                    None,
+                    1000,
+                )
+                .await?
+                .events
+                .into_iter()
+                .map(StarknetEvent::from)
+                .collect::<Vec<_>>();
+
+            assert_eq!(subscribed_events.len(), polled_events.len());
+
+            for (subscribed_event, polled_event) in subscribed_events.iter().zip(&polled_events) {
+                assert_eq!(subscribed_event.selector, polled_event.selector);
+                assert_eq!(subscribed_event.keys, polled_event.keys);
+                assert_eq!(subscribed_event.data, polled_event.data);
+            }
+
+            subscribed_event_count += subscribed_events.len();
+        }
+
+        info!("received {subscribed_event_count} events over the subscription");
+
+        assert!(subscribed_event_count > 0);
+
+        Ok(())
+    })
+}
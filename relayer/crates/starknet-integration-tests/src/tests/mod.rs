@@ -1,4 +1,5 @@
 pub mod erc20;
+pub mod event_subscription;
 pub mod feeder;
 pub mod modified_test_suite;
 pub mod packet_commitment;
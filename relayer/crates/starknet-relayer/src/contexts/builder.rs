@@ -4,7 +4,7 @@ use core::ops::Deref;
 use core::time::Duration;
 use std::collections::HashSet;
 use std::env::var;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 use cgp::core::component::UseDelegate;
@@ -40,15 +40,22 @@ use hermes_cosmos::error::HermesError;
 use hermes_cosmos::relayer::contexts::{CosmosBuilder, CosmosChain};
 use hermes_cosmos::runtime::types::runtime::HermesRuntime;
 use hermes_prelude::*;
-use hermes_starknet_chain_components::impls::StarknetChainConfig;
-use hermes_starknet_chain_components::types::StarknetWallet;
+use hermes_starknet_chain_components::impls::{StarknetChainConfig, StarknetEventSourceConfig};
+use hermes_starknet_chain_components::types::{
+    StarknetEventSubscription, StarknetRpcTransport, StarknetSignerPool, StarknetTelemetry,
+    StarknetWallet, StarknetWalletConfig,
+};
 use hermes_starknet_chain_context::contexts::{
     StarknetChain, StarknetChainFields, StarknetEventEncoding,
 };
-use hermes_starknet_chain_context::impls::HandleStarknetChainError;
+use hermes_starknet_chain_context::impls::{
+    run_starknet_event_subscription, run_starknet_telemetry_monitor, run_starknet_telemetry_server,
+    HandleStarknetChainError,
+};
 use ibc::core::host::types::identifiers::{ChainId, ClientId};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
+use starknet_block_verifier::AsyncEndpoint;
 use url::Url;
 
 use crate::contexts::cosmos_starknet_birelay::CosmosStarknetBiRelay;
@@ -69,6 +76,9 @@ pub struct StarknetBuilderFields {
     pub runtime: HermesRuntime,
     // Fields for StarknetChain
     pub starknet_chain_config: Option<StarknetChainConfig>,
+    /// Metrics of all the Starknet chains built, served by a single telemetry server.
+    pub starknet_telemetry: StarknetTelemetry,
+    pub telemetry_server_started: OnceLock<()>,
 }
 
 impl Deref for StarknetBuilder {
@@ -342,6 +352,8 @@ impl StarknetBuilder {
                 cosmos_builder,
                 runtime,
                 starknet_chain_config,
+                starknet_telemetry: StarknetTelemetry::default(),
+                telemetry_server_started: OnceLock::new(),
             }),
         }
     }
@@ -361,11 +373,27 @@ impl StarknetBuilder {
 
         let json_rpc_url = Url::parse(&chain_config.json_rpc_url)?;
 
-        let starknet_rpc_client =
-            Arc::new(JsonRpcClient::new(HttpTransport::new(json_rpc_url.clone())));
+        let (metrics, start_monitor) = self
+            .starknet_telemetry
+            .chain_metrics(expected_chain_id.as_str())
+            .map_err(|e| eyre!("failed to register the metrics of {expected_chain_id}: {e}"))?;
+
+        let starknet_rpc_client = Arc::new(JsonRpcClient::new(StarknetRpcTransport::new(
+            HttpTransport::new(json_rpc_url.clone()),
+            metrics.clone(),
+        )));
 
         let feeder_gateway_url = Url::parse(&chain_config.feeder_gateway_url)?;
 
+        let feeder_gateway_endpoint = AsyncEndpoint::with_config(
+            feeder_gateway_url.as_str(),
+            chain_config
+                .feeder_gateway_config
+                .clone()
+                .unwrap_or_default(),
+        )
+        .map_err(|e| eyre!("failed to build the feeder gateway client: {e}"))?;
+
         let chain_id_felt = starknet_rpc_client.chain_id().await?;
 
         let chain_id = chain_id_felt.to_string().parse()?;
@@ -374,19 +402,33 @@ impl StarknetBuilder {
             return Err(eyre!("Starknet Starknet chain has a different ID as configured. Expected: {expected_chain_id}, got: {chain_id}").into());
         }
 
-        let wallet_path = PathBuf::from(chain_config.relayer_wallet_1.clone());
+        let relayer_wallet_paths = chain_config.relayer_wallet_paths();
 
-        let wallet_path_2 = PathBuf::from(chain_config.relayer_wallet_2.clone());
+        if relayer_wallet_paths.is_empty() {
+            return Err(eyre!("at least one Starknet relayer wallet must be configured").into());
+        }
 
-        let wallet_str = self.runtime.read_file_as_string(&wallet_path).await?;
+        let mut signers = Vec::new();
 
-        let wallet_str_2 = self.runtime.read_file_as_string(&wallet_path_2).await?;
+        for wallet_path in &relayer_wallet_paths {
+            let wallet_path = PathBuf::from(wallet_path);
 
-        let relayer_wallet_1: StarknetWallet = toml::from_str(&wallet_str)
-            .map_err(|e| eyre!("Failed to parse relayer wallet: {e}"))?;
+            let wallet_str = self.runtime.read_file_as_string(&wallet_path).await?;
 
-        let relayer_wallet_2: StarknetWallet = toml::from_str(&wallet_str_2)
-            .map_err(|e| eyre!("Failed to parse relayer wallet 2: {e}"))?;
+            let wallet = load_starknet_wallet(&wallet_path, &wallet_str).map_err(|e| {
+                eyre!(
+                    "Failed to load relayer wallet at {}: {e}",
+                    wallet_path.display()
+                )
+            })?;
+
+            signers.push(wallet);
+        }
+
+        let signer_pool = StarknetSignerPool::new(
+            signers.len(),
+            chain_config.signer_pool.clone().unwrap_or_default(),
+        );
 
         let contract_classes = &chain_config.contract_classes;
 
@@ -450,6 +492,32 @@ impl StarknetBuilder {
             })
             .ok();
 
+        let (event_subscription, poll_interval) = match &chain_config.event_source {
+            Some(StarknetEventSourceConfig::Push {
+                url,
+                reconnect_delay,
+                poll_interval,
+            }) => {
+                let ibc_core_contract_address = contract_addresses.ibc_core.ok_or_else(|| {
+                    Self::raise_error(
+                        "IBC core contract address is required for the push event source",
+                    )
+                })?;
+
+                let subscription = StarknetEventSubscription::default();
+
+                self.runtime.runtime.spawn(run_starknet_event_subscription(
+                    url.clone(),
+                    *ibc_core_contract_address,
+                    *reconnect_delay,
+                    subscription.clone(),
+                ));
+
+                (Some(subscription), *poll_interval)
+            }
+            Some(StarknetEventSourceConfig::Pull) | None => (None, chain_config.poll_interval),
+        };
+
         let context = StarknetChain {
             fields: Arc::new(StarknetChainFields {
                 runtime: self.runtime.clone(),
@@ -460,19 +528,46 @@ impl StarknetBuilder {
                 ibc_core_contract_address,
                 ibc_ics20_contract_address,
                 event_encoding,
-                poll_interval: chain_config.poll_interval,
+                poll_interval,
                 block_time: chain_config.block_time,
                 nonce_mutex: Arc::new(Mutex::new(())),
-                signers: vec![relayer_wallet_1, relayer_wallet_2],
-                signer_mutex: Arc::new(Mutex::new(0)),
+                signers,
                 rpc_client,
                 json_rpc_url,
                 feeder_gateway_url,
+                feeder_gateway_endpoint,
                 client_refresh_rate,
                 ed25519_attestator_addresses,
+                attestator_http_client: Default::default(),
+                event_subscription,
+                signer_pool,
+                metrics,
             }),
         };
 
+        if let Some(telemetry_config) = chain_config.telemetry.as_ref() {
+            if self.telemetry_server_started.set(()).is_ok() {
+                self.runtime.runtime.spawn(run_starknet_telemetry_server(
+                    telemetry_config.listen_addr,
+                    self.starknet_telemetry.clone(),
+                ));
+            }
+
+            if start_monitor {
+                let mut channels = Vec::new();
+
+                for channel in &telemetry_config.channels {
+                    channels.push((channel.port_id.parse()?, channel.channel_id.parse()?));
+                }
+
+                self.runtime.runtime.spawn(run_starknet_telemetry_monitor(
+                    context.clone(),
+                    channels,
+                    telemetry_config.refresh_interval,
+                ));
+            }
+        }
+
         Ok(context)
     }
 
@@ -517,6 +612,20 @@ impl StarknetBuilder {
     }
 }
 
+fn load_starknet_wallet(wallet_path: &Path, wallet_str: &str) -> Result<StarknetWallet, String> {
+    let wallet_config: StarknetWalletConfig =
+        toml::from_str(wallet_str).map_err(|e| e.to_string())?;
+
+    if wallet_config.is_plaintext() {
+        tracing::warn!(
+            "Starknet wallet at {} contains a plaintext signing key, use an encrypted keystore or a remote signer instead",
+            wallet_path.display()
+        );
+    }
+
+    wallet_config.load_wallet(wallet_path.parent().unwrap_or(Path::new(".")))
+}
+
 pub trait CanUseStarknetBuilder: CanBuildChain<Index<0>> + CanBuildChain<Index<1>> {}
 
 impl CanUseStarknetBuilder for StarknetBuilder {}